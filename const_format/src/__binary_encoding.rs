@@ -0,0 +1,452 @@
+use crate::{
+    __hidden_utils::hex_digit_value,
+    formatting::{hex_as_ascii, HexFormatting},
+    Case,
+};
+
+/// Configuration for the [`base64_encode`] and [`base64_decode`] macros.
+///
+/// The default configuration is [`STANDARD`](#associatedconstant.STANDARD),
+/// which uses the standard alphabet, with padding.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{base64_decode, base64_encode, Base64Config};
+///
+/// const CFG: Base64Config = Base64Config::URL_SAFE.set_padding(false);
+///
+/// assert_eq!(base64_encode!("hello?>", CFG), "aGVsbG8_Pg");
+/// assert_eq!(base64_decode!("aGVsbG8_Pg", CFG), b"hello?>");
+///
+/// ```
+///
+/// [`base64_encode`]: ./macro.base64_encode.html
+/// [`base64_decode`]: ./macro.base64_decode.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Base64Config {
+    url_safe: bool,
+    padding: bool,
+}
+
+impl Base64Config {
+    /// The standard alphabet (using `+` and `/`), with `=` padding.
+    pub const STANDARD: Self = Self {
+        url_safe: false,
+        padding: true,
+    };
+
+    /// The standard alphabet (using `+` and `/`), without padding.
+    pub const STANDARD_NO_PAD: Self = Self::STANDARD.set_padding(false);
+
+    /// The url-safe alphabet (using `-` and `_`), with `=` padding.
+    pub const URL_SAFE: Self = Self::STANDARD.set_url_safe(true);
+
+    /// The url-safe alphabet (using `-` and `_`), without padding.
+    pub const URL_SAFE_NO_PAD: Self = Self::URL_SAFE.set_padding(false);
+
+    /// Sets whether the url-safe alphabet is used.
+    #[inline]
+    pub const fn set_url_safe(mut self, url_safe: bool) -> Self {
+        self.url_safe = url_safe;
+        self
+    }
+
+    /// Sets whether the encoded string is padded with `=` to a multiple of 4 bytes.
+    ///
+    /// When decoding, padding is required if this is `true`, and forbidden if it's `false`.
+    #[inline]
+    pub const fn set_padding(mut self, padding: bool) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Whether the url-safe alphabet is used.
+    #[inline]
+    pub const fn is_url_safe(self) -> bool {
+        self.url_safe
+    }
+
+    /// Whether the encoded string is padded with `=`.
+    #[inline]
+    pub const fn has_padding(self) -> bool {
+        self.padding
+    }
+
+    const fn alphabet(self) -> &'static [u8; 64] {
+        if self.url_safe {
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
+        } else {
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"
+        }
+    }
+
+    const fn decode_symbol(self, b: u8) -> Option<u8> {
+        match b {
+            b'A'..=b'Z' => Some(b - b'A'),
+            b'a'..=b'z' => Some(b - b'a' + 26),
+            b'0'..=b'9' => Some(b - b'0' + 52),
+            b'+' if !self.url_safe => Some(62),
+            b'/' if !self.url_safe => Some(63),
+            b'-' if self.url_safe => Some(62),
+            b'_' if self.url_safe => Some(63),
+            _ => None,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Converts the input of the encoding/decoding macros into a byte slice.
+pub struct BytesConv<T>(pub T);
+
+impl BytesConv<&'static str> {
+    pub const fn conv(self) -> &'static [u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl BytesConv<&'static [u8]> {
+    pub const fn conv(self) -> &'static [u8] {
+        self.0
+    }
+}
+
+impl<const N: usize> BytesConv<&'static [u8; N]> {
+    pub const fn conv(self) -> &'static [u8] {
+        self.0
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Whether the input of a decoding macro is valid,
+/// every variant with an error stores the offending byte offset.
+#[derive(Copy, Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum DecodeValidity {
+    Valid,
+    InvalidByte(usize),
+    OddLength(usize),
+    InvalidLength(usize),
+    InvalidPadding(usize),
+    TrailingBits(usize),
+}
+
+impl DecodeValidity {
+    pub const fn is_valid(self) -> bool {
+        matches!(self, Self::Valid)
+    }
+
+    pub const fn assert_valid(self) {
+        match self {
+            Self::Valid => (),
+            Self::InvalidByte(index) => [/*invalid byte for this encoding*/][index],
+            Self::OddLength(index) => [/*hex string has an odd amount of digits*/][index],
+            Self::InvalidLength(index) => [/*base64 string has an invalid length*/][index],
+            Self::InvalidPadding(index) => [/*base64 padding is missing or unexpected*/][index],
+            Self::TrailingBits(index) => [/*last base64 symbol has non-zero trailing bits*/][index],
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+pub struct HexEncodeArgs {
+    bytes: &'static [u8],
+    hex_fmt: HexFormatting,
+    case_is_valid: bool,
+    pub out_len: usize,
+}
+
+impl HexEncodeArgs {
+    pub const fn new(bytes: &'static [u8], case: Case) -> Self {
+        let (hex_fmt, case_is_valid) = match case {
+            Case::Lower => (HexFormatting::Lower, true),
+            Case::Upper => (HexFormatting::Upper, true),
+            _ => (HexFormatting::Lower, false),
+        };
+        Self {
+            bytes,
+            hex_fmt,
+            case_is_valid,
+            out_len: bytes.len() * 2,
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        if !self.case_is_valid {
+            let _: () = [/*only Case::Lower and Case::Upper can be used for hex*/][self.out_len];
+        }
+    }
+
+    pub const fn encode<const L: usize>(&self) -> [u8; L] {
+        let mut out = [0u8; L];
+        let mut out_i = 0;
+        iter_copy_slice! {b in self.bytes =>
+            out[out_i] = hex_as_ascii(b >> 4, self.hex_fmt);
+            out[out_i + 1] = hex_as_ascii(b & 0xF, self.hex_fmt);
+            out_i += 2;
+        }
+        out
+    }
+}
+
+pub struct HexDecodeArgs {
+    bytes: &'static [u8],
+    pub validity: DecodeValidity,
+    pub out_len: usize,
+}
+
+impl HexDecodeArgs {
+    pub const fn new(bytes: &'static [u8]) -> Self {
+        let mut validity = DecodeValidity::Valid;
+
+        let mut i = 0;
+        while i < bytes.len() {
+            if hex_digit_value(bytes[i]).is_none() {
+                validity = DecodeValidity::InvalidByte(i);
+                break;
+            }
+            i += 1;
+        }
+
+        if validity.is_valid() && bytes.len() % 2 == 1 {
+            validity = DecodeValidity::OddLength(bytes.len());
+        }
+
+        Self {
+            bytes,
+            validity,
+            out_len: if validity.is_valid() {
+                bytes.len() / 2
+            } else {
+                0
+            },
+        }
+    }
+
+    pub const fn decode<const L: usize>(&self) -> [u8; L] {
+        let mut out = [0u8; L];
+        __for_range! {i in 0..L =>
+            out[i] = (hex_val(self.bytes[2 * i]) << 4) | hex_val(self.bytes[2 * i + 1]);
+        }
+        out
+    }
+}
+
+const fn hex_val(b: u8) -> u8 {
+    match hex_digit_value(b) {
+        Some(x) => x,
+        None => 0,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+pub struct Base64EncodeArgs {
+    bytes: &'static [u8],
+    config: Base64Config,
+    pub out_len: usize,
+}
+
+impl Base64EncodeArgs {
+    pub const fn new(bytes: &'static [u8], config: Base64Config) -> Self {
+        let len = bytes.len();
+        let out_len = len / 3 * 4
+            + if config.padding {
+                [0, 4, 4][len % 3]
+            } else {
+                [0, 2, 3][len % 3]
+            };
+        Self {
+            bytes,
+            config,
+            out_len,
+        }
+    }
+
+    pub const fn encode<const L: usize>(&self) -> [u8; L] {
+        let alphabet = self.config.alphabet();
+        let bytes = self.bytes;
+        let mut out = [b'='; L];
+        let mut out_i = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let rem = bytes.len() - i;
+            let b0 = bytes[i];
+            let b1 = if rem > 1 { bytes[i + 1] } else { 0 };
+            let b2 = if rem > 2 { bytes[i + 2] } else { 0 };
+
+            let symbols = [
+                b0 >> 2,
+                ((b0 & 0b11) << 4) | (b1 >> 4),
+                ((b1 & 0b1111) << 2) | (b2 >> 6),
+                b2 & 0b11_1111,
+            ];
+            let written = if rem >= 3 { 4 } else { rem + 1 };
+
+            __for_range! {j in 0..written =>
+                out[out_i] = alphabet[symbols[j] as usize];
+                out_i += 1;
+            }
+
+            i += 3;
+        }
+
+        out
+    }
+}
+
+pub struct Base64DecodeArgs {
+    bytes: &'static [u8],
+    config: Base64Config,
+    pub validity: DecodeValidity,
+    pub out_len: usize,
+}
+
+impl Base64DecodeArgs {
+    pub const fn new(bytes: &'static [u8], config: Base64Config) -> Self {
+        let mut data_len = bytes.len();
+        while data_len > 0 && bytes[data_len - 1] == b'=' {
+            data_len -= 1;
+        }
+
+        let validity = base64_validity(bytes, data_len, config);
+
+        Self {
+            bytes,
+            config,
+            validity,
+            out_len: if validity.is_valid() {
+                data_len / 4 * 3 + [0, 0, 1, 2][data_len % 4]
+            } else {
+                0
+            },
+        }
+    }
+
+    pub const fn decode<const L: usize>(&self) -> [u8; L] {
+        let mut out = [0u8; L];
+        let mut out_i = 0;
+        let mut i = 0;
+
+        while out_i < L {
+            let mut group = 0u32;
+            __for_range! {j in 0..4 =>
+                let symbol = if i + j < self.bytes.len() {
+                    match self.config.decode_symbol(self.bytes[i + j]) {
+                        Some(x) => x,
+                        None => 0,
+                    }
+                } else {
+                    0
+                };
+                group = (group << 6) | symbol as u32;
+            }
+
+            __for_range! {j in 0..3 =>
+                if out_i < L {
+                    out[out_i] = (group >> (16 - 8 * j)) as u8;
+                    out_i += 1;
+                }
+            }
+
+            i += 4;
+        }
+
+        out
+    }
+}
+
+const fn base64_validity(bytes: &[u8], data_len: usize, config: Base64Config) -> DecodeValidity {
+    __for_range! {i in 0..data_len =>
+        if config.decode_symbol(bytes[i]).is_none() {
+            return DecodeValidity::InvalidByte(i);
+        }
+    }
+
+    if data_len % 4 == 1 {
+        return DecodeValidity::InvalidLength(data_len);
+    }
+
+    let padding = bytes.len() - data_len;
+    let expected_padding = if config.padding {
+        (4 - data_len % 4) % 4
+    } else {
+        0
+    };
+    if padding != expected_padding {
+        return DecodeValidity::InvalidPadding(data_len);
+    }
+
+    // The bits of the last symbol that don't make up a whole byte must be zero,
+    // so that every encoded string decodes to a different byte string.
+    let unused_bits_mask = [0, 0, 0b1111, 0b11][data_len % 4];
+    if unused_bits_mask != 0 {
+        if let Some(last) = config.decode_symbol(bytes[data_len - 1]) {
+            if last & unused_bits_mask != 0 {
+                return DecodeValidity::TrailingBits(data_len - 1);
+            }
+        }
+    }
+
+    DecodeValidity::Valid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Base64Config, Base64DecodeArgs, DecodeValidity, HexDecodeArgs};
+
+    #[test]
+    fn hex_validity() {
+        let cases: &[(&'static [u8], DecodeValidity)] = &[
+            (b"", DecodeValidity::Valid),
+            (b"0aF9", DecodeValidity::Valid),
+            (b"0aG9", DecodeValidity::InvalidByte(2)),
+            (b"0a 9", DecodeValidity::InvalidByte(2)),
+            (b"0aF", DecodeValidity::OddLength(3)),
+        ];
+
+        for (bytes, expected) in cases.iter().copied() {
+            assert_eq!(HexDecodeArgs::new(bytes).validity, expected, "{:?}", bytes);
+        }
+    }
+
+    #[test]
+    fn base64_validity() {
+        let pad = Base64Config::STANDARD;
+        let no_pad = Base64Config::STANDARD_NO_PAD;
+        let url = Base64Config::URL_SAFE;
+
+        let cases: &[(&'static [u8], Base64Config, DecodeValidity)] = &[
+            (b"", pad, DecodeValidity::Valid),
+            (b"Zm9v", pad, DecodeValidity::Valid),
+            (b"Zm8=", pad, DecodeValidity::Valid),
+            (b"Zg==", pad, DecodeValidity::Valid),
+            (b"Zg", no_pad, DecodeValidity::Valid),
+            (b"Zg", pad, DecodeValidity::InvalidPadding(2)),
+            (b"Zg==", no_pad, DecodeValidity::InvalidPadding(2)),
+            (b"Zg=", pad, DecodeValidity::InvalidPadding(2)),
+            (b"Zg=a", pad, DecodeValidity::InvalidByte(2)),
+            (b"Zm9vY", no_pad, DecodeValidity::InvalidLength(5)),
+            (b"Zh==", pad, DecodeValidity::TrailingBits(1)),
+            (b"Zm9=", pad, DecodeValidity::TrailingBits(2)),
+            (b"+/", no_pad, DecodeValidity::TrailingBits(1)),
+            (b"-_==", pad, DecodeValidity::InvalidByte(0)),
+            (b"-w==", url, DecodeValidity::Valid),
+            (b"+w==", url, DecodeValidity::InvalidByte(0)),
+        ];
+
+        for (bytes, config, expected) in cases.iter().copied() {
+            assert_eq!(
+                Base64DecodeArgs::new(bytes, config).validity,
+                expected,
+                "{:?} {:?}",
+                bytes,
+                config,
+            );
+        }
+    }
+}
@@ -23,3 +23,14 @@ pub union PtrToRef<'a, T: ?Sized> {
     pub ptr: *const T,
     pub reff: &'a T,
 }
+
+/// Converts an ascii hexadecimal digit (either case) to its numeric value.
+#[cfg(feature = "rust_1_51")]
+pub(crate) const fn hex_digit_value(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
@@ -4,6 +4,18 @@ mod str_replace;
 #[cfg(feature = "rust_1_51")]
 pub use self::str_replace::{ReplaceInput, ReplaceInputConv};
 
+#[cfg(feature = "rust_1_51")]
+mod str_unescape;
+
+#[cfg(feature = "rust_1_51")]
+pub use self::str_unescape::{UnescapeInput, UnescapeValidity};
+
+#[cfg(feature = "rust_1_51")]
+mod str_from_utf8;
+
+#[cfg(feature = "rust_1_51")]
+pub use self::str_from_utf8::{FromUtf8Input, Utf8Validity};
+
 mod str_repeat;
 pub use str_repeat::StrRepeatArgs;
 
@@ -0,0 +1,190 @@
+pub struct FromUtf8Input {
+    bytes: &'static [u8],
+    validity: Utf8Validity,
+    valid_up_to: usize,
+    lossy_len: usize,
+}
+
+/// Whether the bytes are valid utf8,
+/// every variant with an error stores the byte offset of the first invalid byte.
+#[derive(Copy, Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum Utf8Validity {
+    Valid,
+    InvalidSequence(usize),
+    IncompleteSequence(usize),
+}
+
+impl Utf8Validity {
+    pub const fn is_valid(self) -> bool {
+        matches!(self, Self::Valid)
+    }
+
+    pub const fn assert_valid(self) {
+        match self {
+            Self::Valid => (),
+            Self::InvalidSequence(index) => [/*invalid utf8 sequence*/][index],
+            Self::IncompleteSequence(index) => [/*incomplete utf8 sequence at the end*/][index],
+        }
+    }
+}
+
+/// The utf8 encoding of U+FFFD, the replacement character.
+const REPLACEMENT: [u8; 3] = [0xEF, 0xBF, 0xBD];
+
+impl FromUtf8Input {
+    pub const fn new(bytes: &'static [u8]) -> Self {
+        let mut validity = Utf8Validity::Valid;
+        let mut valid_up_to = bytes.len();
+        let mut lossy_len = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let (is_valid, len) = next_sequence(bytes, i);
+            if is_valid {
+                lossy_len += len;
+            } else {
+                if validity.is_valid() {
+                    // Sequences that start with a valid leading byte are only
+                    // cut short by a non-continuation byte, or by the end of the input.
+                    let is_leading = matches!(bytes[i], 0xC2..=0xF4);
+                    validity = if is_leading && i + len == bytes.len() {
+                        Utf8Validity::IncompleteSequence(i)
+                    } else {
+                        Utf8Validity::InvalidSequence(i)
+                    };
+                    valid_up_to = i;
+                }
+                lossy_len += REPLACEMENT.len();
+            }
+            i += len;
+        }
+
+        Self {
+            bytes,
+            validity,
+            valid_up_to,
+            lossy_len,
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        self.validity.assert_valid()
+    }
+
+    /// The length of the longest valid utf8 prefix of the bytes.
+    pub const fn valid_up_to(&self) -> usize {
+        self.valid_up_to
+    }
+
+    pub const fn lossy_length(&self) -> usize {
+        self.lossy_len
+    }
+
+    /// Copies the bytes up to the first invalid byte.
+    pub const fn valid_prefix<const L: usize>(&self) -> [u8; L] {
+        let mut out = [0u8; L];
+        __for_range! {i in 0..L =>
+            out[i] = self.bytes[i];
+        }
+        out
+    }
+
+    /// Copies the bytes, replacing each maximal invalid subsequence with U+FFFD,
+    /// the same way that `String::from_utf8_lossy` does it.
+    pub const fn lossy<const L: usize>(&self) -> [u8; L] {
+        let bytes = self.bytes;
+        let mut out = [0u8; L];
+        let mut out_i = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let (is_valid, len) = next_sequence(bytes, i);
+            if is_valid {
+                __for_range! {j in i..i + len =>
+                    out[out_i] = bytes[j];
+                    out_i += 1;
+                }
+            } else {
+                iter_copy_slice! {b in REPLACEMENT =>
+                    out[out_i] = b;
+                    out_i += 1;
+                }
+            }
+            i += len;
+        }
+
+        out
+    }
+}
+
+// Returns whether the sequence starting at `bytes[start]` is a valid utf8 encoded char,
+// and its length.
+//
+// For invalid sequences, the length is that of the longest prefix of a valid sequence,
+// or 1 if the first byte can't start a sequence.
+const fn next_sequence(bytes: &[u8], start: usize) -> (bool, usize) {
+    let b0 = bytes[start];
+
+    // The length of the sequence, and the range of valid values for the second byte,
+    // which excludes overlong encodings, surrogates, and code points above U+10FFFF.
+    let (len, second_min, second_max) = match b0 {
+        0x00..=0x7F => return (true, 1),
+        0xC2..=0xDF => (2, 0x80, 0xBF),
+        0xE0 => (3, 0xA0, 0xBF),
+        0xE1..=0xEC | 0xEE..=0xEF => (3, 0x80, 0xBF),
+        0xED => (3, 0x80, 0x9F),
+        0xF0 => (4, 0x90, 0xBF),
+        0xF1..=0xF3 => (4, 0x80, 0xBF),
+        0xF4 => (4, 0x80, 0x8F),
+        _ => return (false, 1),
+    };
+
+    let mut i = 1;
+    while i < len {
+        let (min, max) = if i == 1 {
+            (second_min, second_max)
+        } else {
+            (0x80, 0xBF)
+        };
+        if start + i == bytes.len() || bytes[start + i] < min || bytes[start + i] > max {
+            return (false, i);
+        }
+        i += 1;
+    }
+
+    (true, len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FromUtf8Input, Utf8Validity};
+
+    #[test]
+    fn validity_offsets() {
+        let cases: &[(&'static [u8], Utf8Validity)] = &[
+            (b"", Utf8Validity::Valid),
+            (
+                "foo\u{FF}\u{FFFF}\u{10FFFF}".as_bytes(),
+                Utf8Validity::Valid,
+            ),
+            (b"foo\x80", Utf8Validity::InvalidSequence(3)),
+            (b"ab\xC0\x80", Utf8Validity::InvalidSequence(2)),
+            (b"\xE0\x80\x80", Utf8Validity::InvalidSequence(0)),
+            (b"a\xED\xA0\x80", Utf8Validity::InvalidSequence(1)),
+            (b"\xF4\x90\x80\x80", Utf8Validity::InvalidSequence(0)),
+            (b"\xF5", Utf8Validity::InvalidSequence(0)),
+            (b"\xE2\x82x", Utf8Validity::InvalidSequence(0)),
+            (b"abc\xE2\x82", Utf8Validity::IncompleteSequence(3)),
+            (b"\xF0\x9F\x98", Utf8Validity::IncompleteSequence(0)),
+            (
+                b"\xF0\x9F\x98\x80\xFF\xF0",
+                Utf8Validity::InvalidSequence(4),
+            ),
+        ];
+
+        for (bytes, expected) in cases.iter().copied() {
+            assert_eq!(FromUtf8Input::new(bytes).validity, expected, "{:?}", bytes);
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{__hidden_utils::hex_digit_value, char_encoding::code_point_to_utf8};
+
+pub struct UnescapeInput {
+    str: &'static str,
+    validity: UnescapeValidity,
+    out_len: usize,
+}
+
+/// Whether the escape sequences in the string are valid,
+/// every variant with an error stores the byte offset of the `\` that starts
+/// the malformed escape sequence.
+#[derive(Copy, Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum UnescapeValidity {
+    Valid,
+    UnknownEscape(usize),
+    TrailingBackslash(usize),
+    InvalidHexEscape(usize),
+    HexEscapeOutOfRange(usize),
+    InvalidUnicodeEscape(usize),
+    InvalidCodePoint(usize),
+}
+
+impl UnescapeValidity {
+    pub const fn is_valid(self) -> bool {
+        matches!(self, Self::Valid)
+    }
+
+    pub const fn assert_valid(self) {
+        match self {
+            Self::Valid => (),
+            Self::UnknownEscape(index) => [/*unknown escape sequence*/][index],
+            Self::TrailingBackslash(index) => [/*backslash at the end of the string*/][index],
+            Self::InvalidHexEscape(index) => [/*expected two hex digits after `\x`*/][index],
+            Self::HexEscapeOutOfRange(index) => [/*`\x` escape is above `\x7F`*/][index],
+            Self::InvalidUnicodeEscape(index) => [/*malformed `\u{...}` escape*/][index],
+            Self::InvalidCodePoint(index) => [/*`\u{...}` escape isn't a valid char*/][index],
+        }
+    }
+}
+
+impl UnescapeInput {
+    pub const fn new(str: &'static str) -> Self {
+        let bytes = str.as_bytes();
+        let mut out_len = 0;
+        let mut validity = UnescapeValidity::Valid;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            if bytes[i] != b'\\' {
+                out_len += 1;
+                i += 1;
+                continue;
+            }
+
+            match unescape_at(bytes, i) {
+                Ok((code_point, next)) => {
+                    out_len += code_point_to_utf8(code_point).1;
+                    i = next;
+                }
+                Err(e) => {
+                    validity = e;
+                    break;
+                }
+            }
+        }
+
+        Self {
+            str,
+            validity,
+            out_len,
+        }
+    }
+
+    pub const fn assert_valid(&self) {
+        self.validity.assert_valid()
+    }
+
+    pub const fn unescaped_length(&self) -> usize {
+        self.out_len
+    }
+
+    pub const fn unescape<const L: usize>(&self) -> [u8; L] {
+        let bytes = self.str.as_bytes();
+        let mut out = [0u8; L];
+        let mut out_i = 0;
+        let mut i = 0;
+
+        // `out_len` only counts the bytes before the first invalid escape,
+        // so this stops at the same place that `new` did.
+        while out_i < L {
+            if bytes[i] != b'\\' {
+                out[out_i] = bytes[i];
+                out_i += 1;
+                i += 1;
+                continue;
+            }
+
+            if let Ok((code_point, next)) = unescape_at(bytes, i) {
+                let (encoded, len) = code_point_to_utf8(code_point);
+                __for_range! {j in 0..len =>
+                    out[out_i] = encoded[j];
+                    out_i += 1;
+                }
+                i = next;
+            } else {
+                break;
+            }
+        }
+
+        out
+    }
+}
+
+// Decodes the escape sequence starting with the `\` at `bytes[start]`,
+// returning the escaped code point and the index right after the escape sequence.
+const fn unescape_at(bytes: &[u8], start: usize) -> Result<(u32, usize), UnescapeValidity> {
+    let i = start + 1;
+    if i == bytes.len() {
+        return Err(UnescapeValidity::TrailingBackslash(start));
+    }
+
+    let simple = match bytes[i] {
+        b'n' => b'\n',
+        b't' => b'\t',
+        b'r' => b'\r',
+        b'0' => b'\0',
+        b'\\' => b'\\',
+        b'\'' => b'\'',
+        b'"' => b'"',
+        b'x' => {
+            if i + 2 >= bytes.len() {
+                return Err(UnescapeValidity::InvalidHexEscape(start));
+            }
+            let (hi, lo) = match (hex_digit_value(bytes[i + 1]), hex_digit_value(bytes[i + 2])) {
+                (Some(hi), Some(lo)) => (hi, lo),
+                _ => return Err(UnescapeValidity::InvalidHexEscape(start)),
+            };
+            if hi > 7 {
+                return Err(UnescapeValidity::HexEscapeOutOfRange(start));
+            }
+            return Ok((((hi << 4) | lo) as u32, i + 3));
+        }
+        b'u' => return unescape_unicode(bytes, start),
+        _ => return Err(UnescapeValidity::UnknownEscape(start)),
+    };
+
+    Ok((simple as u32, i + 1))
+}
+
+// Decodes a `\u{...}` escape, which has 1 to 6 hex digits and optional underscores
+// after the first digit, like in Rust string literals.
+const fn unescape_unicode(bytes: &[u8], start: usize) -> Result<(u32, usize), UnescapeValidity> {
+    let err = UnescapeValidity::InvalidUnicodeEscape(start);
+    let mut i = start + 2;
+
+    if i >= bytes.len() || bytes[i] != b'{' {
+        return Err(err);
+    }
+    i += 1;
+
+    let mut code_point = 0u32;
+    let mut digits = 0;
+    loop {
+        if i >= bytes.len() {
+            return Err(err);
+        }
+        match bytes[i] {
+            b'}' if digits != 0 => break,
+            b'_' if digits != 0 => {}
+            b => match hex_digit_value(b) {
+                Some(digit) if digits < 6 => {
+                    code_point = (code_point << 4) | digit as u32;
+                    digits += 1;
+                }
+                _ => return Err(err),
+            },
+        }
+        i += 1;
+    }
+
+    match code_point {
+        0xD800..=0xDFFF | 0x110000..=u32::MAX => Err(UnescapeValidity::InvalidCodePoint(start)),
+        _ => Ok((code_point, i + 1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UnescapeInput, UnescapeValidity};
+
+    #[test]
+    fn validity_offsets() {
+        let cases: &[(&'static str, UnescapeValidity)] = &[
+            ("foo\\n\\u{41}", UnescapeValidity::Valid),
+            ("foo\\q", UnescapeValidity::UnknownEscape(3)),
+            ("ab\\", UnescapeValidity::TrailingBackslash(2)),
+            ("\\x4", UnescapeValidity::InvalidHexEscape(0)),
+            ("a\\x4g", UnescapeValidity::InvalidHexEscape(1)),
+            ("ñ\\x80", UnescapeValidity::HexEscapeOutOfRange(2)),
+            ("\\u41", UnescapeValidity::InvalidUnicodeEscape(0)),
+            ("\\u{}", UnescapeValidity::InvalidUnicodeEscape(0)),
+            ("\\u{_1}", UnescapeValidity::InvalidUnicodeEscape(0)),
+            ("\\u{1234567}", UnescapeValidity::InvalidUnicodeEscape(0)),
+            ("\\u{41", UnescapeValidity::InvalidUnicodeEscape(0)),
+            ("xy\\u{D800}", UnescapeValidity::InvalidCodePoint(2)),
+            ("\\u{110000}", UnescapeValidity::InvalidCodePoint(0)),
+        ];
+
+        for (str, expected) in cases.iter().copied() {
+            assert_eq!(UnescapeInput::new(str).validity, expected, "{:?}", str);
+        }
+    }
+}
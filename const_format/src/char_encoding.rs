@@ -1,5 +1,7 @@
 use crate::formatting::{hex_as_ascii, HexFormatting};
 
+mod unicode_tables;
+
 #[cfg(any(test, feature = "fmt"))]
 pub(crate) const fn char_display_len(c: char) -> usize {
     match c as u32 {
@@ -12,16 +14,15 @@ pub(crate) const fn char_display_len(c: char) -> usize {
 
 #[cfg(any(test, feature = "fmt"))]
 pub(crate) const fn char_debug_len(c: char) -> usize {
-    let inner = match c {
-        '\t' | '\r' | '\n' | '\\' | '\'' | '\"' => 2,
-        '\x00'..='\x1F' => 4,
-        _ => char_display_len(c),
-    };
-    inner + 2
+    char_to_debug(c).len()
 }
 
 const fn char_to_utf8(char: char) -> ([u8; 4], usize) {
-    let u32 = char as u32;
+    code_point_to_utf8(char as u32)
+}
+
+/// Encodes `u32` as utf8, the caller must ensure that it's a valid unicode scalar value.
+pub(crate) const fn code_point_to_utf8(u32: u32) -> ([u8; 4], usize) {
     match u32 {
         0..=127 => ([u32 as u8, 0, 0, 0], 1),
         0x80..=0x7FF => {
@@ -45,38 +46,120 @@ const fn char_to_utf8(char: char) -> ([u8; 4], usize) {
     }
 }
 
+/// Decodes the utf8 encoded char that starts at `bytes[index]`,
+/// returning the code point and its length in bytes.
+///
+/// This returns `None` if the bytes up to `end` aren't a valid utf8 encoded char,
+/// which only happens when `index..end` isn't a range of char boundaries.
+const fn utf8_to_code_point(bytes: &[u8], index: usize, end: usize) -> Option<(u32, usize)> {
+    let b0 = bytes[index];
+    let (len, mut code_point) = match b0 {
+        0..=0x7F => return Some((b0 as u32, 1)),
+        0xC0..=0xDF => (2, (b0 & 0b1_1111) as u32),
+        0xE0..=0xEF => (3, (b0 & 0b1111) as u32),
+        0xF0..=0xF7 => (4, (b0 & 0b111) as u32),
+        _ => return None,
+    };
+
+    if index + len > end {
+        return None;
+    }
+
+    __for_range! {i in index + 1..index + len =>
+        let b = bytes[i];
+        if b & 0b1100_0000 != 0b1000_0000 {
+            return None;
+        }
+        code_point = (code_point << 6) | (b & 0b11_1111) as u32;
+    }
+
+    Some((code_point, len))
+}
+
+const fn in_ranges(ranges: &[(u32, u32)], c: u32) -> bool {
+    let mut low = 0;
+    let mut high = ranges.len();
+    while low < high {
+        let mid = (low + high) / 2;
+        let (start, end) = ranges[mid];
+        if c < start {
+            high = mid;
+        } else if c > end {
+            low = mid + 1;
+        } else {
+            return true;
+        }
+    }
+    false
+}
+
+/// Whether `c` is printable, the same way that `core` determines it when debug formatting.
+pub(crate) const fn is_printable(c: u32) -> bool {
+    match c {
+        0x20..=0x7E => true,
+        0..=0x7F => false,
+        _ => !in_ranges(unicode_tables::NON_PRINTABLE, c),
+    }
+}
+
+/// Whether `c` has the `Grapheme_Extend` unicode property.
+pub(crate) const fn is_grapheme_extended(c: u32) -> bool {
+    c >= 0x80 && in_ranges(unicode_tables::GRAPHEME_EXTEND, c)
+}
+
+/// Escapes `c` the same way that `core` does it when debug formatting
+/// `char`s (`in_str == false`) and strings (`in_str == true`).
+///
+/// Returns the escaped char, and its length.
+const fn escape_debug(c: u32, in_str: bool) -> ([u8; 10], usize) {
+    let escape = match c {
+        0x00 => b'0',
+        0x09 => b't',
+        0x0A => b'n',
+        0x0D => b'r',
+        0x5C => b'\\',
+        0x22 if in_str => b'"',
+        0x27 if !in_str => b'\'',
+        _ if !is_printable(c) || is_grapheme_extended(c) => return unicode_escape(c),
+        _ => {
+            let ([b0, b1, b2, b3], len) = code_point_to_utf8(c);
+            return ([b0, b1, b2, b3, 0, 0, 0, 0, 0, 0], len);
+        }
+    };
+    ([b'\\', escape, 0, 0, 0, 0, 0, 0, 0, 0], 2)
+}
+
+// Escapes `c` as `\u{...}`, with lowercase hexadecimal digits.
+const fn unicode_escape(c: u32) -> ([u8; 10], usize) {
+    let mut digits = 1;
+    while digits < 6 && (c >> (4 * digits)) != 0 {
+        digits += 1;
+    }
+
+    let mut out = [b'\\', b'u', b'{', 0, 0, 0, 0, 0, 0, 0];
+    __for_range! {i in 0..digits =>
+        let nibble = ((c >> (4 * (digits - 1 - i))) & 0xF) as u8;
+        out[3 + i] = hex_as_ascii(nibble, HexFormatting::Lower);
+    }
+    out[3 + digits] = b'}';
+    (out, digits + 4)
+}
+
 pub(crate) const fn char_to_display(char: char) -> FmtChar {
     let ([b0, b1, b2, b3], len) = char_to_utf8(char);
     FmtChar {
-        encoded: [b0, b1, b2, b3, 0, 0],
+        encoded: [b0, b1, b2, b3, 0, 0, 0, 0, 0, 0, 0, 0],
         len: len as u8,
     }
 }
 
 pub(crate) const fn char_to_debug(c: char) -> FmtChar {
-    let ([b0, b1, b2, b3], len) = match c {
-        '\t' => (*br#"\t  "#, 2),
-        '\r' => (*br#"\r  "#, 2),
-        '\n' => (*br#"\n  "#, 2),
-        '\\' => (*br#"\\  "#, 2),
-        '\'' => (*br#"\'  "#, 2),
-        '\"' => (*br#"\"  "#, 2),
-        '\x00'..='\x1F' => {
-            let n = c as u8;
-            (
-                [
-                    b'\\',
-                    b'x',
-                    hex_as_ascii(n >> 4, HexFormatting::Upper),
-                    hex_as_ascii(n & 0b1111, HexFormatting::Upper),
-                ],
-                4,
-            )
-        }
-        _ => char_to_utf8(c),
-    };
+    let (escaped, len) = escape_debug(c as u32, false);
 
-    let mut encoded = [b'\'', b0, b1, b2, b3, 0];
+    let mut encoded = [b'\'', 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+    __for_range! {i in 0..len =>
+        encoded[i + 1] = escaped[i];
+    }
     encoded[len + 1] = b'\'';
 
     FmtChar {
@@ -85,16 +168,112 @@ pub(crate) const fn char_to_debug(c: char) -> FmtChar {
     }
 }
 
+/// Debug formats the char that starts at `bytes[index]`,
+/// as an element of a debug formatted string (without the quotes).
+///
+/// Returns the formatted char, and the length of the unformatted char in `bytes`.
+///
+/// If `bytes[index..end]` doesn't start with a utf8 encoded char
+/// (eg: `index` isn't a char boundary), this copies a single byte without escaping it.
+pub const fn str_char_to_debug(bytes: &[u8], index: usize, end: usize) -> (FmtChar, usize) {
+    match utf8_to_code_point(bytes, index, end) {
+        Some((c, char_len)) => {
+            let ([b0, b1, b2, b3, b4, b5, b6, b7, b8, b9], len) = escape_debug(c, true);
+            let encoded = [b0, b1, b2, b3, b4, b5, b6, b7, b8, b9, 0, 0];
+            (
+                FmtChar {
+                    encoded,
+                    len: len as u8,
+                },
+                char_len,
+            )
+        }
+        None => {
+            let mut encoded = [0u8; 12];
+            encoded[0] = bytes[index];
+            (FmtChar { encoded, len: 1 }, 1)
+        }
+    }
+}
+
+/// Computes the length of `bytes[start..end]` as a debug formatted string,
+/// including the quotes.
+pub(crate) const fn str_debug_len(bytes: &[u8], mut start: usize, end: usize) -> usize {
+    let mut sum = 2;
+    while start < end {
+        match bytes[start] {
+            b'"' | b'\\' => {
+                sum += 2;
+                start += 1;
+            }
+            0x20..=0x7E => {
+                sum += 1;
+                start += 1;
+            }
+            _ => {
+                let (fmt, char_len) = str_char_to_debug(bytes, start, end);
+                sum += fmt.len();
+                start += char_len;
+            }
+        }
+    }
+    sum
+}
+
+/// Escapes `b` the same way that `u8::escape_ascii` does it,
+/// returning the escaped byte and its length.
+#[cfg(any(test, feature = "fmt"))]
+pub(crate) const fn byte_to_escaped_ascii(b: u8) -> ([u8; 4], usize) {
+    let escape = match b {
+        b'\t' => b't',
+        b'\r' => b'r',
+        b'\n' => b'n',
+        b'\\' | b'\'' | b'"' => b,
+        0x20..=0x7E => return ([b, 0, 0, 0], 1),
+        _ => {
+            let hi = hex_as_ascii(b >> 4, HexFormatting::Lower);
+            let lo = hex_as_ascii(b & 0xF, HexFormatting::Lower);
+            return ([b'\\', b'x', hi, lo], 4);
+        }
+    };
+    ([b'\\', escape, 0, 0], 2)
+}
+
+/// Computes the length of `bytes` as a debug formatted byte string (eg: `b"foo\n"`).
+#[cfg(any(test, feature = "fmt"))]
+pub(crate) const fn bytes_debug_len(bytes: &[u8]) -> usize {
+    let mut sum = 3;
+    iter_copy_slice! {b in bytes =>
+        sum += byte_to_escaped_ascii(b).1;
+    }
+    sum
+}
+
+/// Computes the length of `bytes` when written as a hexdump,
+/// with 16 bytes per line.
+#[cfg(any(test, feature = "fmt"))]
+pub(crate) const fn bytes_hexdump_len(bytes: &[u8]) -> usize {
+    if bytes.is_empty() {
+        return 0;
+    }
+    let lines = (bytes.len() - 1) / 16 + 1;
+    lines * HEXDUMP_LINE_OVERHEAD + bytes.len() + (lines - 1)
+}
+
+/// The length of a hexdump line, excluding the ascii column and the newline.
+#[cfg(any(test, feature = "fmt"))]
+pub(crate) const HEXDUMP_LINE_OVERHEAD: usize = 62;
+
 #[derive(Copy, Clone)]
 pub struct FmtChar {
-    encoded: [u8; 6],
+    encoded: [u8; 12],
     len: u8,
 }
 
 impl FmtChar {
     /// Array which contains the pre-len display/debug-formatted  `char`,
     /// only `&self.encoded[][..self.len()]` should be copied.
-    pub const fn encoded(&self) -> &[u8; 6] {
+    pub const fn encoded(&self) -> &[u8; 12] {
         &self.encoded
     }
 
@@ -105,29 +284,33 @@ impl FmtChar {
     pub(crate) const fn as_bytes(&self) -> &[u8] {
         #[cfg(not(feature = "rust_1_64"))]
         {
-            match self.len() {
-                1 => {
-                    let [ret @ .., _, _, _, _, _] = &self.encoded;
-                    ret
-                }
-                2 => {
-                    let [ret @ .., _, _, _, _] = &self.encoded;
-                    ret
-                }
-                3 => {
-                    let [ret @ .., _, _, _] = &self.encoded;
-                    ret
-                }
-                4 => {
-                    let [ret @ .., _, _] = &self.encoded;
-                    ret
-                }
-                5 => {
-                    let [ret @ .., _] = &self.encoded;
-                    ret
-                }
-                6 => &self.encoded,
-                x => [/*bug WTF*/][x],
+            macro_rules! slice_up_to_len {
+                ($($len:literal => [$($ignored:tt)*],)*) => {
+                    match self.len() {
+                        $(
+                            $len => {
+                                let [ret @ .., $($ignored)*] = &self.encoded;
+                                ret
+                            }
+                        )*
+                        12 => &self.encoded,
+                        x => [/*bug WTF*/][x],
+                    }
+                };
+            }
+
+            slice_up_to_len! {
+                1 => [_, _, _, _, _, _, _, _, _, _, _],
+                2 => [_, _, _, _, _, _, _, _, _, _],
+                3 => [_, _, _, _, _, _, _, _, _],
+                4 => [_, _, _, _, _, _, _, _],
+                5 => [_, _, _, _, _, _, _],
+                6 => [_, _, _, _, _, _],
+                7 => [_, _, _, _, _],
+                8 => [_, _, _, _],
+                9 => [_, _, _],
+                10 => [_, _],
+                11 => [_],
             }
         }
 
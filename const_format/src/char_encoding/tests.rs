@@ -1,4 +1,11 @@
-use super::{char_debug_len, char_display_len, char_to_debug, char_to_display};
+use super::{
+    char_debug_len, char_display_len, char_to_debug, char_to_display, str_char_to_debug,
+    str_debug_len,
+};
+
+use crate::test_utils::std_debug_changed;
+
+use core::fmt::Write;
 
 #[test]
 fn char_to_utf8_encoding_test() {
@@ -28,66 +35,118 @@ fn char_to_utf8_display_test() {
 
 #[test]
 fn char_to_utf8_debug_test() {
-    let first_escapes = [
-        ('\x00', r#"'\x00'"#),
-        ('\x01', r#"'\x01'"#),
-        ('\x02', r#"'\x02'"#),
-        ('\x03', r#"'\x03'"#),
-        ('\x04', r#"'\x04'"#),
-        ('\x05', r#"'\x05'"#),
-        ('\x06', r#"'\x06'"#),
-        ('\x07', r#"'\x07'"#),
-        ('\x08', r#"'\x08'"#),
-        ('\t', r#"'\t'"#),
-        ('\n', r#"'\n'"#),
-        ('\x0B', r#"'\x0B'"#),
-        ('\x0C', r#"'\x0C'"#),
-        ('\r', r#"'\r'"#),
-        ('\x0E', r#"'\x0E'"#),
-        ('\x0F', r#"'\x0F'"#),
-        ('\x10', r#"'\x10'"#),
-        ('\x11', r#"'\x11'"#),
-        ('\x12', r#"'\x12'"#),
-        ('\x13', r#"'\x13'"#),
-        ('\x14', r#"'\x14'"#),
-        ('\x15', r#"'\x15'"#),
-        ('\x16', r#"'\x16'"#),
-        ('\x17', r#"'\x17'"#),
-        ('\x18', r#"'\x18'"#),
-        ('\x19', r#"'\x19'"#),
-        ('\x1A', r#"'\x1A'"#),
-        ('\x1B', r#"'\x1B'"#),
-        ('\x1C', r#"'\x1C'"#),
-        ('\x1D', r#"'\x1D'"#),
-        ('\x1E', r#"'\x1E'"#),
-        ('\x1F', r#"'\x1F'"#),
-    ];
-
-    for (c, expected) in first_escapes.iter().copied() {
+    let mut buffer = arrayvec::ArrayString::<[u8; 16]>::new();
+    for c in '\0'..=core::char::MAX {
         let utf8_here = char_to_debug(c);
-        assert_eq!(expected.as_bytes(), utf8_here.as_bytes(), "{:?}", c);
-        assert_eq!(expected.len(), char_debug_len(c), "{:?}", c);
+        assert_eq!(utf8_here.len(), char_debug_len(c), "{:?}", c);
+
+        // these are tested in `std_debug_changed_test`
+        if std_debug_changed(c) {
+            continue;
+        }
+
+        buffer.clear();
+        write!(buffer, "{:?}", c).unwrap();
+
+        assert_eq!(buffer.as_bytes(), utf8_here.as_bytes(), "{:?}", c);
     }
+}
 
-    let other_escapes = [('\'', r#"'\''"#), ('\"', r#"'\"'"#), ('\\', r#"'\\'"#)];
+#[test]
+fn str_char_to_debug_test() {
+    let mut expected = arrayvec::ArrayString::<[u8; 32]>::new();
+    let mut buffer = arrayvec::ArrayString::<[u8; 32]>::new();
+    for c in '\0'..=core::char::MAX {
+        // The char is after another one to test that grapheme extenders
+        // are escaped everywhere in the string, not only at the start.
+        buffer.clear();
+        buffer.push('a');
+        buffer.push(c);
 
-    let mut buffer = arrayvec::ArrayString::<[u8; 12]>::new();
-    for c in '\x20'..=core::char::MAX {
-        let utf8_here = char_to_debug(c);
+        let bytes = buffer.as_bytes();
+        let (fmt, char_len) = str_char_to_debug(bytes, 1, bytes.len());
+        assert_eq!(char_len, c.len_utf8(), "{:?}", c);
+        assert_eq!(
+            fmt.len() + 3,
+            str_debug_len(bytes, 0, bytes.len()),
+            "{:?}",
+            c
+        );
 
-        if let Some((_, expected)) = Some(c)
-            .filter(|c| *c <= '\x7F')
-            .and_then(|c| other_escapes.iter().copied().find(|x| x.0 == c))
-        {
-            assert_eq!(expected.as_bytes(), utf8_here.as_bytes(), "{:?}", c);
-            assert_eq!(expected.len(), char_debug_len(c), "{:?}", c);
-        } else {
-            buffer.clear();
-            buffer.push('\'');
-            buffer.push(c);
-            buffer.push('\'');
-            assert_eq!(buffer.as_bytes(), utf8_here.as_bytes(), "{:?}", c);
-            assert_eq!(buffer.len(), char_debug_len(c), "{:?}", c);
+        // these are tested in `std_debug_changed_test`
+        if std_debug_changed(c) {
+            continue;
         }
+
+        expected.clear();
+        write!(expected, "{:?}", &buffer[..]).unwrap();
+
+        assert_eq!(
+            &expected.as_bytes()[2..expected.len() - 1],
+            fmt.as_bytes(),
+            "{:?}",
+            c
+        );
+    }
+}
+
+#[test]
+fn std_debug_changed_test() {
+    // (char, debug formatted char, debug formatted char in a string)
+    let chars = [
+        ('\0', r"'\0'", r"\0"),
+        ('"', r#"'"'"#, r#"\""#),
+        ('\'', r"'\''", "'"),
+        ('\u{61D}', "'\u{61D}'", "\u{61D}"),
+        ('\u{CC0}', r"'\u{cc0}'", r"\u{cc0}"),
+        ('\u{1B4E}', "'\u{1B4E}'", "\u{1B4E}"),
+        ('\u{1FAE9}', "'\u{1FAE9}'", "\u{1FAE9}"),
+        ('\u{31350}', "'\u{31350}'", "\u{31350}"),
+    ];
+
+    for &(c, char_expected, str_expected) in chars.iter() {
+        assert!(std_debug_changed(c), "{:?}", c);
+
+        assert_eq!(
+            char_to_debug(c).as_bytes(),
+            char_expected.as_bytes(),
+            "{:?}",
+            c
+        );
+        assert_eq!(char_debug_len(c), char_expected.len(), "{:?}", c);
+
+        let mut buffer = arrayvec::ArrayString::<[u8; 8]>::new();
+        buffer.push('a');
+        buffer.push(c);
+        let bytes = buffer.as_bytes();
+        let (fmt, _) = str_char_to_debug(bytes, 1, bytes.len());
+        assert_eq!(fmt.as_bytes(), str_expected.as_bytes(), "{:?}", c);
+        assert_eq!(
+            str_debug_len(bytes, 0, bytes.len()),
+            str_expected.len() + 3,
+            "{:?}",
+            c
+        );
+    }
+}
+
+#[test]
+fn str_debug_len_test() {
+    let strings = [
+        ("", r#""""#),
+        ("hello", r#""hello""#),
+        ("\0\t\r\n\\'\"\x01\x7F", r#""\0\t\r\n\\'\"\u{1}\u{7f}""#),
+        (
+            "a\u{301}\u{80}\u{A0}\u{AD}ñ个\u{200B}\u{FFFE}\u{E0001}\u{10FFFF}🧡",
+            r#""a\u{301}\u{80}\u{a0}\u{ad}ñ个\u{200b}\u{fffe}\u{e0001}\u{10ffff}🧡""#,
+        ),
+    ];
+    for &(str, expected) in strings.iter() {
+        assert_eq!(
+            expected.len(),
+            str_debug_len(str.as_bytes(), 0, str.len()),
+            "{:?}",
+            str
+        );
     }
 }
@@ -2,14 +2,17 @@
 ///
 /// Derives the [`FormatMarker`] trait, and defines an `const_debug_fmt` inherent
 /// method to format a type at compile-time.
+///
+/// With the "nightly_const_traits" feature,
+/// this also implements the [`ConstDebug`](./fmt/trait.ConstDebug.html) const trait
+/// for every impl of the `const_debug_fmt` method.
 /// 
 /// # Features 
 /// 
 /// This derive macro is only available with the "derive" feature,
-/// and the nightly compiler,
-/// because at the time of writing these docs (2021-08-XX) mutable references in const fn
-/// require the unstable
-/// [`const_mut_refs`](https://github.com/rust-lang/rust/issues/57349) feature.
+/// and either Rust 1.83.0 with the "rust_1_83" feature, or the nightly compiler,
+/// because it uses mutable references in const fn,
+/// which were stabilized in Rust 1.83.0.
 ///
 /// # Limitations
 ///
@@ -23,6 +26,9 @@
 ///
 /// - Provide all the implementations ahead of time, what the [`impls attribute`] is for.
 ///
+/// - On Rust nightly, use the [`const_trait_bounds attribute`]
+/// to format the fields through the [`ConstDebug` trait] instead.
+///
 /// - Provide a macro that formats the type.
 /// The `call_debug_fmt` macro is a version of this that formats generic std types,
 /// then it can be used to format fields of the type with the 
@@ -55,7 +61,6 @@
 /// Example:
 /// 
 /// ```rust
-/// # #![feature(const_mut_refs)]
 /// #[derive(const_format::ConstDebug)]
 /// #[cdeb(impls(
 ///     "Foo<u8, u64>",
@@ -78,6 +83,110 @@
 /// cfmt = {version = "0.*", package = "const_format"}
 /// ```
 ///
+/// <span id = "cdeb_non_exhaustive"> </span>
+///
+/// ### `#[cdeb(non_exhaustive)]`
+///
+/// Writes a `..` after the fields of the struct (or every variant of the enum),
+/// to show that some fields are not printed (eg: fields with the `#[cdeb(ignore)]` attribute).
+///
+/// Example:
+///
+/// ```rust
+/// use const_format::{ConstDebug, formatc};
+///
+/// #[derive(ConstDebug)]
+/// #[cdeb(non_exhaustive)]
+/// struct Config {
+///     name: &'static str,
+///     #[cdeb(ignore)]
+///     password: &'static str,
+/// }
+///
+/// const CONFIG: Config = Config { name: "foo", password: "hunter2" };
+///
+/// assert_eq!(formatc!("{:?}", CONFIG), r#"Config { name: "foo", .. }"#);
+/// ```
+///
+/// ### `#[cdeb(std_debug)]`
+///
+/// Also implements [`core::fmt::Debug`] for the type (or every type in the
+/// [`impls attribute`]), delegating to the derived `const_debug_fmt` method,
+/// so that runtime and compile-time debug formatting always agree.
+///
+/// This uses [`ConstFmtAdapter`], which formats the value into a 512 bytes buffer,
+/// formatting it again if the output is longer
+/// (into a heap-allocated buffer with the "alloc" feature, otherwise in multiple chunks).
+/// The length of the buffer can be changed with
+/// `#[cdeb(std_debug(capacity = 4096))]`,
+/// where the capacity is an integer or a string literal containing a constant expression.
+///
+/// Example:
+///
+/// ```rust
+/// use const_format::{ConstDebug, formatc};
+///
+/// #[derive(ConstDebug)]
+/// #[cdeb(std_debug)]
+/// struct Config {
+///     name: &'static str,
+///     #[cdeb(ignore)]
+///     password: &'static str,
+/// }
+///
+/// const CONFIG: Config = Config { name: "foo", password: "hunter2" };
+///
+/// assert_eq!(formatc!("{:?}", CONFIG), r#"Config { name: "foo" }"#);
+/// assert_eq!(format!("{:?}", CONFIG), r#"Config { name: "foo" }"#);
+/// ```
+///
+/// ### `#[cdeb(const_trait_bounds)]`
+///
+/// Implements debug formatting for all the instances of a generic type at once,
+/// by formatting the fields that use type parameters with the [`ConstDebug` trait],
+/// and requiring the types of those fields to implement it
+/// (eg: `T: [const] ConstDebug` for a field of type `T`).
+///
+/// This requires the "nightly_const_traits" feature,
+/// and `#![feature(const_trait_impl)]` in the crate that uses the attribute.
+/// It can't be used with the [`impls attribute`].
+///
+/// Example:
+///
+#[cfg_attr(feature = "nightly_const_traits", doc = "```rust")]
+#[cfg_attr(not(feature = "nightly_const_traits"), doc = "```ignore")]
+/// #![feature(const_trait_impl)]
+///
+/// use const_format::{ConstDebug, formatc};
+///
+/// #[derive(ConstDebug)]
+/// #[cdeb(const_trait_bounds)]
+/// struct Pair<A, B> {
+///     left: A,
+///     right: Option<B>,
+///     count: u32,
+/// }
+///
+/// const PAIR: Pair<u8, Pair<&str, char>> = Pair {
+///     left: 3,
+///     right: Some(Pair { left: "foo", right: None, count: 0 }),
+///     count: 1,
+/// };
+///
+/// assert_eq!(
+///     formatc!("{:?}", PAIR),
+///     r#"Pair { left: 3, right: Some(Pair { left: "foo", right: None, count: 0 }), count: 1 }"#,
+/// );
+/// ```
+///
+/// # Variant attributes
+///
+/// These attributes go on the variants of an enum.
+///
+/// ### `#[cdeb(non_exhaustive)]`
+///
+/// Like the [container attribute](#cdeb_non_exhaustive), but only for this variant.
+///
 /// # Field attributes
 ///
 /// ### `#[cdeb(ignore)]`
@@ -153,8 +262,6 @@
 /// This example demonstrates using the derive without using any helper attributes.
 /// 
 /// ```rust
-/// #![feature(const_mut_refs)]
-/// 
 /// use const_format::{ConstDebug, formatc};
 /// 
 /// use std::cmp::Ordering;
@@ -201,8 +308,6 @@
 /// specifying a list of impls of types that unconditionally implement const debug formatting
 /// 
 /// ```rust
-/// #![feature(const_mut_refs)]
-/// 
 /// use const_format::{ConstDebug, formatc};
 /// 
 /// use std::marker::PhantomData;
@@ -238,8 +343,6 @@
 /// This example demonstrates when you would use the `is_a` attributes.
 /// 
 /// ```rust
-/// #![feature(const_mut_refs)]
-/// 
 /// use const_format::{ConstDebug, formatc};
 /// 
 /// use std::{
@@ -321,6 +424,9 @@
 ///
 /// [`FormatMarker`]: ./marker_traits/trait.FormatMarker.html
 /// [`impls attribute`]: #cdebimpls
+/// [`const_trait_bounds attribute`]: #cdebconst_trait_bounds
+/// [`ConstDebug` trait]: ./fmt/trait.ConstDebug.html
+/// [`ConstFmtAdapter`]: ./fmt/struct.ConstFmtAdapter.html
 ///
 ///
 ///
@@ -331,7 +437,6 @@
 /// crate is renamed.
 /// 
 /// ```rust
-/// #![feature(const_mut_refs)]
 /// # extern crate self as const_format;
 /// # extern crate const_format as cfmt;
 /// # fn main() {
@@ -0,0 +1,107 @@
+/// Derives const display formatting for a type, from a format string.
+///
+/// Defines a `const_display_fmt` inherent method that writes the fields of the type
+/// with the format string passed in the `#[cdisplay("...")]` attribute.
+///
+/// This doesn't implement the [`FormatMarker`] trait, which is required to
+/// format the type with the [`formatc`] macro.
+/// You can get it by also deriving [`ConstDebug`], or with the [`impl_fmt`] macro.
+///
+/// With the "nightly_const_traits" feature,
+/// this also implements the [`ConstDisplay`](./fmt/trait.ConstDisplay.html) const trait
+/// for every impl of the `const_display_fmt` method.
+///
+/// # Features
+///
+/// This derive macro is only available with the "derive" feature,
+/// and either Rust 1.83.0 with the "rust_1_83" feature, or the nightly compiler,
+/// because it uses mutable references in const fn,
+/// which were stabilized in Rust 1.83.0.
+///
+/// # Format string
+///
+/// The format string uses the same syntax as the [`formatc`] macro,
+/// where the arguments are the fields of the struct or variant:
+///
+/// - `{field}`: the field named `field` of a braced struct or variant.
+///
+/// - `{0}`, `{1}`, `{}`: the fields of a tuple struct or variant, by position.
+///
+/// Names that aren't fields are assumed to be constants in scope, like in [`formatc`].
+///
+/// The format string goes on the type for structs,
+/// and on each variant for enums.
+///
+/// # Container Attributes
+///
+/// These attributes go on the type itself, rather than the variants.
+///
+/// ### `#[cdisplay(debug_print)]`
+///
+/// Panics with the output of the expanded derive.
+///
+/// ### `#[cdisplay(impls(....))]`
+///
+/// Implements const display formatting for multiple different
+/// concrete instances of the type,
+/// like the [`#[cdeb(impls(....))]`](./derive.ConstDebug.html#cdebimpls) attribute.
+///
+/// ### `#[cdisplay(crate = "foo::bar")]`
+///
+/// The path to the `const_format` crate, useful if you want to reexport this macro,
+/// or rename the `const_format` crate in the Cargo.toml .
+///
+/// # Examples
+///
+/// ### Struct
+///
+/// ```rust
+/// use const_format::{ConstDebug, ConstDisplay, formatc};
+///
+/// #[derive(ConstDebug, ConstDisplay)]
+/// #[cdisplay("{host}:{port}")]
+/// struct Address {
+///     host: &'static str,
+///     port: u16,
+/// }
+///
+/// const ADDR: Address = Address { host: "localhost", port: 8080 };
+///
+/// assert_eq!(formatc!("{}", ADDR), "localhost:8080");
+/// assert_eq!(formatc!("{:?}", ADDR), r#"Address { host: "localhost", port: 8080 }"#);
+///
+/// ```
+///
+/// ### Enum
+///
+/// ```rust
+/// use const_format::{ConstDebug, ConstDisplay, formatc};
+///
+/// #[derive(ConstDebug, ConstDisplay)]
+/// enum Error {
+///     #[cdisplay("expected at most {MAX_LEN} bytes, found {len}")]
+///     TooLong { len: usize },
+///     #[cdisplay("invalid byte {1:#x} at {0}")]
+///     InvalidByte(usize, u8),
+///     #[cdisplay("unexpected end of input")]
+///     Eof,
+/// }
+///
+/// const MAX_LEN: usize = 16;
+///
+/// assert_eq!(
+///     formatc!("{}", Error::TooLong { len: 20 }),
+///     "expected at most 16 bytes, found 20",
+/// );
+/// assert_eq!(formatc!("{}", Error::InvalidByte(3, 255)), "invalid byte 0xff at 3");
+/// assert_eq!(formatc!("{}", Error::Eof), "unexpected end of input");
+///
+/// ```
+///
+/// [`FormatMarker`]: ./marker_traits/trait.FormatMarker.html
+/// [`ConstDebug`]: ./derive.ConstDebug.html
+/// [`formatc`]: ./macro.formatc.html
+/// [`impl_fmt`]: ./macro.impl_fmt.html
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "derive")))]
+#[cfg(feature = "derive")]
+pub use const_format_proc_macros::ConstDisplay;
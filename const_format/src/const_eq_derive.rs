@@ -0,0 +1,115 @@
+/// Derives const equality for a type.
+///
+/// Defines a `const fn const_eq(&self, other: &Self) -> bool` inherent method,
+/// which compares the fields of the struct (or the variant and fields of the enum),
+/// and is what the [`assertc_eq`] and [`assertc_ne`] macros use to compare
+/// user-defined types.
+///
+/// The fields are compared with the `const_eq` method of [`PWrapper`]
+/// for standard library types,
+/// and with the `const_eq` inherent method of the field type otherwise,
+/// which requires it to implement the [`FormatMarker`] trait
+/// (eg: by deriving [`ConstDebug`]).
+///
+/// # Features
+///
+/// This derive macro is only available with the "derive" and "assertc" features,
+/// and either Rust 1.83.0 with the "rust_1_83" feature, or the nightly compiler.
+///
+/// # Container Attributes
+///
+/// These attributes go on the type itself, rather than the fields.
+///
+/// ### `#[ceq(debug_print)]`
+///
+/// Panics with the output of the expanded derive.
+///
+/// ### `#[ceq(impls(....))]`
+///
+/// Implements const equality for multiple different concrete instances of the type,
+/// like the [`#[cdeb(impls(....))]`](./derive.ConstDebug.html#cdebimpls) attribute.
+///
+/// ### `#[ceq(crate = "foo::bar")]`
+///
+/// The path to the `const_format` crate, useful if you want to reexport this macro,
+/// or rename the `const_format` crate in the Cargo.toml .
+///
+/// # Field attributes
+///
+/// ### `#[ceq(ignore)]`
+///
+/// Doesn't compare the field.
+///
+/// ### `#[ceq(with = "module::function")]`
+///
+/// Compares the field with the passed-in function,
+/// which must have the signature `const fn(&FieldType, &FieldType) -> bool`.
+///
+/// ### `#[ceq(is_a(....))]`
+///
+/// Tells the derive how to compare a field whose type isn't detected automatically,
+/// taking the same arguments as
+/// [`#[cdeb(is_a(....))]`](./derive.ConstDebug.html#cdebis_a),
+/// except for `newtype`.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{ConstDebug, ConstEq, assertc_eq, assertc_ne};
+///
+/// #[derive(ConstDebug, ConstEq)]
+/// struct Config {
+///     name: &'static str,
+///     ports: [u16; 2],
+///     mode: Mode,
+///     #[ceq(with = "same_len")]
+///     tag: &'static str,
+///     #[ceq(ignore)]
+///     counter: u32,
+/// }
+///
+/// #[derive(ConstDebug, ConstEq)]
+/// enum Mode {
+///     Fast,
+///     Limited { max: Option<u32> },
+/// }
+///
+/// const fn same_len(l: &&str, r: &&str) -> bool {
+///     l.len() == r.len()
+/// }
+///
+/// const CONFIG: Config = Config {
+///     name: "foo",
+///     ports: [80, 443],
+///     mode: Mode::Limited { max: Some(8) },
+///     tag: "abc",
+///     counter: 0,
+/// };
+///
+/// assertc_eq!(
+///     CONFIG,
+///     Config {
+///         name: "foo",
+///         ports: [80, 443],
+///         mode: Mode::Limited { max: Some(8) },
+///         tag: "xyz",
+///         counter: 100,
+///     }
+/// );
+///
+/// assertc_ne!(CONFIG, Config { mode: Mode::Fast, ..CONFIG });
+///
+/// # fn main(){}
+/// ```
+///
+/// [`assertc_eq`]: ./macro.assertc_eq.html
+/// [`assertc_ne`]: ./macro.assertc_ne.html
+/// [`ConstDebug`]: ./derive.ConstDebug.html
+/// [`FormatMarker`]: ./marker_traits/trait.FormatMarker.html
+/// [`PWrapper`]: ./struct.PWrapper.html
+#[cfg_attr(
+    feature = "__docsrs",
+    doc(cfg(all(feature = "derive", feature = "assertc")))
+)]
+#[cfg(all(feature = "derive", feature = "assertc"))]
+pub use const_format_proc_macros::ConstEq;
@@ -46,8 +46,6 @@ pub struct ImplFmtWhereClause;
 
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// #[derive(const_format::ConstDebug)]
 /// struct Foo<T>(*const T)
 /// where T: 'static;
@@ -56,8 +54,6 @@ pub struct ImplFmtWhereClause;
 /// ```
 ///
 /// ```compile_fail
-/// #![feature(const_mut_refs)]
-///
 /// #[derive(const_format::ConstDebug)]
 /// struct Foo<T>(*const T)
 /// where AAAA: AAAA;
@@ -69,8 +65,6 @@ pub struct ImplFmtWhereClause;
 pub struct ConstDebugWhereClause;
 
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::StrWriterMut;
 ///
 /// let mut len = 0;
@@ -85,8 +79,6 @@ pub struct ConstDebugWhereClause;
 /// ```
 ///
 /// ```compile_fail
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::StrWriterMut;
 ///
 /// let mut len = 0;
@@ -104,15 +96,11 @@ pub struct ConstDebugWhereClause;
 pub struct AsStr_For_StrWriterMut_NoEncoding;
 
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// const_format::assertc!(true, "foo");
 ///
 /// ```
 ///
 /// ```compile_fail
-/// #![feature(const_mut_refs)]
-///
 /// const_format::assertc!(false, "foo");
 ///
 /// ```
@@ -120,8 +108,6 @@ pub struct AsStr_For_StrWriterMut_NoEncoding;
 /// # With a Formatting argument
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// const_format::assertc!(
 ///     true,
 ///     "{foo}\n{foo:#?}\n{}",
@@ -139,8 +125,6 @@ pub struct AsStr_For_StrWriterMut_NoEncoding;
 /// ```
 ///
 /// ```compile_fail
-/// #![feature(const_mut_refs)]
-///
 /// const_format::assertc!(
 ///     false,
 ///     "{foo}\n{foo:#?}\n{}",
@@ -163,15 +147,11 @@ pub struct Assert;
 /// # assert_eq
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// const_format::assertc_eq!(0u8, 0u8, "foo");
 ///
 /// ```
 ///
 /// ```compile_fail
-/// #![feature(const_mut_refs)]
-///
 /// const_format::assertc_eq!(0u8, 10u8, "foo");
 ///
 /// ```
@@ -179,15 +159,11 @@ pub struct Assert;
 /// # assert_ne
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// const_format::assertc_ne!(0u8, 10u8, "foo");
 ///
 /// ```
 ///
 /// ```compile_fail
-/// #![feature(const_mut_refs)]
-///
 /// const_format::assertc_ne!(0u8, 0u8, "foo");
 ///
 /// ```
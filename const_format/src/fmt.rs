@@ -3,10 +3,10 @@
 //!
 //! # Features
 //!
-//! This module requires the "fmt" feature to be exported, and the nightly compiler,
-//! because at the time of writing these docs (2021-08-XX) mutable references in const fn
-//! require the unstable
-//! [`const_mut_refs`](https://github.com/rust-lang/rust/issues/57349) feature.
+//! This module requires the "fmt" feature to be exported,
+//! and either Rust 1.83.0 with the "rust_1_83" feature, or the nightly compiler,
+//! because it uses mutable references in const fn,
+//! which were stabilized in Rust 1.83.0.
 //!
 //! # Implementing the formatting methods
 //!
@@ -63,7 +63,7 @@
 //!
 //! - Debug formatting (eg: `formatc!("{:?}", 0u8)` ):<br>
 //! Similar to how Debug formatting in the standard library works,
-//! except that it does not escape unicode characters.
+//! strings and chars are escaped exactly like the standard library does it.
 //!
 //! - Display formatting (eg: `formatc!("{}", 0u8)`, `formatc!("{:}", 0u8)` )
 //!
@@ -94,9 +94,91 @@
 //! - The binary formater (eg: `formatc!("{:#b}", FOO)`):
 //! prefixes numbers with `0b`.
 //!
+//! ### Debug limits
+//!
+//! Debug formatting can limit how much of a data structure is written,
+//! with a parenthesized list of limits after the formatter.
+//! eg:`"{:?(depth = 2)}", "{:#x?(entries = 8)}", "{:?(depth = 3, entries = 16)}"`.
+//!
+//! - `depth`: structs, tuples, lists, sets, and maps nested deeper than this
+//! are written as `..`.
+//!
+//! - `entries`: lists, sets, and maps write at most this many entries,
+//! followed by `...` if there were more.
+//!
+//! These set the `max_depth` and `max_entries` of the [`FormattingFlags`] passed to the
+//! argument, which are honored by the [`Formatter`]'s debug builders
+//! ([`DebugStruct`], [`DebugTuple`], [`DebugList`], [`DebugSet`], and [`DebugMap`]).
+//!
+//! ```rust
+//! use const_format::formatc;
+//!
+//! const LIST: &str = formatc!("{:?(entries = 3)}", [3u8, 5, 8, 13, 21]);
+//! assert_eq!(LIST, "[3, 5, 8, ...]");
+//! ```
+//!
+//! ### Line width
+//!
+//! The alternate Debug formatter can write data structures that fit within a line width
+//! in a single line, with a `line_width` in the parenthesized list after the formatter.
+//! eg:`"{:#?(line_width = 80)}", "{:#?(depth = 4, line_width = 100)}"`.
+//!
+//! This sets the `line_width` of the [`FormattingFlags`] passed to the argument,
+//! the [`FormattingFlags::set_line_width`] method describes it in more detail.
+//!
+//! ```rust
+//! use const_format::formatc;
+//!
+//! const FITS: &str = formatc!("{:#?(line_width = 20)}", [3u8, 5, 8]);
+//! assert_eq!(FITS, "[3, 5, 8]");
+//!
+//! const DOESNT_FIT: &str = formatc!("{:#?(line_width = 8)}", [3u8, 5, 8]);
+//! assert_eq!(DOESNT_FIT, "[\n    3,\n    5,\n    8,\n]");
+//! ```
+//!
+//! ### Indentation
+//!
+//! The alternate Debug formatter indents with 4 spaces by default,
+//! which can be changed with an `indent` in the parenthesized list after the formatter,
+//! set to either an amount of spaces, or `tab`.
+//! eg:`"{:#?(indent = 2)}", "{:#?(indent = tab, line_width = 80)}"`.
+//!
+//! This sets the `indent` of the [`FormattingFlags`] passed to the argument,
+//! with the [`FormattingFlags::set_indent`] method.
+//!
+//! ```rust
+//! use const_format::formatc;
+//!
+//! const TWO: &str = formatc!("{:#?(indent = 2)}", [3u8, 5]);
+//! assert_eq!(TWO, "[\n  3,\n  5,\n]");
+//!
+//! const TAB: &str = formatc!("{:#?(indent = tab)}", [3u8, 5]);
+//! assert_eq!(TAB, "[\n\t3,\n\t5,\n]");
+//! ```
+//!
+//! ### Width, fill, and alignment
+//!
+//! Arguments can be padded to a minimum width with the same syntax as the standard library,
+//! eg:`"{:8}", "{:>8}", "{:*^8?}", "{:<#10x?}"`.
+//!
+//! This sets the width, fill character, and [`Alignment`] of the [`FormattingFlags`]
+//! passed to the argument, which are used by the [`Formatter::pad`] and
+//! [`Formatter::pad_integral`] methods.
+//! Integers, `&str`, `bool`, and `char` are padded like in the standard library,
+//! and types with a user-defined formatting impl can call those methods to be padded as well.
+//!
+//! ```rust
+//! use const_format::formatc;
+//!
+//! const TABLE: &str = formatc!("{:<6}|{:>4}\n{:<6}|{:>4}", "foo", 3u8, "barbaz", 1000u16);
+//! assert_eq!(TABLE, "foo   |   3\nbarbaz|1000");
+//!
+//! assert_eq!(formatc!("[{:*^9}]", "hello"), "[**hello**]");
+//! ```
+//!
 //! ### Additional specifiers
 //!
-//! `const_format` macros don't support width, fill, alignment, sign,
+//! `const_format` macros don't support sign, `0` (zero padding),
 //! or precision specifiers.
 //!
 //! <span id="custom-formatting-section"></span>
@@ -123,10 +205,8 @@
 //!
 #![cfg_attr(feature = "derive", doc = "```rust")]
 #![cfg_attr(not(feature = "derive"), doc = "```ignore")]
-//! #![feature(const_mut_refs)]
-//!
 //! use const_format::{Error, Formatter, FormattingFlags, PWrapper, StrWriter};
-//! use const_format::{ConstDebug, try_, unwrap, writec};
+//! use const_format::{ConstDebug, try_, unwrap_fmt, writec};
 //!
 //! use std::ops::Range;
 //!
@@ -151,7 +231,7 @@
 //!         point: Point{ x: 13, y: 21 },
 //!     };
 //!
-//!     unwrap!(writec!(writer, "{:X?}", foo));
+//!     unwrap_fmt!(writer, |f| writec!(f, "{:X?}", foo));
 //!
 //!     writer
 //! }
@@ -175,8 +255,6 @@
 //! This example demonstrates how you can use the `fmt` api without using any proc macros.
 //!
 //! ```rust
-//! #![feature(const_mut_refs)]
-//!
 //! use const_format::{Error, Formatter, FormattingFlags, PWrapper, StrWriter};
 //! use const_format::{call_debug_fmt, coerce_to_fmt, impl_fmt, try_};
 //!
@@ -223,15 +301,15 @@
 //!     let flags = FormattingFlags::NEW.set_alternate(true);
 //!     let mut writer = StrWriter::new([0; CAP]);
 //!
-//!     const_format::unwrap!(
+//!     const_format::unwrap_fmt!(writer, |f| {
 //!         Foo {
 //!             a: 5,
 //!             b: 8,
 //!             c: 13,
 //!             d: [Ordering::Less, Ordering::Equal, Ordering::Greater],
 //!             ignored: (),
-//!         }.const_debug_fmt(&mut Formatter::from_sw(&mut writer, flags))
-//!     );
+//!         }.const_debug_fmt(&mut f.make_formatter(flags))
+//!     });
 //!
 //!     writer
 //! }
@@ -267,8 +345,6 @@
 //! by using a `Formatter` directly.
 //!
 //! ```rust
-//! #![feature(const_mut_refs)]
-//!
 //! use const_format::{call_debug_fmt, formatc};
 //!
 //! // Positional argument
@@ -305,6 +381,17 @@
 //!
 //! [`writec`]: ../macro.writec.html
 //! [`Formatter`]: ./struct.Formatter.html
+//! [`FormattingFlags`]: ./struct.FormattingFlags.html
+//! [`FormattingFlags::set_line_width`]: ./struct.FormattingFlags.html#method.set_line_width
+//! [`FormattingFlags::set_indent`]: ./struct.FormattingFlags.html#method.set_indent
+//! [`Alignment`]: ./enum.Alignment.html
+//! [`Formatter::pad`]: ./struct.Formatter.html#method.pad
+//! [`Formatter::pad_integral`]: ./struct.Formatter.html#method.pad_integral
+//! [`DebugStruct`]: ./struct.DebugStruct.html
+//! [`DebugTuple`]: ./struct.DebugTuple.html
+//! [`DebugList`]: ./struct.DebugList.html
+//! [`DebugSet`]: ./struct.DebugSet.html
+//! [`DebugMap`]: ./struct.DebugMap.html
 //! [`FormatMarker`]: ../marker_traits/trait.FormatMarker.html
 //! [`ConstDebug`]: ../derive.ConstDebug.html
 //!
@@ -313,17 +400,34 @@
 //!
 //!
 
+pub(crate) mod const_string;
+#[cfg(feature = "nightly_const_traits")]
+mod const_traits;
 mod error;
 mod formatter;
+mod std_adapter;
 mod std_type_impls;
 mod str_writer;
 mod str_writer_mut;
+#[cfg(feature = "alloc")]
+pub(crate) mod string_writer;
+mod window_writer;
 
-pub use crate::formatting::{FormattingFlags, NumberFormatting};
+pub use crate::formatting::{Alignment, FormattingFlags, NumberFormatting};
 
 pub use self::{
-    error::{Error, Result, ToResult},
-    formatter::{ComputeStrLength, DebugList, DebugSet, DebugStruct, DebugTuple, Formatter},
+    const_string::ConstString,
+    error::{Error, NotEnoughSpaceError, Result, ToResult},
+    formatter::{
+        ComputeStrLength, DebugList, DebugMap, DebugSet, DebugStruct, DebugTuple, Formatter,
+    },
+    std_adapter::{ConstFmtAdapter, ConstFmtFn},
     str_writer::StrWriter,
-    str_writer_mut::{NoEncoding, StrWriterMut, Utf8Encoding},
+    str_writer_mut::{Checkpoint, NoEncoding, StrWriterMut, Utf8Encoding},
 };
+
+#[cfg(feature = "alloc")]
+pub use self::string_writer::StringWriter;
+
+#[cfg(feature = "nightly_const_traits")]
+pub use self::const_traits::{ConstDebug, ConstDisplay};
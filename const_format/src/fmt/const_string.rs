@@ -0,0 +1,455 @@
+use crate::{
+    fmt::{Error, Formatter, FormattingFlags, StrWriter, StrWriterMut},
+    marker_traits::{IsNotAStrWriter, WriteMarker},
+    pargument::PArgument,
+    utils::slice_up_to_len,
+    wrapper_types::PWrapper,
+};
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+/// An owned string stored inline in a `[u8; N]` array,
+/// which can be built up and returned from const fns.
+///
+/// This is a [`StrWriter<[u8; N]>`](./struct.StrWriter.html)
+/// with value semantics:
+/// it's `Copy`, can be stored in `static`s and `const`s,
+/// and compared, formatted, and dereferenced to `&str` at runtime.
+///
+/// # Construction
+///
+/// - [`new`](#method.new): constructs an empty `ConstString`
+///   that can be written into with the [`writec`] macro,
+///   or the [`push_str`](#method.push_str) method.
+///
+/// - [`try_from_str`](#method.try_from_str): copies a string into a `ConstString`.
+///
+/// - The [`const_string`] macro: constructs an exactly-sized `ConstString`
+///   from constant arguments, like [`concatcp`] does.
+///
+/// # Example
+///
+/// ### Returning from a const fn
+///
+/// ```rust
+/// use const_format::{ConstString, unwrap_fmt, writec};
+///
+/// const fn describe(id: u32, name: &str) -> ConstString<64> {
+///     let mut string = ConstString::new();
+///     unwrap_fmt!(string, |f| writec!(f, "#{} {:?}", id, name));
+///     string
+/// }
+///
+/// const FIRST: ConstString<64> = describe(3, "foo");
+/// static SECOND: ConstString<64> = describe(5, "bar");
+///
+/// assert_eq!(FIRST.as_str(), r#"#3 "foo""#);
+/// assert_eq!(SECOND, r#"#5 "bar""#);
+///
+/// // `ConstString` dereferences to `str`
+/// assert!(SECOND.ends_with(r#""bar""#));
+///
+/// ```
+///
+/// ### Concatenation
+///
+/// ```rust
+/// use const_format::{ConstString, const_string, unwrap};
+///
+/// const HELLO: ConstString<5> = const_string!("hello");
+/// const WORLD: ConstString<6> = const_string!(" ", "world");
+///
+/// const GREETING: ConstString<11> = unwrap!(HELLO.concat(&WORLD));
+///
+/// assert_eq!(GREETING, "hello world");
+/// assert!(GREETING.const_eq(&const_string!("hello", ' ', "world")));
+///
+/// ```
+///
+/// [`writec`]: ../macro.writec.html
+/// [`concatcp`]: ../macro.concatcp.html
+/// [`const_string`]: ../macro.const_string.html
+#[derive(Copy, Clone)]
+pub struct ConstString<const N: usize> {
+    writer: StrWriter<[u8; N]>,
+}
+
+impl<const N: usize> ConstString<N> {
+    /// Constructs an empty `ConstString`.
+    pub const fn new() -> Self {
+        Self {
+            writer: StrWriter::new([0; N]),
+        }
+    }
+
+    /// Constructs a `ConstString` with a copy of `string`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::NotEnoughSpace` if `string` is longer than `N` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{ConstString, Error, unwrap};
+    ///
+    /// const FOO: ConstString<8> = unwrap!(ConstString::try_from_str("foo"));
+    /// assert_eq!(FOO, "foo");
+    ///
+    /// assert_eq!(ConstString::<2>::try_from_str("foo"), Err(Error::NotEnoughSpace));
+    ///
+    /// ```
+    pub const fn try_from_str(string: &str) -> Result<Self, Error> {
+        let mut this = Self::new();
+        match this.push_str(string) {
+            Ok(()) => Ok(this),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Appends `string` to the end of this `ConstString`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::NotEnoughSpace`, without writing anything,
+    /// if `string` doesn't fit in the remaining capacity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{ConstString, Error};
+    ///
+    /// let mut string = ConstString::<8>::new();
+    ///
+    /// string.push_str("foo")?;
+    /// string.push_str("bar")?;
+    /// assert_eq!(string, "foobar");
+    ///
+    /// assert_eq!(string.push_str("baz"), Err(Error::NotEnoughSpace));
+    /// assert_eq!(string, "foobar");
+    ///
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub const fn push_str(&mut self, string: &str) -> Result<(), Error> {
+        self.writer.as_mut().write_str(string)
+    }
+
+    /// Concatenates this `ConstString` with `other` into a `ConstString<O>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::NotEnoughSpace` if both strings don't fit in `O` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{ConstString, const_string, unwrap};
+    ///
+    /// const fn path(dir: &ConstString<8>, file: &ConstString<8>) -> ConstString<17> {
+    ///     let with_slash: ConstString<9> = unwrap!(dir.concat(&const_string!("/")));
+    ///     unwrap!(with_slash.concat(file))
+    /// }
+    ///
+    /// const DIR: ConstString<8> = const_format::unwrap!(ConstString::try_from_str("src"));
+    /// const FILE: ConstString<8> = const_format::unwrap!(ConstString::try_from_str("lib.rs"));
+    ///
+    /// assert_eq!(path(&DIR, &FILE), "src/lib.rs");
+    ///
+    /// ```
+    pub const fn concat<const M: usize, const O: usize>(
+        &self,
+        other: &ConstString<M>,
+    ) -> Result<ConstString<O>, Error> {
+        let mut out = ConstString::<O>::new();
+        match out.push_str(self.as_str()) {
+            Ok(()) => {}
+            Err(e) => return Err(e),
+        }
+        match out.push_str(other.as_str()) {
+            Ok(()) => Ok(out),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Copies this string into a `ConstString` with a different capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::NotEnoughSpace` if this string doesn't fit in `M` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{ConstString, Error, const_string, unwrap};
+    ///
+    /// const FOO: ConstString<3> = const_string!("foo");
+    /// const FOO_BIG: ConstString<16> = unwrap!(FOO.resize());
+    ///
+    /// assert_eq!(FOO_BIG, "foo");
+    /// assert_eq!(FOO_BIG.capacity(), 16);
+    ///
+    /// assert_eq!(FOO.resize::<2>(), Err(Error::NotEnoughSpace));
+    ///
+    /// ```
+    pub const fn resize<const M: usize>(&self) -> Result<ConstString<M>, Error> {
+        ConstString::try_from_str(self.as_str())
+    }
+
+    /// Gets the string as a `&str`.
+    #[inline(always)]
+    pub const fn as_str(&self) -> &str {
+        self.writer.r().as_str()
+    }
+
+    /// Gets the string as a `&[u8]`.
+    #[inline(always)]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.writer.r().as_bytes()
+    }
+
+    /// Gets the length of the string in bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.writer.len()
+    }
+
+    /// Checks whether the string is empty.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.writer.is_empty()
+    }
+
+    /// Gets the maximum length of the string, in bytes.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Gets how many more bytes can be written into this `ConstString`.
+    #[inline(always)]
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.writer.len()
+    }
+
+    /// Truncates this `ConstString` to `length`.
+    ///
+    /// If `length` is greater than the current length, this does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::NotOnCharBoundary` if `length` is not on a char boundary.
+    pub const fn truncate(&mut self, length: usize) -> Result<(), Error> {
+        self.writer.as_mut().truncate(length)
+    }
+
+    /// Truncates this `ConstString` to length 0.
+    pub const fn clear(&mut self) {
+        self.writer.as_mut().clear()
+    }
+
+    /// Borrows this `ConstString` into a [`StrWriterMut`],
+    /// for calling its `write_*` methods.
+    ///
+    /// [`StrWriterMut`]: ./struct.StrWriterMut.html
+    #[inline(always)]
+    pub const fn as_mut(&mut self) -> StrWriterMut<'_> {
+        self.writer.as_mut()
+    }
+
+    /// Constructs a [`Formatter`] that appends to this `ConstString`.
+    ///
+    /// [`Formatter`]: ./struct.Formatter.html
+    #[inline(always)]
+    pub const fn make_formatter(&mut self, flags: FormattingFlags) -> Formatter<'_> {
+        let writer: &mut StrWriter = &mut self.writer;
+        writer.make_formatter(flags)
+    }
+
+    /// For borrowing this mutably in macros, without getting nested mutable references.
+    #[inline(always)]
+    pub const fn borrow_mutably(&mut self) -> &mut Self {
+        self
+    }
+
+    /// Compares this string with `other` for equality.
+    pub const fn const_eq<const M: usize>(&self, other: &ConstString<M>) -> bool {
+        crate::utils::str_eq(self.as_str(), other.as_str())
+    }
+
+    /// Compares this string with `other` for equality.
+    pub const fn eq_str(&self, other: &str) -> bool {
+        crate::utils::str_eq(self.as_str(), other)
+    }
+
+    /// Compares this string with `other` lexicographically by bytes,
+    /// the same way that `str`'s `Ord` impl does.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{ConstString, const_string};
+    ///
+    /// use std::cmp::Ordering;
+    ///
+    /// const FOO: ConstString<3> = const_string!("foo");
+    ///
+    /// assert_eq!(FOO.const_cmp(&const_string!("foo")), Ordering::Equal);
+    /// assert_eq!(FOO.const_cmp(&const_string!("fo")), Ordering::Greater);
+    /// assert_eq!(FOO.const_cmp(&const_string!("fop")), Ordering::Less);
+    ///
+    /// ```
+    pub const fn const_cmp<const M: usize>(&self, other: &ConstString<M>) -> Ordering {
+        let left = self.as_bytes();
+        let right = other.as_bytes();
+
+        let mut i = 0;
+        while i < left.len() && i < right.len() {
+            if left[i] != right[i] {
+                return if left[i] < right[i] {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                };
+            }
+            i += 1;
+        }
+
+        if left.len() < right.len() {
+            Ordering::Less
+        } else if left.len() > right.len() {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+impl<const N: usize> WriteMarker for ConstString<N> {
+    type Kind = IsNotAStrWriter;
+    type This = Self;
+}
+
+impl_fmt! {
+    impl[const N: usize,] ConstString<N>;
+
+    /// Writes the string with display formatting.
+    pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        PWrapper(self.as_str()).const_display_fmt(f)
+    }
+
+    /// Writes the string with debug formatting.
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        PWrapper(self.as_str()).const_debug_fmt(f)
+    }
+}
+
+impl<const N: usize> Default for ConstString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for ConstString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for ConstString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Debug for ConstString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> Display for ConstString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<ConstString<M>> for ConstString<N> {
+    fn eq(&self, other: &ConstString<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for ConstString<N> {}
+
+impl<const N: usize> PartialEq<str> for ConstString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for ConstString<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize, const M: usize> PartialOrd<ConstString<M>> for ConstString<N> {
+    fn partial_cmp(&self, other: &ConstString<M>) -> Option<Ordering> {
+        Some(self.as_str().cmp(other.as_str()))
+    }
+}
+
+impl<const N: usize> Ord for ConstString<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> Hash for ConstString<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+/// Concatenates `args` into a `ConstString<N>`,
+/// used by the `concatcp_generic` and `formatcp_generic` macros.
+#[doc(hidden)]
+#[track_caller]
+pub const fn __concatcp_generic<const N: usize>(args: &[PArgument]) -> ConstString<N> {
+    let needed = PArgument::calc_len(args);
+    if needed > N {
+        __capacity_error(N, needed)
+    }
+
+    let out = crate::pmr::__priv_concatenate::<N>(args);
+    // `__priv_concatenate` only writes valid utf8 into `out.array`.
+    let string = unsafe { core::str::from_utf8_unchecked(slice_up_to_len(&out.array, out.len)) };
+
+    match ConstString::try_from_str(string) {
+        Ok(x) => x,
+        Err(_) => __capacity_error(N, needed),
+    }
+}
+
+/// Panics with an error message saying that `capacity` is smaller than `needed`.
+#[doc(hidden)]
+#[track_caller]
+pub const fn __capacity_error(capacity: usize, needed: usize) -> ! {
+    let mut msg = ConstString::<192>::new();
+    let _ = crate::writec!(
+        msg,
+        "the capacity passed to the macro ({}) is smaller than \
+         the length of the formatted string ({}), pass a capacity of at least {}",
+        capacity,
+        needed,
+        needed,
+    );
+    panic!("{}", msg.as_str())
+}
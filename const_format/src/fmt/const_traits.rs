@@ -0,0 +1,145 @@
+use crate::fmt::{ConstString, Error, Formatter};
+
+/// A const trait for Display formatting,
+/// the trait version of the inherent `const_display_fmt` methods.
+///
+/// This is only available with the "nightly_const_traits" feature,
+/// which requires Rust nightly.
+///
+/// This allows writing const fns, and const trait impls,
+/// that format any type with a `T: [const] ConstDisplay` bound.
+///
+/// # Implementors
+///
+/// This trait is implemented for the standard library types that have a
+/// `const_display_fmt` method through the [`PWrapper`] type:
+/// integers, `bool`, `char`, `str`, and the `NonZero*` integers,
+/// as well as references to implementors,
+/// and the types that derive [`ConstDisplay`](../derive.ConstDisplay.html).
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(const_trait_impl)]
+///
+/// use const_format::fmt::{ConstDisplay, ConstString, Error, Formatter, FormattingFlags};
+///
+/// const fn display<T: [const] ConstDisplay>(value: &T) -> ConstString<64> {
+///     let mut string = ConstString::new();
+///     let _ = value.const_display_fmt(&mut string.make_formatter(FormattingFlags::NEW));
+///     string
+/// }
+///
+/// const NUM: &ConstString<64> = &display(&100u8);
+/// const TEXT: &ConstString<64> = &display(&"hello");
+///
+/// assert_eq!(NUM.as_str(), "100");
+/// assert_eq!(TEXT.as_str(), "hello");
+///
+/// ```
+///
+/// [`PWrapper`]: ../struct.PWrapper.html
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "nightly_const_traits")))]
+pub const trait ConstDisplay {
+    /// Formats `self` into `f`, like [`core::fmt::Display::fmt`].
+    fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error>;
+}
+
+/// A const trait for Debug formatting,
+/// the trait version of the inherent `const_debug_fmt` methods.
+///
+/// This is only available with the "nightly_const_traits" feature,
+/// which requires Rust nightly.
+///
+/// This allows writing const fns, and const trait impls,
+/// that format any type with a `T: [const] ConstDebug` bound,
+/// so that generic types can be formatted without listing every instantiation
+/// with the [`impl_fmt`] macro.
+///
+/// # Implementors
+///
+/// This trait is implemented for the standard library types that have a
+/// `const_debug_fmt` method through the [`PWrapper`] type,
+/// for slices, arrays, and `Option`s of implementors,
+/// and for the types that derive [`ConstDebug`](../derive.ConstDebug.html).
+///
+/// # Example
+///
+/// ```rust
+/// #![feature(const_trait_impl)]
+///
+/// use const_format::fmt::{ConstDebug, ConstString, Error, Formatter, FormattingFlags};
+/// use const_format::try_;
+///
+/// struct Pair<A, B>(A, B);
+///
+/// impl<A: [const] ConstDebug, B: [const] ConstDebug> const ConstDebug for Pair<A, B> {
+///     fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+///         let mut f = f.debug_tuple("Pair");
+///         try_!(self.0.const_debug_fmt(f.field()));
+///         try_!(self.1.const_debug_fmt(f.field()));
+///         f.finish()
+///     }
+/// }
+///
+/// const fn debug<T: [const] ConstDebug>(value: &T) -> ConstString<64> {
+///     let mut string = ConstString::new();
+///     let _ = value.const_debug_fmt(&mut string.make_formatter(FormattingFlags::NEW));
+///     string
+/// }
+///
+/// const PAIR: &ConstString<64> = &debug(&Pair(3u8, Pair("foo", [Some('a'), None])));
+///
+/// assert_eq!(PAIR.as_str(), r#"Pair(3, Pair("foo", [Some('a'), None]))"#);
+///
+/// ```
+///
+/// [`PWrapper`]: ../struct.PWrapper.html
+/// [`impl_fmt`]: ../macro.impl_fmt.html
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "nightly_const_traits")))]
+pub const trait ConstDebug {
+    /// Formats `self` into `f`, like [`core::fmt::Debug::fmt`].
+    fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error>;
+}
+
+impl<T: ?Sized + [const] ConstDisplay> const ConstDisplay for &T {
+    #[inline(always)]
+    fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        (**self).const_display_fmt(f)
+    }
+}
+
+impl<T: ?Sized + [const] ConstDisplay> const ConstDisplay for &mut T {
+    #[inline(always)]
+    fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        (**self).const_display_fmt(f)
+    }
+}
+
+impl<T: ?Sized + [const] ConstDebug> const ConstDebug for &T {
+    #[inline(always)]
+    fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        (**self).const_debug_fmt(f)
+    }
+}
+
+impl<T: ?Sized + [const] ConstDebug> const ConstDebug for &mut T {
+    #[inline(always)]
+    fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        (**self).const_debug_fmt(f)
+    }
+}
+
+impl<const N: usize> const ConstDisplay for ConstString<N> {
+    #[inline(always)]
+    fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        self.const_display_fmt(f)
+    }
+}
+
+impl<const N: usize> const ConstDebug for ConstString<N> {
+    #[inline(always)]
+    fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        self.const_debug_fmt(f)
+    }
+}
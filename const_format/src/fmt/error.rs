@@ -1,6 +1,8 @@
 // <_< clippy you silly
 #![allow(clippy::enum_variant_names)]
 
+use super::{Formatter, FormattingFlags, StrWriter};
+
 use core::fmt::{self, Display};
 
 /// An error while trying to write into a StrWriter.
@@ -8,6 +10,9 @@ use core::fmt::{self, Display};
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum Error {
     /// Attempted to write something into the buffer when there isn't enough space to write it.
+    ///
+    /// The writer records the details of this error as a
+    /// [`NotEnoughSpaceError`](./struct.NotEnoughSpaceError.html).
     NotEnoughSpace,
     /// For compatibility with [`NotAsciiError`](../wrapper_types/struct.NotAsciiError.html)
     NotAscii,
@@ -20,7 +25,7 @@ impl Display for Error {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         match self {
             Self::NotEnoughSpace => {
-                fmt.write_str("The was not enough space to write the formatted output")
+                fmt.write_str("There was not enough space to write the formatted output")
             }
             Self::NotAscii => fmt.write_str("Attempted to write non-ascii text"),
             Self::NotOnCharBoundary => {
@@ -30,6 +35,107 @@ impl Display for Error {
     }
 }
 
+#[cfg(feature = "rust_1_83")]
+impl core::error::Error for Error {}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Details about a write that didn't fit in the buffer of a
+/// [`StrWriterMut`] or [`Formatter`].
+///
+/// The last write that returned an [`Error::NotEnoughSpace`]
+/// (or that was truncated in [truncating mode]) is recorded by the writer,
+/// and can be queried with the [`StrWriterMut::not_enough_space`] and
+/// [`Formatter::not_enough_space`] methods.
+///
+/// This only describes a single write,
+/// to compute the length of all the formatted output you can use [`ComputeStrLength`].
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::fmt::{Error, NotEnoughSpaceError, StrWriter, StrWriterMut};
+/// use const_format::writec;
+///
+/// let mut buffer = StrWriter::new([0; 16]);
+/// let mut writer = StrWriterMut::new(&mut buffer);
+///
+/// let res = writec!(writer, "{} readings from {}", 3u32, "thermometer");
+/// assert_eq!(res, Err(Error::NotEnoughSpace));
+///
+/// let error = writer.not_enough_space().unwrap();
+/// assert_eq!(
+///     error,
+///     NotEnoughSpaceError { position: 16, needed: 27, capacity: 16 },
+/// );
+/// assert_eq!(error.additional(), 11);
+///
+/// assert_eq!(
+///     error.to_string(),
+///     "needed 27 bytes, capacity 16, when writing at byte 16",
+/// );
+///
+/// ```
+///
+/// [`StrWriterMut`]: ./struct.StrWriterMut.html
+/// [`Formatter`]: ./struct.Formatter.html
+/// [`Error::NotEnoughSpace`]: ./enum.Error.html#variant.NotEnoughSpace
+/// [truncating mode]: ./struct.StrWriterMut.html#truncation
+/// [`StrWriterMut::not_enough_space`]: ./struct.StrWriterMut.html#method.not_enough_space
+/// [`Formatter::not_enough_space`]: ./struct.Formatter.html#method.not_enough_space
+/// [`ComputeStrLength`]: ./struct.ComputeStrLength.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NotEnoughSpaceError {
+    /// The length of the written text when the write was attempted.
+    pub position: usize,
+    /// The buffer length required for the write to succeed.
+    pub needed: usize,
+    /// The length of the buffer.
+    pub capacity: usize,
+}
+
+impl NotEnoughSpaceError {
+    /// How many more bytes the buffer needed for the write to succeed.
+    pub const fn additional(&self) -> usize {
+        self.needed.saturating_sub(self.capacity)
+    }
+}
+
+impl Display for NotEnoughSpaceError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            fmt,
+            "needed {} bytes, capacity {}, when writing at byte {}",
+            self.needed, self.capacity, self.position,
+        )
+    }
+}
+
+#[cfg(feature = "rust_1_83")]
+impl core::error::Error for NotEnoughSpaceError {}
+
+impl From<NotEnoughSpaceError> for Error {
+    fn from(_: NotEnoughSpaceError) -> Self {
+        Error::NotEnoughSpace
+    }
+}
+
+impl_fmt! {
+    impl NotEnoughSpaceError;
+
+    /// Writes this error in the same format as its `Display` impl.
+    pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        try_!(f.write_str("needed "));
+        try_!(f.write_usize_display(self.needed));
+        try_!(f.write_str(" bytes, capacity "));
+        try_!(f.write_usize_display(self.capacity));
+        try_!(f.write_str(", when writing at byte "));
+        f.write_usize_display(self.position)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 macro_rules! index_vars{
     ($self:ident, $index:ident; $($variant:ident),* $(,)? ) => (
         enum Index{
@@ -54,7 +160,9 @@ impl Error {
         };
 
         match self {
-            Error::NotEnoughSpace => ["The was not enough space to write the formatted output"][i],
+            Error::NotEnoughSpace => {
+                ["There was not enough space to write the formatted output"][i]
+            }
             Error::NotAscii => ["Attempted to write non-ascii text"][i],
             Error::NotOnCharBoundary => {
                 ["Attempted to index a byte that's not on a char boundary."][i]
@@ -62,6 +170,35 @@ impl Error {
         };
         loop {}
     }
+
+    /// Like [`unwrap`](#method.unwrap), except that for a
+    /// [`NotEnoughSpace`](#variant.NotEnoughSpace) error,
+    /// the panic message includes the [`NotEnoughSpaceError`] in `space` (if it's `Some`),
+    /// eg: "needed 312 bytes, capacity 256, when writing at byte 250".
+    ///
+    /// This is what the [`unwrap_fmt`] macro uses.
+    ///
+    /// [`NotEnoughSpaceError`]: ./struct.NotEnoughSpaceError.html
+    /// [`unwrap_fmt`]: ../macro.unwrap_fmt.html
+    #[track_caller]
+    pub const fn unwrap_space<T>(&self, space: Option<NotEnoughSpaceError>) -> T {
+        let space = match (self, space) {
+            (Error::NotEnoughSpace, Some(space)) => space,
+            _ => return self.unwrap(),
+        };
+
+        // Long enough for the message with three `usize::MAX` numbers
+        let writer: &mut StrWriter = &mut StrWriter::new([0u8; 192]);
+        let mut f = writer.make_formatter(FormattingFlags::NEW);
+        if f.write_str("There was not enough space to write the formatted output, ")
+            .is_err()
+            || space.const_display_fmt(&mut f).is_err()
+        {
+            return self.unwrap();
+        }
+
+        panic!("{}", writer.as_str_alt())
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
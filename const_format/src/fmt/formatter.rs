@@ -1,5 +1,9 @@
 use crate::{
-    fmt::{Error, FormattingFlags, NoEncoding, StrWriter, StrWriterMut},
+    char_encoding::char_display_len,
+    fmt::{
+        window_writer::WindowWriter, Alignment, Checkpoint, Error, FormattingFlags, NoEncoding,
+        NotEnoughSpaceError, StrWriter, StrWriterMut,
+    },
     utils::saturate_range,
     wrapper_types::{AsciiStr, PWrapper},
 };
@@ -15,8 +19,6 @@ use core::ops::Range;
 /// # Example
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::fmt::{ComputeStrLength, Error, Formatter, FormattingFlags, StrWriter};
 /// use const_format::{try_, writec, unwrap};
 ///
@@ -44,25 +46,35 @@ use core::ops::Range;
 ///
 /// ```
 ///
-/// [`formatc`]: ../macro.formatc.html
+/// # Line width
 ///
+/// When the alternate Debug formatter rewrites data structures into a single line
+/// (as described in the docs for [`FormattingFlags::set_line_width`]),
+/// writing the string temporarily needs more space than its final length.
+/// The [`required_capacity`](#method.required_capacity) method
+/// returns how long the buffer needs to be in that case.
+///
+/// [`formatc`]: ../macro.formatc.html
+/// [`FormattingFlags::set_line_width`]: ./struct.FormattingFlags.html#method.set_line_width
 ///
 pub struct ComputeStrLength {
     len: usize,
+    max_len: usize,
 }
 
 impl ComputeStrLength {
     /// Constructs a ComputeStrLength of length 0.
     pub const fn new() -> Self {
-        Self { len: 0 }
+        Self { len: 0, max_len: 0 }
     }
 
     /// Constructs a `Formatter`,
     /// which instead of writing to a buffer it adds the computed length into this.
     pub const fn make_formatter(&mut self, flags: FormattingFlags) -> Formatter<'_> {
         Formatter {
-            margin: 0,
+            depth: 0,
             flags,
+            line_start: LineStart::Owned(self.len),
             writer: WriterBackend::Length(self),
         }
     }
@@ -70,6 +82,9 @@ impl ComputeStrLength {
     /// Adds `len` to the calculated length.
     pub const fn add_len(&mut self, len: usize) {
         self.len += len;
+        if self.len > self.max_len {
+            self.max_len = self.len;
+        }
     }
 
     /// The length of the string when formatted.
@@ -77,6 +92,14 @@ impl ComputeStrLength {
         self.len
     }
 
+    /// How long a buffer must be to write the formatted string into it.
+    ///
+    /// This is only larger than [`len`](#method.len) if the alternate Debug formatter
+    /// rewrote data structures into a single line.
+    pub const fn required_capacity(&self) -> usize {
+        self.max_len
+    }
+
     /// Whether the length of the computed string is zero.
     pub const fn is_empty(&self) -> bool {
         self.len == 0
@@ -91,9 +114,125 @@ impl ComputeStrLength {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+// Where the line currently being written starts,
+// shared between a `Formatter` and the ones constructed with its `make_formatter` method.
+enum LineStart<'w> {
+    Owned(usize),
+    Borrowed(&'w mut usize),
+}
+
+// Where a builder started writing a data structure,
+// used to rewrite it into a single line when it fits the line width.
+struct Layout {
+    // The position of the opening token
+    start: usize,
+    // The start of the line with the opening token
+    outer_line_start: usize,
+    // The start of the line with the last field
+    inner_line_start: usize,
+    // How many fields (or entries) were written
+    fields: usize,
+    // Whether any field was written over multiple lines
+    multiline: bool,
+}
+
+impl Layout {
+    const fn new(fmt: &Formatter<'_>) -> Self {
+        let line_start = fmt.line_start();
+        Self {
+            start: fmt.position(),
+            outer_line_start: line_start,
+            inner_line_start: line_start,
+            fields: 0,
+            multiline: false,
+        }
+    }
+}
+
+// Writes `indent` `levels` times.
+const fn write_indent(
+    writer: &mut StrWriterMut<'_, NoEncoding>,
+    indent: &str,
+    mut levels: usize,
+) -> Result<(), Error> {
+    while levels != 0 {
+        try_!(writer.write_str(indent));
+        levels -= 1;
+    }
+    Ok(())
+}
+
+// Rewrites the fields of a data structure in `buffer[range]` from one per line,
+// into a single line.
+//
+// The fields start after the `open_skip` bytes long opening token with a line break,
+// which is replaced with `open_space`,
+// and are separated by a comma, a line break,
+// and `indent` repeated until it's `inner_margin` bytes long.
+const fn join_lines(
+    buffer: &mut [u8],
+    range: Range<usize>,
+    open_skip: usize,
+    open_space: &str,
+    mut seps: usize,
+    indent: &[u8],
+    inner_margin: usize,
+) {
+    let open_space = open_space.as_bytes();
+    let mut out = range.start;
+    let mut i = 0;
+    while i < open_space.len() {
+        buffer[out] = open_space[i];
+        out += 1;
+        i += 1;
+    }
+
+    let mut in_ = range.start + open_skip;
+    while in_ < range.end {
+        if seps != 0 && is_line_separator(buffer, in_, range.end, indent, inner_margin) {
+            buffer[out] = b',';
+            buffer[out + 1] = b' ';
+            out += COMMA_SPACE_LEN;
+            in_ += COMMA_NL_LEN + inner_margin;
+            seps -= 1;
+        } else {
+            buffer[out] = buffer[in_];
+            out += 1;
+            in_ += 1;
+        }
+    }
+}
+
+const fn is_line_separator(
+    buffer: &[u8],
+    at: usize,
+    end: usize,
+    indent: &[u8],
+    inner_margin: usize,
+) -> bool {
+    let sep_end = at + COMMA_NL_LEN + inner_margin;
+    if sep_end > end || buffer[at] != b',' || buffer[at + 1] != b'\n' {
+        return false;
+    }
+    let mut i = 0;
+    while i < inner_margin {
+        if buffer[at + COMMA_NL_LEN + i] != indent[i % indent.len()] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 enum WriterBackend<'w> {
     Str(StrWriterMut<'w, NoEncoding>),
     Length(&'w mut ComputeStrLength),
+    // Only used at runtime, by `ConstFmtAdapter` when the "alloc" feature is disabled
+    #[cfg_attr(feature = "alloc", allow(dead_code))]
+    Window(WindowWriter<'w>),
+    // Ignores everything written to it,
+    // used for the parts of a data structure that are cut off by the debug limits.
+    Discard,
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -134,6 +273,9 @@ enum WriterBackend<'w> {
 /// they can return a `Error::NotEnoughSpace` when their `finish` method is called,
 /// not as soon as it happens.
 ///
+/// A `Formatter` in [truncating mode](#method.truncating) doesn't return
+/// `Error::NotEnoughSpace` errors, it cuts the output short instead.
+///
 /// # Examples
 ///
 /// ### Display formatting
@@ -144,8 +286,6 @@ enum WriterBackend<'w> {
 /// or [`DebugTuple`] for tuple structs/variants.
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Error, Formatter, FormattingFlags, StrWriter};
 /// use const_format::{impl_fmt, try_};
 ///
@@ -186,8 +326,6 @@ enum WriterBackend<'w> {
 /// anywhere other than 0.
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Error, Formatter, FormattingFlags, StrWriter};
 /// use const_format::{impl_fmt, try_, writec};
 ///
@@ -223,21 +361,18 @@ enum WriterBackend<'w> {
 /// [`FormattingFlags`]: crate::fmt::FormattingFlags
 ///
 pub struct Formatter<'w> {
-    margin: u16,
+    depth: u16,
     flags: FormattingFlags,
+    line_start: LineStart<'w>,
     writer: WriterBackend<'w>,
 }
 
-const MARGIN_STEP: u16 = 4;
-
 impl<'w> Formatter<'w> {
     /// Constructs a `Formatter`.
     ///
     /// # Example
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::{Error, Formatter, FormattingFlags, StrWriter};
     /// use const_format::try_;
     ///
@@ -259,8 +394,9 @@ impl<'w> Formatter<'w> {
     #[inline]
     pub const fn from_sw(writer: &'w mut StrWriter, flags: FormattingFlags) -> Self {
         Self {
-            margin: 0,
+            depth: 0,
             flags,
+            line_start: LineStart::Owned(writer.len()),
             // safety:
             // Formatter only writes valid utf8, which is valid for both
             // encoding type parameters that StrWriterMut can have(Utf8Encoding / NoEncoding).
@@ -273,8 +409,6 @@ impl<'w> Formatter<'w> {
     /// # Example
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::{Error, Formatter, FormattingFlags, StrWriterMut};
     /// use const_format::try_;
     ///
@@ -302,8 +436,9 @@ impl<'w> Formatter<'w> {
         flags: FormattingFlags,
     ) -> Self {
         Self {
-            margin: 0,
+            depth: 0,
             flags,
+            line_start: LineStart::Owned(writer.len()),
             // safety:
             // Formatter only writes valid utf8, which is valid for both
             // encoding type parameters that StrWriterMut can have(Utf8Encoding / NoEncoding).
@@ -322,8 +457,6 @@ impl<'w> Formatter<'w> {
     /// that had some text written to it already.
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::{Error, Formatter, FormattingFlags, StrWriter};
     /// use const_format::{impl_fmt, try_, writec};
     ///
@@ -360,10 +493,12 @@ impl<'w> Formatter<'w> {
         length: &'w mut usize,
         flags: FormattingFlags,
     ) -> Self {
+        let writer = StrWriterMut::from_custom(buffer, length);
         Self {
-            margin: 0,
+            depth: 0,
             flags,
-            writer: WriterBackend::Str(StrWriterMut::from_custom(buffer, length)),
+            line_start: LineStart::Owned(writer.len()),
+            writer: WriterBackend::Str(writer),
         }
     }
 
@@ -381,10 +516,23 @@ impl<'w> Formatter<'w> {
         flags: FormattingFlags,
     ) -> Self {
         *length = 0;
+        let writer = StrWriterMut::from_custom(buffer, length);
         Self {
-            margin: 0,
+            depth: 0,
             flags,
-            writer: WriterBackend::Str(StrWriterMut::from_custom(buffer, length)),
+            line_start: LineStart::Owned(writer.len()),
+            writer: WriterBackend::Str(writer),
+        }
+    }
+
+    // Constructs a `Formatter` that writes the part of the output in the window of `writer`.
+    #[cfg(not(feature = "alloc"))]
+    pub(crate) const fn from_window(writer: WindowWriter<'w>, flags: FormattingFlags) -> Self {
+        Self {
+            depth: 0,
+            flags,
+            line_start: LineStart::Owned(writer.len()),
+            writer: WriterBackend::Window(writer),
         }
     }
 
@@ -394,20 +542,293 @@ impl<'w> Formatter<'w> {
         self.flags
     }
 
-    /// Gets how much indentation a data structure is printed with.
+    /// Makes this `Formatter` truncate its output when it runs out of space,
+    /// ending it with `marker`,
+    /// as described in the [`StrWriterMut::truncating`] method.
+    ///
+    /// This does nothing if this `Formatter` isn't writing into a buffer.
+    ///
+    /// With a [line width](./struct.FormattingFlags.html#line-width),
+    /// data structures are written over multiple lines before being joined into one,
+    /// so they're truncated if their multi-line form doesn't fit
+    /// (see [`ComputeStrLength::required_capacity`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{Formatter, FormattingFlags, StrWriter};
+    /// use const_format::writec;
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 24]);
+    /// let mut f = Formatter::from_sw(writer, FormattingFlags::NEW).truncating("…");
+    ///
+    /// writec!(f, "{:?}", ["temperature", "pressure", "humidity"])?;
+    /// assert!( f.is_truncated() );
+    ///
+    /// assert_eq!(writer.as_str(), r#"["temperature", "pres…"#);
+    ///
+    /// # Ok::<(), const_format::Error>(())
+    /// ```
+    ///
+    /// [`StrWriterMut::truncating`]: crate::fmt::StrWriterMut::truncating
+    /// [`ComputeStrLength::required_capacity`]: ComputeStrLength::required_capacity
+    pub const fn truncating(mut self, marker: &'static str) -> Self {
+        if let WriterBackend::Str(writer) = &mut self.writer {
+            writer.set_truncating(marker);
+        }
+        self
+    }
+
+    /// Whether this `Formatter` truncated its output,
+    /// which can only happen in [truncating mode](#method.truncating).
+    pub const fn is_truncated(&self) -> bool {
+        match &self.writer {
+            WriterBackend::Str(writer) => writer.is_truncated(),
+            WriterBackend::Length(_) | WriterBackend::Window(_) | WriterBackend::Discard => false,
+        }
+    }
+
+    /// Details about the last write that didn't fit in the buffer,
+    /// `None` if every write fit, or if this `Formatter` isn't writing into a buffer.
+    ///
+    /// This returns the same value as the
+    /// [`StrWriterMut::not_enough_space`] method of the writer
+    /// that this `Formatter` was constructed from.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::fmt::{Formatter, FormattingFlags, NotEnoughSpaceError, StrWriter};
+    /// use const_format::writec;
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 12]);
+    /// let mut f = Formatter::from_sw(writer, FormattingFlags::NEW);
+    ///
+    /// assert!(writec!(f, "{:?}", ["foo", "bar", "baz"]).is_err());
+    /// assert_eq!(
+    ///     f.not_enough_space(),
+    ///     Some(NotEnoughSpaceError { position: 8, needed: 13, capacity: 12 }),
+    /// );
+    /// ```
+    ///
+    /// [`StrWriterMut::not_enough_space`]:
+    /// crate::fmt::StrWriterMut::not_enough_space
+    pub const fn not_enough_space(&self) -> Option<NotEnoughSpaceError> {
+        match &self.writer {
+            WriterBackend::Str(writer) => writer.not_enough_space(),
+            WriterBackend::Length(_) | WriterBackend::Window(_) | WriterBackend::Discard => None,
+        }
+    }
+
+    /// Takes a snapshot of the length of the output of this `Formatter`,
+    /// which can be passed to [`rollback`](#method.rollback)
+    /// to undo everything written after this was called.
+    ///
+    /// This is useful for writing a fallback
+    /// when a composite value (eg: a whole struct) doesn't fit in the buffer,
+    /// instead of leaving it half-written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{Error, Formatter, FormattingFlags, StrWriter};
+    /// use const_format::{impl_fmt, try_};
+    ///
+    /// struct Reading {
+    ///     sensor: &'static str,
+    ///     value: u32,
+    /// }
+    ///
+    /// impl_fmt! {
+    ///     impl Reading;
+    ///
+    ///     const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+    ///         let mut f = f.debug_struct("Reading");
+    ///         try_!(f.field("sensor").write_str_debug(self.sensor));
+    ///         try_!(f.field("value").write_u32_display(self.value));
+    ///         f.finish()
+    ///     }
+    /// }
+    ///
+    /// const fn write_reading(reading: &Reading, f: &mut Formatter<'_>) -> Result<(), Error> {
+    ///     let checkpoint = f.checkpoint();
+    ///     if reading.const_debug_fmt(f).is_err() {
+    ///         f.rollback(checkpoint);
+    ///         try_!(f.write_str("Reading { .. }"));
+    ///     }
+    ///     Ok(())
+    /// }
+    ///
+    /// let reading = Reading { sensor: "thermometer", value: 21 };
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    /// write_reading(&reading, &mut Formatter::from_sw(writer, FormattingFlags::NEW))?;
+    /// assert_eq!(writer.as_str(), r#"Reading { sensor: "thermometer", value: 21 }"#);
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 32]);
+    /// write_reading(&reading, &mut Formatter::from_sw(writer, FormattingFlags::NEW))?;
+    /// assert_eq!(writer.as_str(), "Reading { .. }");
+    ///
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub const fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            len: self.position(),
+            line_start: self.line_start(),
+            truncated: self.is_truncated(),
+        }
+    }
+
+    /// Undoes everything written after `checkpoint` was
+    /// [taken](#method.checkpoint) from this `Formatter`,
+    /// including truncation in [truncating mode](#method.truncating).
+    ///
+    /// This does nothing if the output of this `Formatter`
+    /// is already shorter than it was when the checkpoint was taken.
+    ///
+    /// For an example, [look here](#method.checkpoint)
+    pub const fn rollback(&mut self, checkpoint: Checkpoint) {
+        if checkpoint.len > self.position() {
+            return;
+        }
+        match &mut self.writer {
+            WriterBackend::Str(writer) => writer.rollback(checkpoint),
+            WriterBackend::Length(fmt_len) => fmt_len.len = checkpoint.len,
+            WriterBackend::Window(writer) => writer.rollback(checkpoint.len),
+            WriterBackend::Discard => {}
+        }
+        self.set_line_start(checkpoint.line_start);
+    }
+
+    /// Gets how much indentation a data structure is printed with,
+    /// which is the length of the [indentation string](./struct.FormattingFlags.html#indentation)
+    /// times how deeply nested the data structure is.
     pub const fn margin(&self) -> usize {
-        self.margin as usize
+        self.depth as usize * self.flags.indent().len()
     }
 
     #[inline(always)]
-    const fn increment_margin(&mut self) -> &mut Self {
-        self.margin += 4;
+    const fn increment_depth(&mut self) -> &mut Self {
+        self.depth += 1;
         self
     }
 
     #[inline(always)]
-    const fn decrement_margin(&mut self) {
-        self.margin -= 4;
+    const fn decrement_depth(&mut self) {
+        self.depth -= 1;
+    }
+
+    // Whether a data structure started at the current depth is written as `..`
+    #[inline(always)]
+    const fn exceeds_max_depth(&self) -> bool {
+        self.depth as usize >= self.flags.max_depth()
+    }
+
+    // A formatter that ignores everything written to it.
+    #[inline(always)]
+    const fn discarding(&self) -> Formatter<'w> {
+        Formatter {
+            depth: self.depth,
+            flags: self.flags,
+            line_start: LineStart::Owned(self.line_start()),
+            writer: WriterBackend::Discard,
+        }
+    }
+
+    // How many bytes were written by the time this is called.
+    const fn position(&self) -> usize {
+        match &self.writer {
+            WriterBackend::Str(writer) => writer.len(),
+            WriterBackend::Length(fmt_len) => fmt_len.len(),
+            WriterBackend::Window(writer) => writer.len(),
+            WriterBackend::Discard => 0,
+        }
+    }
+
+    const fn line_start(&self) -> usize {
+        match &self.line_start {
+            LineStart::Owned(x) => *x,
+            LineStart::Borrowed(x) => **x,
+        }
+    }
+
+    const fn set_line_start(&mut self, line_start: usize) {
+        match &mut self.line_start {
+            LineStart::Owned(x) => *x = line_start,
+            LineStart::Borrowed(x) => **x = line_start,
+        }
+    }
+
+    // Called after a builder wrote the `close_len` bytes long closing token
+    // in its own line, at the current margin.
+    const fn end_multiline(&mut self, close_len: usize) {
+        let line_start = self.position().saturating_sub(self.margin() + close_len);
+        self.set_line_start(line_start);
+    }
+
+    // Called by a builder that wrote its fields over multiple lines,
+    // before writing the closing token.
+    //
+    // If the data structure fits in a single line,
+    // this rewrites the fields into a single line, and returns true.
+    //
+    // `close_len` is the length of the closing token written after the fields
+    // in single line mode.
+    const fn try_single_line(
+        &mut self,
+        layout: &Layout,
+        open_newline: &str,
+        open_space: &str,
+        close_len: usize,
+    ) -> bool {
+        let line_width = self.flags.line_width();
+        if line_width == 0 || layout.multiline || self.line_start() != layout.inner_line_start {
+            return false;
+        }
+        match &self.writer {
+            WriterBackend::Str(writer) if writer.is_truncated() => return false,
+            // The fields can't be rewritten when they're partially outside the window
+            WriterBackend::Window(_) | WriterBackend::Discard => return false,
+            _ => {}
+        }
+
+        let seps = layout.fields - 1;
+        let indent = self.flags.indent();
+        let inner_margin = self.margin() + indent.len();
+        let multiline_overhead =
+            open_newline.len() + layout.fields * inner_margin + seps * COMMA_NL_LEN;
+        let written = self.position() - layout.start;
+        if written < multiline_overhead {
+            return false;
+        }
+
+        let single_line_len =
+            open_space.len() + (written - multiline_overhead) + seps * COMMA_SPACE_LEN;
+        let trailing_comma = (self.depth != 0) as usize;
+        let column = layout.start.saturating_sub(layout.outer_line_start);
+        if column + single_line_len + close_len + trailing_comma > line_width {
+            return false;
+        }
+
+        let new_len = layout.start + single_line_len;
+        match &mut self.writer {
+            WriterBackend::Str(writer) => {
+                join_lines(
+                    writer.buffer,
+                    layout.start..*writer.len,
+                    open_newline.len() + inner_margin,
+                    open_space,
+                    seps,
+                    indent.as_bytes(),
+                    inner_margin,
+                );
+                *writer.len = new_len;
+            }
+            WriterBackend::Length(fmt_len) => fmt_len.len = new_len,
+            WriterBackend::Window(_) | WriterBackend::Discard => {}
+        }
+        self.set_line_start(layout.outer_line_start);
+        true
     }
 }
 
@@ -430,8 +851,6 @@ impl<'w> Formatter<'w> {
     /// This example demonstrates how you can change the flags when writing a field.
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::{Error, Formatter, PWrapper};
     /// use const_format::{coerce_to_fmt, formatc, impl_fmt, try_};
     ///
@@ -481,11 +900,17 @@ impl<'w> Formatter<'w> {
     ///
     pub const fn make_formatter(&mut self, flags: FormattingFlags) -> Formatter<'_> {
         Formatter {
-            margin: self.margin,
+            depth: self.depth,
             flags,
+            line_start: LineStart::Borrowed(match &mut self.line_start {
+                LineStart::Owned(x) => x,
+                LineStart::Borrowed(x) => x,
+            }),
             writer: match &mut self.writer {
                 WriterBackend::Str(x) => WriterBackend::Str(x.reborrow()),
                 WriterBackend::Length(x) => WriterBackend::Length(x),
+                WriterBackend::Window(x) => WriterBackend::Window(x.reborrow()),
+                WriterBackend::Discard => WriterBackend::Discard,
             },
         }
     }
@@ -501,10 +926,14 @@ impl<'w> Formatter<'w> {
     ///
     #[inline]
     pub const fn debug_struct(&mut self, name: &str) -> DebugStruct<'_, 'w> {
-        let err = self.write_str(name);
+        let elided = self.exceeds_max_depth();
+        let err = self.write_str(if elided { ".." } else { name });
         DebugStruct {
-            fmt: self.increment_margin(),
+            discard: self.discarding(),
+            layout: Layout::new(self),
+            fmt: if elided { self } else { self.increment_depth() },
             wrote_field: false,
+            elided,
             err,
         }
     }
@@ -519,10 +948,14 @@ impl<'w> Formatter<'w> {
     ///
     #[inline]
     pub const fn debug_tuple(&mut self, name: &str) -> DebugTuple<'_, 'w> {
-        let err = self.write_str(name);
+        let elided = self.exceeds_max_depth();
+        let err = self.write_str(if elided { ".." } else { name });
         DebugTuple {
-            fmt: self.increment_margin(),
+            discard: self.discarding(),
+            layout: Layout::new(self),
+            fmt: if elided { self } else { self.increment_depth() },
             wrote_field: false,
+            elided,
             err,
         }
     }
@@ -537,10 +970,16 @@ impl<'w> Formatter<'w> {
     ///
     #[inline]
     pub const fn debug_list(&mut self) -> DebugList<'_, 'w> {
+        let elided = self.exceeds_max_depth();
+        let err = if elided { self.write_str("..") } else { Ok(()) };
         DebugList {
-            fmt: self.increment_margin(),
+            discard: self.discarding(),
+            layout: Layout::new(self),
+            fmt: if elided { self } else { self.increment_depth() },
             wrote_field: false,
-            err: Ok(()),
+            truncated: false,
+            elided,
+            err,
         }
     }
 
@@ -554,12 +993,218 @@ impl<'w> Formatter<'w> {
     ///
     #[inline]
     pub const fn debug_set(&mut self) -> DebugSet<'_, 'w> {
+        let elided = self.exceeds_max_depth();
+        let err = if elided { self.write_str("..") } else { Ok(()) };
         DebugSet {
-            fmt: self.increment_margin(),
+            discard: self.discarding(),
+            layout: Layout::new(self),
+            fmt: if elided { self } else { self.increment_depth() },
             wrote_field: false,
-            err: Ok(()),
+            truncated: false,
+            elided,
+            err,
         }
     }
+
+    /// For debug writing a map.
+    ///
+    /// # Examples
+    ///
+    /// For examples of using this method, you can look at the docs for [`DebugMap`]
+    ///
+    /// [`DebugMap`]: ./struct.DebugMap.html
+    ///
+    #[inline]
+    pub const fn debug_map(&mut self) -> DebugMap<'_, 'w> {
+        let elided = self.exceeds_max_depth();
+        let err = if elided { self.write_str("..") } else { Ok(()) };
+        DebugMap {
+            discard: self.discarding(),
+            layout: Layout::new(self),
+            fmt: if elided { self } else { self.increment_depth() },
+            wrote_field: false,
+            truncated: false,
+            elided,
+            err,
+        }
+    }
+
+    /// Writes `string`, padded to the [width](./struct.FormattingFlags.html#width)
+    /// of the flags of this `Formatter`.
+    ///
+    /// `string` is aligned to the left unless the flags have another [`Alignment`].
+    ///
+    /// This is meant for types that are formatted as a single string,
+    /// to be padded like `&str` is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Error, Formatter};
+    /// use const_format::{formatc, impl_fmt};
+    ///
+    /// struct Color(&'static str);
+    ///
+    /// impl_fmt!{
+    ///     impl Color;
+    ///
+    ///     const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+    ///         f.pad(self.0)
+    ///     }
+    /// }
+    ///
+    /// const TABLE: &str = formatc!(
+    ///     "[{:6}]\n[{:>6}]\n[{:-^6}]",
+    ///     Color("red"),
+    ///     Color("green"),
+    ///     Color("blue"),
+    /// );
+    ///
+    /// assert_eq!(TABLE, "[red   ]\n[ green]\n[-blue-]");
+    ///
+    /// ```
+    ///
+    /// [`Alignment`]: ./enum.Alignment.html
+    pub const fn pad(&mut self, string: &str) -> Result<(), Error> {
+        let chars = count_chars(string.as_bytes());
+        let after = match self.write_padding_before(chars, string.len(), Alignment::Left) {
+            Ok(after) => after,
+            Err(e) => return Err(e),
+        };
+        try_!(self.write_str(string));
+        self.write_fill(after)
+    }
+
+    /// Writes an integer, padded to the [width](./struct.FormattingFlags.html#width)
+    /// of the flags of this `Formatter`.
+    ///
+    /// This writes a `-` if `is_nonnegative` is false,
+    /// then `prefix` if the alternate flag is enabled (eg: `"0x"` for hexadecimal),
+    /// then `digits`, aligned to the right unless the flags have another [`Alignment`].
+    ///
+    /// This is meant for number types, to be padded like the integers are.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Error, Formatter};
+    /// use const_format::{formatc, impl_fmt};
+    ///
+    /// struct BigInt {
+    ///     is_negative: bool,
+    ///     hex_digits: &'static str,
+    /// }
+    ///
+    /// impl_fmt!{
+    ///     impl BigInt;
+    ///
+    ///     const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+    ///         f.pad_integral(!self.is_negative, "0x", self.hex_digits)
+    ///     }
+    /// }
+    ///
+    /// const BIG: BigInt = BigInt{is_negative: true, hex_digits: "100000000000000000000000"};
+    /// const SMALL: BigInt = BigInt{is_negative: false, hex_digits: "1F"};
+    ///
+    /// assert_eq!(formatc!("[{:8}]", SMALL), "[      1F]");
+    /// assert_eq!(formatc!("[{:<#8}]", SMALL), "[0x1F    ]");
+    /// assert_eq!(formatc!("[{:#8}]", BIG), "[-0x100000000000000000000000]");
+    ///
+    /// ```
+    ///
+    /// [`Alignment`]: ./enum.Alignment.html
+    pub const fn pad_integral(
+        &mut self,
+        is_nonnegative: bool,
+        prefix: &str,
+        digits: &str,
+    ) -> Result<(), Error> {
+        let sign = if is_nonnegative { "" } else { "-" };
+        let prefix = if self.flags.is_alternate() {
+            prefix
+        } else {
+            ""
+        };
+
+        let chars = sign.len() + count_chars(prefix.as_bytes()) + count_chars(digits.as_bytes());
+        let len = sign.len() + prefix.len() + digits.len();
+        let after = match self.write_padding_before(chars, len, Alignment::Right) {
+            Ok(after) => after,
+            Err(e) => return Err(e),
+        };
+        try_!(self.write_str(sign));
+        try_!(self.write_str(prefix));
+        try_!(self.write_str(digits));
+        self.write_fill(after)
+    }
+
+    // Writes the fill characters that go before a value that is
+    // `chars` characters and `len` bytes long,
+    // returning how many fill characters must be written after the value with `write_fill`.
+    //
+    // This doesn't write anything if there isn't enough space for the padded value,
+    // unless the writer truncates its output.
+    pub(crate) const fn write_padding_before(
+        &mut self,
+        chars: usize,
+        len: usize,
+        default_alignment: Alignment,
+    ) -> Result<usize, Error> {
+        let padding = self.flags.width().saturating_sub(chars);
+        if padding == 0 {
+            return Ok(0);
+        }
+
+        let alignment = match self.flags.alignment() {
+            Some(alignment) => alignment,
+            None => default_alignment,
+        };
+        let (before, after) = match alignment {
+            Alignment::Left => (0, padding),
+            Alignment::Center => (padding / 2, padding - padding / 2),
+            Alignment::Right => (padding, 0),
+        };
+
+        let fill_len = char_display_len(self.flags.fill());
+        if let WriterBackend::Str(writer) = &mut self.writer {
+            if !writer.is_truncating() && writer.remaining_capacity() < len + padding * fill_len {
+                let position = writer.len();
+                return match writer.overflow(position, position + len + padding * fill_len) {
+                    Ok(()) => Ok(0),
+                    Err(e) => Err(e),
+                };
+            }
+        }
+
+        try_!(self.write_fill(before));
+        Ok(after)
+    }
+
+    // Writes the fill character `count` times.
+    pub(crate) const fn write_fill(&mut self, mut count: usize) -> Result<(), Error> {
+        let fill = self.flags.fill();
+        while count != 0 {
+            try_!(self.write_char(fill));
+            count -= 1;
+        }
+        Ok(())
+    }
+}
+
+// Counts the chars in the utf8 encoded `bytes`.
+const fn count_chars(bytes: &[u8]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        // Skipping the continuation bytes of multi-byte chars
+        if bytes[i] & 0b1100_0000 != 0b1000_0000 {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -582,6 +1227,19 @@ macro_rules! field_method_impl {
         len(|$fmt_len:ident| $($write_name_len:tt)*)
         fmt(|$writer:ident| $($write_name_fmt:tt)*)
     ) => ({
+        if $self.elided {
+            return &mut $self.discard;
+        }
+
+        let is_alternate = $self.fmt.flags.is_alternate();
+        if is_alternate && $self.fmt.line_start() != $self.layout.inner_line_start {
+            $self.layout.multiline = true;
+        }
+        let sep_start = $self.fmt.position();
+        let margin = $self.fmt.margin();
+        let indent = $self.fmt.flags.indent();
+        let depth = $self.fmt.depth as usize;
+
         match &mut $self.fmt.writer {
             WriterBackend::Length($fmt_len)=>{
                 let $fmt_len = &mut **$fmt_len;
@@ -589,19 +1247,17 @@ macro_rules! field_method_impl {
                 const OPEN_SPACE: usize = $open_space.len();
                 const OPEN_NEWLINE: usize = $open_newline.len();
 
-                let is_alternate = $self.fmt.flags.is_alternate();
                 $fmt_len.add_len(match ($self.wrote_field, is_alternate) {
                     (false, false) => OPEN_SPACE,
-                    (false, true) => OPEN_NEWLINE + $self.fmt.margin as usize,
+                    (false, true) => OPEN_NEWLINE + margin,
                     (true , false) => COMMA_SPACE_LEN,
-                    (true , true) => COMMA_NL_LEN + $self.fmt.margin as usize,
+                    (true , true) => COMMA_NL_LEN + margin,
                 });
                 $($write_name_len)*
             }
             WriterBackend::Str($writer)=>{
                 let $writer = &mut *$writer;
 
-                let is_alternate = $self.fmt.flags.is_alternate();
                 let sep = match ($self.wrote_field, is_alternate) {
                     (false, false)=>$open_space,
                     (false, true)=>$open_newline,
@@ -610,11 +1266,34 @@ macro_rules! field_method_impl {
                 };
                 trys!($writer.write_str(sep), $self);
                 if is_alternate {
-                    trys!($writer.write_ascii_repeated(b' ', $self.fmt.margin as usize), $self);
+                    trys!(write_indent($writer, indent, depth), $self);
                 }
                 $($write_name_fmt)*
             }
+            WriterBackend::Window($writer)=>{
+                let $writer = &mut *$writer;
+
+                let sep = match ($self.wrote_field, is_alternate) {
+                    (false, false)=>$open_space,
+                    (false, true)=>$open_newline,
+                    (true, false)=>", ",
+                    (true, true)=>",\n",
+                };
+                trys!($writer.write_str(sep), $self);
+                if is_alternate {
+                    $writer.write_indent(indent, depth);
+                }
+                $($write_name_fmt)*
+            }
+            WriterBackend::Discard => {}
         }
+
+        if is_alternate {
+            let sep_len = if $self.wrote_field { COMMA_NL_LEN } else { $open_newline.len() };
+            $self.layout.inner_line_start = sep_start + sep_len;
+            $self.fmt.set_line_start(sep_start + sep_len);
+        }
+        $self.layout.fields += 1;
         $self.wrote_field = true;
 
         $self.fmt
@@ -622,22 +1301,39 @@ macro_rules! field_method_impl {
 }
 
 macro_rules! finish_method_impl {
-    ($self: ident, $close_token:expr, $space_close:expr) => {{
+    (
+        $self: ident, $close_token:expr, $space_close:expr;
+        single_line($open_newline:expr, $open_space:expr)
+    ) => {{
         if let result @ Err(_) = $self.err {
             return result;
         }
+        if $self.elided {
+            return Ok(());
+        }
 
-        $self.fmt.decrement_margin();
+        $self.fmt.decrement_depth();
         if $self.wrote_field {
-            match &mut $self.fmt.writer {
+            let multiline = $self.fmt.flags.is_alternate()
+                && !$self.fmt.try_single_line(
+                    &$self.layout,
+                    $open_newline,
+                    $open_space,
+                    $space_close.len(),
+                );
+            let margin = $self.fmt.margin();
+            let indent = $self.fmt.flags.indent();
+            let depth = $self.fmt.depth as usize;
+
+            let res = match &mut $self.fmt.writer {
                 WriterBackend::Length(fmt_len) => {
                     let fmt_len = &mut **fmt_len;
 
                     const CLOSE_TOKEN: usize = $close_token.len();
                     const SPACE_CLOSE: usize = $space_close.len();
 
-                    if $self.fmt.flags.is_alternate() {
-                        fmt_len.add_len(COMMA_NL_LEN + $self.fmt.margin as usize + CLOSE_TOKEN);
+                    if multiline {
+                        fmt_len.add_len(COMMA_NL_LEN + margin + CLOSE_TOKEN);
                     } else {
                         fmt_len.add_len(SPACE_CLOSE);
                     }
@@ -646,21 +1342,115 @@ macro_rules! finish_method_impl {
                 WriterBackend::Str(writer) => {
                     let writer = &mut *writer;
 
-                    if $self.fmt.flags.is_alternate() {
+                    if multiline {
                         try_!(writer.write_str(",\n"));
-                        try_!(writer.write_ascii_repeated(b' ', $self.fmt.margin as usize));
+                        try_!(write_indent(writer, indent, depth));
                         writer.write_str($close_token)
                     } else {
                         writer.write_str($space_close)
                     }
                 }
+                WriterBackend::Window(writer) => {
+                    if multiline {
+                        try_!(writer.write_str(",\n"));
+                        writer.write_indent(indent, depth);
+                        writer.write_str($close_token)
+                    } else {
+                        writer.write_str($space_close)
+                    }
+                }
+                WriterBackend::Discard => Ok(()),
+            };
+            if multiline {
+                $self.fmt.end_multiline($close_token.len());
             }
+            res
         } else {
             Ok(())
         }
     }};
 }
 
+macro_rules! finish_non_exhaustive_method_impl {
+    (
+        $self: ident, $open_close:expr, $comma_close:expr, $close_token:expr, $dots:expr;
+        single_line($open_newline:expr, $open_space:expr)
+    ) => {{
+        if let result @ Err(_) = $self.err {
+            return result;
+        }
+        if $self.elided {
+            return Ok(());
+        }
+
+        $self.fmt.decrement_depth();
+        let margin = $self.fmt.margin();
+        let indent = $self.fmt.flags.indent();
+        let depth = $self.fmt.depth as usize;
+        let multiline = $self.wrote_field
+            && $self.fmt.flags.is_alternate()
+            && !$self.fmt.try_single_line(
+                &$self.layout,
+                $open_newline,
+                $open_space,
+                $comma_close.len(),
+            );
+
+        let res = match &mut $self.fmt.writer {
+            WriterBackend::Length(fmt_len) => {
+                let fmt_len = &mut **fmt_len;
+
+                const OPEN_CLOSE: usize = $open_close.len();
+                const COMMA_CLOSE: usize = $comma_close.len();
+                const CLOSE_TOKEN: usize = $close_token.len();
+                const DOTS_NL: usize = $dots.len() + 1;
+
+                fmt_len.add_len(match ($self.wrote_field, multiline) {
+                    (false, _) => OPEN_CLOSE,
+                    (true, false) => COMMA_CLOSE,
+                    (true, true) => {
+                        COMMA_NL_LEN + indent.len() + DOTS_NL + 2 * margin + CLOSE_TOKEN
+                    }
+                });
+                Ok(())
+            }
+            WriterBackend::Str(writer) => {
+                let writer = &mut *writer;
+
+                match ($self.wrote_field, multiline) {
+                    (false, _) => writer.write_str($open_close),
+                    (true, false) => writer.write_str($comma_close),
+                    (true, true) => {
+                        try_!(writer.write_str(",\n"));
+                        try_!(write_indent(writer, indent, depth + 1));
+                        try_!(writer.write_str($dots));
+                        try_!(writer.write_str("\n"));
+                        try_!(write_indent(writer, indent, depth));
+                        writer.write_str($close_token)
+                    }
+                }
+            }
+            WriterBackend::Window(writer) => match ($self.wrote_field, multiline) {
+                (false, _) => writer.write_str($open_close),
+                (true, false) => writer.write_str($comma_close),
+                (true, true) => {
+                    try_!(writer.write_str(",\n"));
+                    writer.write_indent(indent, depth + 1);
+                    try_!(writer.write_str($dots));
+                    try_!(writer.write_str("\n"));
+                    writer.write_indent(indent, depth);
+                    writer.write_str($close_token)
+                }
+            },
+            WriterBackend::Discard => Ok(()),
+        };
+        if multiline {
+            $self.fmt.end_multiline($close_token.len());
+        }
+        res
+    }};
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 /// A helper struct for debug formatting a braced struct, or braced variant.
@@ -671,8 +1461,6 @@ macro_rules! finish_method_impl {
 /// and a braced variant.
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Error, Formatter};
 /// use const_format::{call_debug_fmt, coerce_to_fmt, formatc, impl_fmt, try_};
 ///
@@ -733,7 +1521,12 @@ macro_rules! finish_method_impl {
 /// ```
 pub struct DebugStruct<'f, 'w> {
     fmt: &'f mut Formatter<'w>,
+    // What the fields are written into when this is elided
+    discard: Formatter<'w>,
+    layout: Layout,
     wrote_field: bool,
+    // Whether this is written as `..` for exceeding the max depth
+    elided: bool,
     err: Result<(), Error>,
 }
 
@@ -755,7 +1548,45 @@ impl<'f, 'w> DebugStruct<'f, 'w> {
     /// Finishes writing the struct/variant,
     /// and if anything went wrong in the `field` method,returns an error.
     pub const fn finish(self) -> Result<(), Error> {
-        finish_method_impl!(self, "}", " }")
+        finish_method_impl!(self, "}", " }"; single_line(" {\n", " { "))
+    }
+
+    /// Finishes writing the struct/variant with a `..` after the fields,
+    /// to show that some fields were not written,
+    /// and if anything went wrong in the `field` method,returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{Error, Formatter};
+    /// use const_format::{formatc, impl_fmt, try_};
+    ///
+    /// struct Foo {
+    ///     a: u32,
+    ///     _secret: u32,
+    /// }
+    ///
+    /// impl_fmt!{
+    ///     impl Foo;
+    ///     
+    ///     const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+    ///         let mut f = f.debug_struct("Foo");
+    ///         try_!(f.field("a").write_u32_debug(self.a));
+    ///         f.finish_non_exhaustive()
+    ///     }
+    /// }
+    ///
+    /// const FOO: Foo = Foo { a: 3, _secret: 5 };
+    ///
+    /// assert_eq!(formatc!("{:?}", FOO), "Foo { a: 3, .. }");
+    /// assert_eq!(formatc!("{:#?}", FOO), "Foo {\n    a: 3,\n    ..\n}");
+    ///
+    /// ```
+    pub const fn finish_non_exhaustive(self) -> Result<(), Error> {
+        finish_non_exhaustive_method_impl!(
+            self, " { .. }", ", .. }", "}", "..";
+            single_line(" {\n", " { ")
+        )
     }
 }
 
@@ -769,8 +1600,6 @@ impl<'f, 'w> DebugStruct<'f, 'w> {
 /// and an enum of tuple variants.
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Error, Formatter};
 /// use const_format::{call_debug_fmt, coerce_to_fmt, formatc, impl_fmt, try_};
 ///
@@ -824,7 +1653,12 @@ impl<'f, 'w> DebugStruct<'f, 'w> {
 /// ```
 pub struct DebugTuple<'f, 'w> {
     fmt: &'f mut Formatter<'w>,
+    // What the fields are written into when this is elided
+    discard: Formatter<'w>,
+    layout: Layout,
     wrote_field: bool,
+    // Whether this is written as `..` for exceeding the max depth
+    elided: bool,
     err: Result<(), Error>,
 }
 
@@ -837,28 +1671,99 @@ impl<'f, 'w> DebugTuple<'f, 'w> {
     /// Finishes writing the tuple struct/variant,
     /// and if anything went wrong in the `field` method,returns an error.
     pub const fn finish(self) -> Result<(), Error> {
-        finish_method_impl!(self, ")", ")")
+        finish_method_impl!(self, ")", ")"; single_line("(\n", "("))
+    }
+
+    /// Finishes writing the tuple struct/variant with a `..` after the fields,
+    /// to show that some fields were not written,
+    /// and if anything went wrong in the `field` method,returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{Error, Formatter};
+    /// use const_format::{formatc, impl_fmt, try_};
+    ///
+    /// struct Foo(u32, u32);
+    ///
+    /// impl_fmt!{
+    ///     impl Foo;
+    ///     
+    ///     const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+    ///         let mut f = f.debug_tuple("Foo");
+    ///         try_!(f.field().write_u32_debug(self.0));
+    ///         f.finish_non_exhaustive()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(formatc!("{:?}", Foo(3, 5)), "Foo(3, ..)");
+    /// assert_eq!(formatc!("{:#?}", Foo(3, 5)), "Foo(\n    3,\n    ..\n)");
+    ///
+    /// ```
+    pub const fn finish_non_exhaustive(self) -> Result<(), Error> {
+        finish_non_exhaustive_method_impl!(
+            self, "(..)", ", ..)", ")", "..";
+            single_line("(\n", "(")
+        )
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 
+macro_rules! limit_entries {
+    ($self: ident) => {
+        if !$self.elided && $self.layout.fields >= $self.fmt.flags.max_entries() {
+            $self.truncated = true;
+            return &mut $self.discard;
+        }
+    };
+}
+
 macro_rules! finish_listset_method_impl {
-    ($self: ident, $close_token:expr, $open_close:expr) => {{
+    (
+        $self: ident, $close_token:expr, $open_close:expr;
+        truncated($open_dots_close:expr, $comma_dots_close:expr);
+        single_line($open_newline:expr, $open_space:expr)
+    ) => {{
         if let result @ Err(_) = $self.err {
             return result;
         }
+        if $self.elided {
+            return Ok(());
+        }
+        if $self.truncated {
+            return finish_non_exhaustive_method_impl!(
+                $self,
+                $open_dots_close,
+                $comma_dots_close,
+                $close_token,
+                "...";
+                single_line($open_newline, $open_space)
+            );
+        }
 
-        match &mut $self.fmt.writer {
+        $self.fmt.decrement_depth();
+        let multiline = $self.wrote_field
+            && $self.fmt.flags.is_alternate()
+            && !$self.fmt.try_single_line(
+                &$self.layout,
+                $open_newline,
+                $open_space,
+                $close_token.len(),
+            );
+        let margin = $self.fmt.margin();
+        let indent = $self.fmt.flags.indent();
+        let depth = $self.fmt.depth as usize;
+
+        let res = match &mut $self.fmt.writer {
             WriterBackend::Length(fmt_len) => {
                 let fmt_len = &mut **fmt_len;
                 const CLOSE_TOKEN: usize = $close_token.len();
                 const OPEN_CLOSE: usize = $open_close.len();
 
-                $self.fmt.margin -= MARGIN_STEP;
                 if $self.wrote_field {
-                    if $self.fmt.flags.is_alternate() {
-                        fmt_len.add_len(COMMA_NL_LEN + $self.fmt.margin as usize);
+                    if multiline {
+                        fmt_len.add_len(COMMA_NL_LEN + margin);
                     }
                     fmt_len.add_len(CLOSE_TOKEN);
                 } else {
@@ -869,19 +1774,33 @@ macro_rules! finish_listset_method_impl {
             WriterBackend::Str(writer) => {
                 let writer = &mut *writer;
 
-                $self.fmt.margin -= MARGIN_STEP;
-                let margin = $self.fmt.margin as usize;
                 if $self.wrote_field {
-                    if $self.fmt.flags.is_alternate() {
+                    if multiline {
                         try_!(writer.write_str(",\n"));
-                        try_!(writer.write_ascii_repeated(b' ', margin));
+                        try_!(write_indent(writer, indent, depth));
                     }
                     writer.write_str($close_token)
                 } else {
                     writer.write_str($open_close)
                 }
             }
+            WriterBackend::Window(writer) => {
+                if $self.wrote_field {
+                    if multiline {
+                        try_!(writer.write_str(",\n"));
+                        writer.write_indent(indent, depth);
+                    }
+                    writer.write_str($close_token)
+                } else {
+                    writer.write_str($open_close)
+                }
+            }
+            WriterBackend::Discard => Ok(()),
+        };
+        if multiline {
+            $self.fmt.end_multiline($close_token.len());
         }
+        res
     }};
 }
 
@@ -894,8 +1813,6 @@ macro_rules! finish_listset_method_impl {
 /// This example demonstrates how you can debug format a custom type as a list.
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Error, Formatter};
 /// use const_format::{formatc, impl_fmt, try_};
 ///
@@ -927,20 +1844,32 @@ macro_rules! finish_listset_method_impl {
 ///
 pub struct DebugList<'f, 'w> {
     fmt: &'f mut Formatter<'w>,
+    // What the entries are written into when this is elided or truncated
+    discard: Formatter<'w>,
+    layout: Layout,
     wrote_field: bool,
+    // Whether entries were skipped for exceeding the max entries
+    truncated: bool,
+    // Whether this is written as `..` for exceeding the max depth
+    elided: bool,
     err: Result<(), Error>,
 }
 
 impl<'f, 'w> DebugList<'f, 'w> {
     /// Adds a list entry to the formatted output
     pub const fn entry(&mut self) -> &mut Formatter<'w> {
+        limit_entries!(self);
         field_method_impl!(self, "[", "[\n"; len(|fmt_len|) fmt(|writer|) )
     }
 
     /// Finishes writing the list,
     /// and if anything went wrong in the `entry` method,returns an error.
     pub const fn finish(self) -> Result<(), Error> {
-        finish_listset_method_impl!(self, "]", "[]")
+        finish_listset_method_impl!(
+            self, "]", "[]";
+            truncated("[...]", ", ...]");
+            single_line("[\n", "[")
+        )
     }
 }
 
@@ -953,8 +1882,6 @@ impl<'f, 'w> DebugList<'f, 'w> {
 /// This example demonstrates how you can debug format a custom type as a set.
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Error, Formatter};
 /// use const_format::{formatc, impl_fmt, try_};
 ///
@@ -986,20 +1913,152 @@ impl<'f, 'w> DebugList<'f, 'w> {
 ///
 pub struct DebugSet<'f, 'w> {
     fmt: &'f mut Formatter<'w>,
+    // What the entries are written into when this is elided or truncated
+    discard: Formatter<'w>,
+    layout: Layout,
     wrote_field: bool,
+    // Whether entries were skipped for exceeding the max entries
+    truncated: bool,
+    // Whether this is written as `..` for exceeding the max depth
+    elided: bool,
     err: Result<(), Error>,
 }
 
 impl<'f, 'w> DebugSet<'f, 'w> {
     /// Adds a set entry to the formatted output
     pub const fn entry(&mut self) -> &mut Formatter<'w> {
+        limit_entries!(self);
         field_method_impl!(self, "{", "{\n"; len(|fmt_len|) fmt(|writer|) )
     }
 
     /// Finishes writing the set,
     /// and if anything went wrong in the `entry` method,returns an error.
     pub const fn finish(self) -> Result<(), Error> {
-        finish_listset_method_impl!(self, "}", "{}")
+        finish_listset_method_impl!(
+            self, "}", "{}";
+            truncated("{...}", ", ...}");
+            single_line("{\n", "{")
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// For debug formatting a map.
+///
+/// Every call to the [`key`] method must be followed by a call to the [`value`] method,
+/// [`entry`] does both for `&str` keys.
+///
+/// # Example
+///
+/// This example demonstrates how you can debug format a lookup table as a map.
+///
+/// ```rust
+/// use const_format::{Error, Formatter};
+/// use const_format::{formatc, impl_fmt, try_};
+///
+/// fn main() {
+///     const MAP: &str = formatc!("{:?}", Table(&[(3, "foo"), (5, "bar")]));
+///     const NAMES: &str = formatc!("{:#?}", Names(&[("foo", 3), ("bar", 5)]));
+///     
+///     assert_eq!(MAP, r#"{3: "foo", 5: "bar"}"#);
+///     assert_eq!(NAMES, "{\n    \"foo\": 3,\n    \"bar\": 5,\n}");
+/// }
+///
+/// struct Table(&'static [(u32, &'static str)]);
+///
+/// impl_fmt!{
+///     impl Table;
+///     
+///     const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+///         let mut f = f.debug_map();
+///         let mut i = 0;
+///         while i < self.0.len() {
+///             let (key, value) = self.0[i];
+///             try_!(f.key().write_u32_debug(key));
+///             try_!(f.value().write_str_debug(value));
+///             i+=1;
+///         }
+///         f.finish()
+///     }
+/// }
+///
+/// struct Names(&'static [(&'static str, u32)]);
+///
+/// impl_fmt!{
+///     impl Names;
+///     
+///     const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+///         let mut f = f.debug_map();
+///         let mut i = 0;
+///         while i < self.0.len() {
+///             let (name, value) = self.0[i];
+///             try_!(f.entry(name).write_u32_debug(value));
+///             i+=1;
+///         }
+///         f.finish()
+///     }
+/// }
+///
+/// ```
+///
+/// [`key`]: #method.key
+/// [`value`]: #method.value
+/// [`entry`]: #method.entry
+///
+pub struct DebugMap<'f, 'w> {
+    fmt: &'f mut Formatter<'w>,
+    // What the entries are written into when this is elided or truncated
+    discard: Formatter<'w>,
+    layout: Layout,
+    wrote_field: bool,
+    // Whether entries were skipped for exceeding the max entries
+    truncated: bool,
+    // Whether this is written as `..` for exceeding the max depth
+    elided: bool,
+    err: Result<(), Error>,
+}
+
+impl<'f, 'w> DebugMap<'f, 'w> {
+    /// Adds the key of an entry to the formatted output,
+    /// which must be followed by a call to [`value`](#method.value).
+    pub const fn key(&mut self) -> &mut Formatter<'w> {
+        limit_entries!(self);
+        field_method_impl!(self, "{", "{\n"; len(|fmt_len|) fmt(|writer|) )
+    }
+
+    /// Adds the value of an entry to the formatted output,
+    /// which must come after a call to [`key`](#method.key).
+    pub const fn value(&mut self) -> &mut Formatter<'w> {
+        if self.elided || self.truncated {
+            return &mut self.discard;
+        }
+
+        match &mut self.fmt.writer {
+            WriterBackend::Length(fmt_len) => fmt_len.add_len(COLON_SPACE_LEN),
+            WriterBackend::Str(writer) => trys!(writer.write_str(": "), self),
+            WriterBackend::Window(writer) => trys!(writer.write_str(": "), self),
+            WriterBackend::Discard => {}
+        }
+        self.fmt
+    }
+
+    /// Adds an entry with `key` as its Debug formatted key to the formatted output,
+    /// returning the Formatter to write the value with.
+    pub const fn entry(&mut self, key: &str) -> &mut Formatter<'w> {
+        let res = self.key().write_str_debug(key);
+        trys!(res, self);
+        self.value()
+    }
+
+    /// Finishes writing the map,
+    /// and if anything went wrong in the `key`/`value`/`entry` methods,returns an error.
+    pub const fn finish(self) -> Result<(), Error> {
+        finish_listset_method_impl!(
+            self, "}", "{}";
+            truncated("{...}", ", ...}");
+            single_line("{\n", "{")
+        )
     }
 }
 
@@ -1046,6 +2105,16 @@ macro_rules! delegate_write_methods {
                 WriterBackend::Str(writer)=>{
                     writer.$method($($arg,)*)
                 }
+                WriterBackend::Window(writer)=>{
+                    let len = $len;
+                    if writer.overlaps(len) {
+                        writer.$method($($arg,)*)
+                    } else {
+                        writer.skip(len);
+                        Ok(())
+                    }
+                }
+                WriterBackend::Discard => Ok(()),
             }
         }
     )
@@ -1295,6 +2364,57 @@ delegate_write_methods! {
     fn write_ascii_debug(ascii: AsciiStr<'_>)
     length = PWrapper(ascii.as_bytes()).compute_utf8_debug_len();
 
+    /// Writes `bytes` as a Debug-formatted byte string literal.
+    ///
+    /// For more details, you can look at the docs for
+    /// [`StrWriterMut::write_bytes_debug`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Formatter, FormattingFlags, StrWriter};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 16]);
+    /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    ///
+    /// let _ = fmt.write_bytes_debug(&[b'a', 0, 0xFF, b'\t']);
+    ///
+    /// assert_eq!(writer.as_str(), r#"b"a\x00\xff\t""#);
+    ///
+    /// ```
+    ///
+    /// [`StrWriterMut::write_bytes_debug`]: ./struct.StrWriterMut.html#method.write_bytes_debug
+    fn write_bytes_debug(bytes: &[u8])
+    length = crate::char_encoding::bytes_debug_len(bytes);
+
+    /// Writes `bytes` as a hexdump, in the same format as `hexdump -C`.
+    ///
+    /// For more details, you can look at the docs for
+    /// [`StrWriterMut::write_bytes_hexdump`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Formatter, FormattingFlags, StrWriter};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 128]);
+    /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    ///
+    /// let _ = fmt.write_bytes_hexdump(b"hello");
+    ///
+    /// assert_eq!(
+    ///     writer.as_str(),
+    ///     "00000000  68 65 6c 6c 6f                                    |hello|",
+    /// );
+    ///
+    /// ```
+    ///
+    /// [`StrWriterMut::write_bytes_hexdump`]: ./struct.StrWriterMut.html#method.write_bytes_hexdump
+    fn write_bytes_hexdump(bytes: &[u8])
+    length = crate::char_encoding::bytes_hexdump_len(bytes);
+
 
     /// Write `n` with display formatting.
     ///
@@ -1406,6 +2526,16 @@ macro_rules! delegate_integer_debug_methods {
                 WriterBackend::Str(writer)=>{
                     writer.$method($($arg,)* $flags)
                 }
+                WriterBackend::Window(writer)=>{
+                    let len = $len;
+                    if writer.overlaps(len) {
+                        writer.$method($($arg,)* $flags)
+                    } else {
+                        writer.skip(len);
+                        Ok(())
+                    }
+                }
+                WriterBackend::Discard => Ok(()),
             }
         }
     )
@@ -0,0 +1,286 @@
+use crate::{
+    fmt::{Error, Formatter, FormattingFlags, StrWriterMut},
+    formatting::Alignment,
+};
+
+#[cfg(feature = "alloc")]
+use crate::fmt::ComputeStrLength;
+
+#[cfg(not(feature = "alloc"))]
+use crate::fmt::window_writer::WindowWriter;
+
+use core::fmt::{self, Binary, Debug, Display, LowerHex, UpperHex};
+
+/// The type of the functions that [`ConstFmtAdapter`] formats its value with.
+///
+/// [`ConstFmtAdapter`]: ./struct.ConstFmtAdapter.html
+pub type ConstFmtFn<T> = fn(&T, &mut Formatter<'_>) -> Result<(), Error>;
+
+/// Adapts a type with `const_display_fmt`/`const_debug_fmt` methods
+/// to the [`core::fmt`] traits,
+/// so that it can be printed with `println`, `format`, and similar macros.
+///
+/// This is usually constructed with the [`as_std_fmt`] macro.
+///
+/// # Formatting
+///
+/// This formats the value into a `N` bytes long buffer on the stack,
+/// then writes it to the `core::fmt::Formatter`.
+/// If the output doesn't fit in the buffer, the value is formatted again:
+///
+/// - With the "alloc" feature: into a heap-allocated buffer that's as long as the output.
+///
+/// - Without the "alloc" feature: in `N` bytes long chunks,
+///   formatting the value once per chunk.
+///
+/// You can use a larger buffer with the [`with_capacity`] method.
+///
+/// These traits are implemented:
+///
+/// - `Display`: formats the value with `const_display_fmt`.
+///
+/// - `Debug`: formats the value with `const_debug_fmt`.
+///
+/// - `LowerHex`, `UpperHex`, `Binary`: formats the value with `const_debug_fmt`,
+///   writing integers in hexadecimal or binary,
+///   like the `{:x}`, `{:X}`, and `{:b}` formatters of the [`formatc`] macro.
+///
+/// The `#` (alternate), width, fill, and alignment
+/// of the `core::fmt::Formatter` are passed to the const formatter through
+/// its [`FormattingFlags`].
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{Error, Formatter};
+/// use const_format::{as_std_fmt, impl_fmt, try_};
+///
+/// struct Reading {
+///     sensor: &'static str,
+///     value: u32,
+/// }
+///
+/// impl_fmt! {
+///     impl Reading;
+///
+///     const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+///         let mut f = f.debug_struct("Reading");
+///         try_!(f.field("sensor").write_str_debug(self.sensor));
+///         try_!(f.field("value").write_u32_debug(self.value));
+///         f.finish()
+///     }
+///
+///     const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+///         try_!(f.write_str(self.sensor));
+///         try_!(f.write_str(": "));
+///         f.write_u32_display(self.value)
+///     }
+/// }
+///
+/// let reading = Reading { sensor: "thermometer", value: 21 };
+///
+/// assert_eq!(format!("{}", as_std_fmt!(reading)), "thermometer: 21");
+///
+/// assert_eq!(
+///     format!("{:?}", as_std_fmt!(reading)),
+///     r#"Reading { sensor: "thermometer", value: 21 }"#,
+/// );
+///
+/// assert_eq!(
+///     format!("{:#x}", as_std_fmt!(reading)),
+///     "Reading {\n    sensor: \"thermometer\",\n    value: 0x15,\n}",
+/// );
+///
+/// ```
+///
+/// [`as_std_fmt`]: ../macro.as_std_fmt.html
+/// [`with_capacity`]: #method.with_capacity
+/// [`formatc`]: ../macro.formatc.html
+/// [`FormattingFlags`]: ./struct.FormattingFlags.html
+pub struct ConstFmtAdapter<'a, T: ?Sized, const N: usize = 512> {
+    value: &'a T,
+    display: ConstFmtFn<T>,
+    debug: ConstFmtFn<T>,
+}
+
+impl<'a, T: ?Sized> ConstFmtAdapter<'a, T> {
+    /// Constructs a `ConstFmtAdapter`,
+    /// which formats `value` with the `display` function for `Display` formatting,
+    /// and with the `debug` function for every other kind of formatting.
+    pub const fn new(value: &'a T, display: ConstFmtFn<T>, debug: ConstFmtFn<T>) -> Self {
+        Self {
+            value,
+            display,
+            debug,
+        }
+    }
+
+    /// Constructs a `ConstFmtAdapter` that formats `value` with the `display` function
+    /// for all kinds of formatting.
+    pub const fn from_display(value: &'a T, display: ConstFmtFn<T>) -> Self {
+        Self::new(value, display, display)
+    }
+
+    /// Constructs a `ConstFmtAdapter` that formats `value` with the `debug` function
+    /// for all kinds of formatting.
+    pub const fn from_debug(value: &'a T, debug: ConstFmtFn<T>) -> Self {
+        Self::new(value, debug, debug)
+    }
+}
+
+impl<'a, T: ?Sized, const N: usize> ConstFmtAdapter<'a, T, N> {
+    /// Changes the length of the buffer that the value is formatted into.
+    ///
+    /// Output longer than `M` bytes is formatted again,
+    /// as described in the [type-level docs](#formatting).
+    /// Without the "alloc" feature,
+    /// formatting returns an error if `M` is too small to fit a `char`
+    /// (less than 4 bytes).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::as_std_fmt;
+    ///
+    /// let array = [0u8; 20];
+    ///
+    /// assert_eq!(
+    ///     format!("{:?}", as_std_fmt!(debug, array).with_capacity::<16>()),
+    ///     format!("{:?}", array),
+    /// );
+    /// assert_eq!(
+    ///     format!("{:?}", as_std_fmt!(debug, array).with_capacity::<64>()),
+    ///     format!("{:?}", array),
+    /// );
+    ///
+    /// ```
+    pub const fn with_capacity<const M: usize>(self) -> ConstFmtAdapter<'a, T, M> {
+        ConstFmtAdapter {
+            value: self.value,
+            display: self.display,
+            debug: self.debug,
+        }
+    }
+
+    fn write(
+        &self,
+        func: ConstFmtFn<T>,
+        flags: FormattingFlags,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let mut buffer = [0u8; N];
+        let mut len = 0;
+        let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+
+        match func(self.value, &mut writer.make_formatter(flags)) {
+            Ok(()) => f.write_str(writer.as_str()),
+            // `not_enough_space` is `None` if `func` returned the error itself,
+            // in which case formatting again wouldn't help.
+            Err(Error::NotEnoughSpace) if writer.not_enough_space().is_some() => {
+                self.write_long(func, flags, f)
+            }
+            Err(_) => Err(fmt::Error),
+        }
+    }
+
+    // Formats the value into a heap allocated buffer of the required length.
+    #[cfg(feature = "alloc")]
+    fn write_long(
+        &self,
+        func: ConstFmtFn<T>,
+        flags: FormattingFlags,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let mut computer = ComputeStrLength::new();
+        func(self.value, &mut computer.make_formatter(flags)).map_err(|_| fmt::Error)?;
+
+        let mut buffer = alloc::vec![0u8; computer.required_capacity()];
+        let mut len = 0;
+        let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+
+        func(self.value, &mut writer.make_formatter(flags)).map_err(|_| fmt::Error)?;
+        f.write_str(writer.as_str())
+    }
+
+    // Formats the value once for every `N` bytes long chunk of the output,
+    // each one starting where the previous chunk ended.
+    #[cfg(not(feature = "alloc"))]
+    fn write_long(
+        &self,
+        func: ConstFmtFn<T>,
+        flags: FormattingFlags,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let mut start = 0;
+        loop {
+            let mut buffer = [0u8; N];
+            let mut len = 0;
+            let mut writer = WindowWriter::new(&mut buffer, start, &mut len);
+
+            let mut fmt = Formatter::from_window(writer.reborrow(), flags);
+            func(self.value, &mut fmt).map_err(|_| fmt::Error)?;
+
+            let chunk = writer.window_str();
+            f.write_str(chunk)?;
+            start += chunk.len();
+
+            if writer.len() <= start {
+                return Ok(());
+            } else if chunk.is_empty() {
+                // The buffer is too small to fit the next char.
+                return Err(fmt::Error);
+            }
+        }
+    }
+}
+
+// Converts the flags of a `core::fmt::Formatter` to `FormattingFlags`.
+fn flags_from_std(f: &fmt::Formatter<'_>) -> FormattingFlags {
+    let mut flags = FormattingFlags::NEW
+        .set_alternate(f.alternate())
+        .set_fill(f.fill());
+
+    if let Some(width) = f.width() {
+        flags = flags.set_width(width);
+    }
+
+    match f.align() {
+        Some(fmt::Alignment::Left) => flags.set_alignment(Alignment::Left),
+        Some(fmt::Alignment::Center) => flags.set_alignment(Alignment::Center),
+        Some(fmt::Alignment::Right) => flags.set_alignment(Alignment::Right),
+        None => flags,
+    }
+}
+
+impl<T: ?Sized, const N: usize> Display for ConstFmtAdapter<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(self.display, flags_from_std(f), f)
+    }
+}
+
+impl<T: ?Sized, const N: usize> Debug for ConstFmtAdapter<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(self.debug, flags_from_std(f), f)
+    }
+}
+
+impl<T: ?Sized, const N: usize> LowerHex for ConstFmtAdapter<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flags = flags_from_std(f).set_lower_hexadecimal();
+        self.write(self.debug, flags, f)
+    }
+}
+
+impl<T: ?Sized, const N: usize> UpperHex for ConstFmtAdapter<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flags = flags_from_std(f).set_hexadecimal();
+        self.write(self.debug, flags, f)
+    }
+}
+
+impl<T: ?Sized, const N: usize> Binary for ConstFmtAdapter<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flags = flags_from_std(f).set_binary();
+        self.write(self.debug, flags, f)
+    }
+}
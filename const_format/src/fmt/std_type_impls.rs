@@ -1,18 +1,22 @@
 #![allow(missing_docs)]
 
 use crate::{
-    fmt::{Error, Formatter},
+    char_encoding::char_display_len,
+    fmt::{Alignment, Error, Formatter},
     marker_traits::IsStdKind,
     wrapper_types::PWrapper,
 };
 
 mod ranges;
 
+#[cfg(feature = "nightly_const_traits")]
+mod const_trait_impls;
+
 ////////////////////////////////////////////////////////////////////////////////
 
 impl PWrapper<&str> {
     pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        f.write_str(self.0)
+        f.pad(self.0)
     }
 
     pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
@@ -22,7 +26,7 @@ impl PWrapper<&str> {
 
 impl PWrapper<bool> {
     pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        f.write_str(if self.0 { "true" } else { "false" })
+        f.pad(if self.0 { "true" } else { "false" })
     }
 
     #[inline(always)]
@@ -33,7 +37,13 @@ impl PWrapper<bool> {
 
 impl PWrapper<char> {
     pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        f.write_char(self.0)
+        let len = char_display_len(self.0);
+        let after = match f.write_padding_before(1, len, Alignment::Left) {
+            Ok(after) => after,
+            Err(e) => return Err(e),
+        };
+        try_!(f.write_char(self.0));
+        f.write_fill(after)
     }
 
     #[inline(always)]
@@ -0,0 +1,117 @@
+//! Implementations of the `ConstDisplay` and `ConstDebug` traits for std types,
+//! delegating to the inherent methods of `PWrapper`.
+
+use crate::fmt::{ConstDebug, ConstDisplay, Error, Formatter};
+
+use core::{
+    cmp::Ordering,
+    marker::{PhantomData, PhantomPinned},
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    },
+    ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+    ptr::NonNull,
+    sync::atomic::Ordering as AtomicOrdering,
+};
+
+macro_rules! const_trait_impls {
+    (
+        $trait:ident :: $method:ident;
+        $( impl[$($impl_:tt)*] $type:ty; )*
+    ) => (
+        $(
+            impl<$($impl_)*> const $trait for $type {
+                #[inline(always)]
+                fn $method(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    coerce_to_fmt!(self).$method(f)
+                }
+            }
+        )*
+    )
+}
+
+const_trait_impls! {
+    ConstDisplay::const_display_fmt;
+
+    impl[] str;
+    impl[] bool;
+    impl[] char;
+    impl[] u8; impl[] i8;
+    impl[] u16; impl[] i16;
+    impl[] u32; impl[] i32;
+    impl[] u64; impl[] i64;
+    impl[] u128; impl[] i128;
+    impl[] usize; impl[] isize;
+    impl[] NonZeroU8; impl[] NonZeroI8;
+    impl[] NonZeroU16; impl[] NonZeroI16;
+    impl[] NonZeroU32; impl[] NonZeroI32;
+    impl[] NonZeroU64; impl[] NonZeroI64;
+    impl[] NonZeroU128; impl[] NonZeroI128;
+    impl[] NonZeroUsize; impl[] NonZeroIsize;
+}
+
+const_trait_impls! {
+    ConstDebug::const_debug_fmt;
+
+    impl[] str;
+    impl[] bool;
+    impl[] char;
+    impl[] u8; impl[] i8;
+    impl[] u16; impl[] i16;
+    impl[] u32; impl[] i32;
+    impl[] u64; impl[] i64;
+    impl[] u128; impl[] i128;
+    impl[] usize; impl[] isize;
+    impl[] NonZeroU8; impl[] NonZeroI8;
+    impl[] NonZeroU16; impl[] NonZeroI16;
+    impl[] NonZeroU32; impl[] NonZeroI32;
+    impl[] NonZeroU64; impl[] NonZeroI64;
+    impl[] NonZeroU128; impl[] NonZeroI128;
+    impl[] NonZeroUsize; impl[] NonZeroIsize;
+    impl[T] *const T;
+    impl[T] *mut T;
+    impl[T] NonNull<T>;
+    impl[T: ?Sized] PhantomData<T>;
+    impl[] PhantomPinned;
+    impl[] ();
+    impl[] Ordering;
+    impl[] AtomicOrdering;
+    impl[] Range<usize>;
+    impl[] RangeFrom<usize>;
+    impl[] RangeFull;
+    impl[] RangeInclusive<usize>;
+    impl[] RangeTo<usize>;
+    impl[] RangeToInclusive<usize>;
+}
+
+impl<T: [const] ConstDebug> const ConstDebug for [T] {
+    fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut f = f.debug_list();
+        __for_range! {i in 0..self.len() =>
+            try_!(self[i].const_debug_fmt(f.entry()));
+        }
+        f.finish()
+    }
+}
+
+impl<T: [const] ConstDebug, const N: usize> const ConstDebug for [T; N] {
+    #[inline(always)]
+    fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let slice: &[T] = self;
+        slice.const_debug_fmt(f)
+    }
+}
+
+impl<T: [const] ConstDebug> const ConstDebug for Option<T> {
+    fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            Some(x) => {
+                let mut f = f.debug_tuple("Some");
+                try_!(x.const_debug_fmt(f.field()));
+                f.finish()
+            }
+            None => f.write_str("None"),
+        }
+    }
+}
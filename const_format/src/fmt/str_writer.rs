@@ -1,4 +1,6 @@
-use super::{Error, Formatter, FormattingFlags, StrWriterMut, Utf8Encoding};
+use super::{
+    str_writer_mut::WriterState, Error, Formatter, FormattingFlags, StrWriterMut, Utf8Encoding,
+};
 
 use core::marker::PhantomData;
 
@@ -65,9 +67,7 @@ use core::marker::PhantomData;
 /// This example shows how you can construct a formatted `&'static str` from associated constants.
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
-/// use const_format::{StrWriter, writec, unwrap};
+/// use const_format::{StrWriter, writec, unwrap_fmt};
 ///
 /// trait Num {
 ///     const V: u32;
@@ -89,7 +89,7 @@ use core::marker::PhantomData;
 ///
 /// const fn compute_str(l: u32, r: u32) -> StrWriter<[u8; 128]> {
 ///     let mut writer = StrWriter::new([0; 128]);
-///     unwrap!(writec!(writer, "{} * {} == {}", l, r, l * r ));
+///     unwrap_fmt!(writer, |f| writec!(f, "{} * {} == {}", l, r, l * r));
 ///     writer
 /// }
 ///
@@ -315,8 +315,6 @@ impl StrWriter {
     /// # Example
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::{StrWriter, StrWriterMut};
     ///
     /// const fn slice() -> StrWriter<[u8; 64]> {
@@ -352,10 +350,8 @@ impl StrWriter {
     /// # Example
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::StrWriter;
-    /// use const_format::{unwrap, writec};
+    /// use const_format::{unwrap_fmt, writec};
     ///
     ///
     /// const CAP: usize = 128;
@@ -364,7 +360,7 @@ impl StrWriter {
     ///     let mut writer =  StrWriter::new([0; CAP]);
     ///
     ///     // Writing the array with debug formatting, and the integers with hexadecimal formatting.
-    ///     unwrap!(writec!(writer, "{:X}", [3u32, 5, 8, 13, 21, 34]));
+    ///     unwrap_fmt!(writer, |f| writec!(f, "{:X}", [3u32, 5, 8, 13, 21, 34]));
     ///
     ///     writer
     /// };
@@ -448,6 +444,7 @@ impl StrWriter {
         StrWriterMut {
             len: &mut self.len,
             buffer: &mut self.buffer,
+            state: WriterState::NEW,
             _encoding: PhantomData,
         }
     }
@@ -458,8 +455,6 @@ impl StrWriter {
     /// # Example
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::{Error, Formatter, FormattingFlags, StrWriter, call_debug_fmt};
     ///
     /// use std::ops::Range;
@@ -495,6 +490,7 @@ impl StrWriter {
             StrWriterMut::<Utf8Encoding> {
                 len: &mut self.len,
                 buffer: &mut self.buffer,
+                state: WriterState::NEW,
                 _encoding: PhantomData,
             },
             flags,
@@ -565,6 +561,7 @@ impl<const N: usize> StrWriter<[u8; N]> {
         StrWriterMut {
             len: &mut self.len,
             buffer: &mut self.buffer,
+            state: WriterState::NEW,
             _encoding: PhantomData,
         }
     }
@@ -577,3 +574,78 @@ impl<A: ?Sized> StrWriter<A> {
         self
     }
 }
+
+impl StrWriter {
+    /// Writes runtime formatted arguments into this `StrWriter`,
+    /// so that it can be used as the writer of the `write`/`writeln` macros
+    /// without importing the [`core::fmt::Write`] trait.
+    ///
+    /// This can't be called in const contexts,
+    /// since `core::fmt::Arguments` can only be formatted at runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `core::fmt::Error` when the buffer runs out of space,
+    /// keeping the arguments written before the one that didn't fit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{StrWriter, writec};
+    ///
+    /// const fn header(writer: &mut StrWriter) -> const_format::Result {
+    ///     writec!(writer, "[{}] ", "const header")
+    /// }
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    ///
+    /// header(writer).unwrap();
+    /// write!(writer, "{} runtime args", std::env::args().count()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     writer.as_str(),
+    ///     format!("[const header] {} runtime args", std::env::args().count()),
+    /// );
+    ///
+    /// ```
+    pub fn write_fmt(&mut self, args: core::fmt::Arguments<'_>) -> core::fmt::Result {
+        core::fmt::Write::write_fmt(self, args)
+    }
+}
+
+impl core::fmt::Write for StrWriter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.as_mut().write_str(s).map_err(|_| core::fmt::Error)
+    }
+}
+
+impl<const N: usize> StrWriter<[u8; N]> {
+    /// Writes runtime formatted arguments into this `StrWriter`,
+    /// so that it can be used as the writer of the `write`/`writeln` macros
+    /// without importing the [`core::fmt::Write`] trait.
+    ///
+    /// For more details you can look at the
+    /// [`StrWriter<[u8]>` method](struct.StrWriter.html#method.write_fmt).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::StrWriter;
+    ///
+    /// let mut writer = StrWriter::new([0; 16]);
+    ///
+    /// writeln!(writer, "{:?}", [3, 5]).unwrap();
+    ///
+    /// assert_eq!(writer.r().as_str(), "[3, 5]\n");
+    ///
+    /// ```
+    pub fn write_fmt(&mut self, args: core::fmt::Arguments<'_>) -> core::fmt::Result {
+        core::fmt::Write::write_fmt(self, args)
+    }
+}
+
+impl<const N: usize> core::fmt::Write for StrWriter<[u8; N]> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.as_mut().write_str(s).map_err(|_| core::fmt::Error)
+    }
+}
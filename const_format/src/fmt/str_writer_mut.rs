@@ -1,12 +1,10 @@
 use crate::{
-    formatting::{
-        hex_as_ascii, ForEscaping, FormattingFlags, HexFormatting, NumberFormatting, FOR_ESCAPING,
-    },
+    formatting::{hex_as_ascii, Alignment, FormattingFlags, HexFormatting, NumberFormatting},
     utils::{min_usize, saturate_range, Constructor},
     wrapper_types::{AsciiStr, PWrapper},
 };
 
-use super::{Error, Formatter, StrWriter};
+use super::{Error, Formatter, NotEnoughSpaceError, StrWriter};
 
 use core::{marker::PhantomData, ops::Range};
 
@@ -36,6 +34,37 @@ use core::{marker::PhantomData, ops::Range};
 /// Every single `write_*` method returns an [`Error::NotEnoughSpace`] if
 /// there is not enough space to write the argument, leaving the string itself unmodified.
 ///
+/// The [`not_enough_space`] method returns details about the last write that didn't fit,
+/// like how long the buffer needed to be.
+///
+/// # Truncation
+///
+/// A `StrWriterMut` in truncating mode (constructed with the [`truncating`] method)
+/// doesn't return [`Error::NotEnoughSpace`] errors,
+/// instead it writes as much as fits, then appends a marker (eg: `"…"`),
+/// and ignores all writes after that.
+///
+/// The written text is cut at the last char boundary that leaves room for the marker,
+/// and whether this happened can be queried with the [`is_truncated`] method.
+///
+/// Strings (including Debug-formatted strings) are cut in the middle,
+/// while integers, byte strings, and hexdumps are either written in full or not at all.
+///
+/// ```rust
+/// use const_format::{StrWriter, StrWriterMut, unwrap, writec};
+///
+/// const fn describe(code: u32, buffer: &mut StrWriter<[u8]>) {
+///     let mut writer = StrWriterMut::new(buffer).truncating("…");
+///     unwrap!(writec!(writer, "error {}: sensor is not responding", code));
+/// }
+///
+/// let mut buffer = StrWriter::new([0; 20]);
+/// describe(404, &mut buffer);
+///
+/// assert_eq!(buffer.r().as_str(), "error 404: sensor…");
+///
+/// ```
+///
 /// # Encoding type parameter
 ///
 /// The `E` type parameter represents the encoding of the buffer that this
@@ -48,8 +77,6 @@ use core::{marker::PhantomData, ops::Range};
 /// using a `StrWriterMut`.
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Error, StrWriterMut, try_, writec};
 ///
 /// const fn format_number(number: u32,slice: &mut [u8]) -> Result<usize, Error> {
@@ -72,6 +99,9 @@ use core::{marker::PhantomData, ops::Range};
 ///
 /// [`from_custom_cleared`]: #method.from_custom_cleared
 /// [`from_custom`]: #method.from_custom
+/// [`truncating`]: #method.truncating
+/// [`not_enough_space`]: #method.not_enough_space
+/// [`is_truncated`]: #method.is_truncated
 ///
 /// [`Utf8Encoding`]: crate::fmt::Utf8Encoding
 /// [`NoEncoding`]: crate::fmt::NoEncoding
@@ -81,11 +111,34 @@ use core::{marker::PhantomData, ops::Range};
 pub struct StrWriterMut<'w, E = Utf8Encoding> {
     pub(super) len: &'w mut usize,
     pub(super) buffer: &'w mut [u8],
+    pub(super) state: WriterState<'w>,
     pub(super) _encoding: PhantomData<Constructor<E>>,
 }
 
+// The state of a StrWriterMut besides the written text.
+pub(crate) enum WriterState<'w> {
+    Owned(StateFields),
+    // Shares the state with the StrWriterMut that this was reborrowed from.
+    Borrowed(&'w mut StateFields),
+}
+
+#[derive(Copy, Clone)]
+pub(crate) struct StateFields {
+    // The marker written at the end of truncated output,
+    // `None` if this doesn't truncate its output.
+    marker: Option<&'static str>,
+    truncated: bool,
+    // The last write that didn't fit in the buffer.
+    space_error: Option<NotEnoughSpaceError>,
+}
+
+// Starts a write method,
+// returning early if this already truncated its output.
 macro_rules! borrow_fields {
     ($self:ident, $len:ident, $buffer:ident) => {
+        if $self.is_truncated() {
+            return Ok(());
+        }
         let $len = &mut *$self.len;
         let $buffer = &mut *$self.buffer;
     };
@@ -103,6 +156,23 @@ pub enum Utf8Encoding {}
 /// [`StrWriterMut`]: ./struct.StrWriterMut.html
 pub enum NoEncoding {}
 
+/// A snapshot of the length of a [`StrWriterMut`] or [`Formatter`],
+/// which the `rollback` method of the same writer goes back to.
+///
+/// This is constructed with the [`StrWriterMut::checkpoint`] and
+/// [`Formatter::checkpoint`] methods.
+///
+/// [`StrWriterMut`]: ./struct.StrWriterMut.html
+/// [`Formatter`]: ./struct.Formatter.html
+/// [`StrWriterMut::checkpoint`]: ./struct.StrWriterMut.html#method.checkpoint
+/// [`Formatter::checkpoint`]: ./struct.Formatter.html#method.checkpoint
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub(super) len: usize,
+    pub(super) line_start: usize,
+    pub(super) truncated: bool,
+}
+
 impl<'w> StrWriterMut<'w, Utf8Encoding> {
     /// Constructs a `StrWriterMut` from a mutable reference to a `StrWriter`
     ///
@@ -125,6 +195,7 @@ impl<'w> StrWriterMut<'w, Utf8Encoding> {
         Self {
             len: &mut writer.len,
             buffer: &mut writer.buffer,
+            state: WriterState::NEW,
             _encoding: PhantomData,
         }
     }
@@ -159,6 +230,7 @@ impl<'w> StrWriterMut<'w, NoEncoding> {
         Self {
             len: length,
             buffer,
+            state: WriterState::NEW,
             _encoding: PhantomData,
         }
     }
@@ -200,6 +272,7 @@ impl<'w> StrWriterMut<'w, Utf8Encoding> {
         Self {
             len: length,
             buffer,
+            state: WriterState::NEW,
             _encoding: PhantomData,
         }
     }
@@ -328,6 +401,260 @@ impl<'w, E> StrWriterMut<'w, E> {
     pub const fn remaining_capacity(&self) -> usize {
         self.buffer.len() - *self.len
     }
+
+    /// Makes this `StrWriterMut` truncate its output when it runs out of space,
+    /// ending it with `marker`.
+    ///
+    /// Instead of returning [`Error::NotEnoughSpace`] errors,
+    /// the `write_*` methods write as much as fits,
+    /// cut at the last char boundary that leaves room for `marker`,
+    /// then write `marker`, ignoring all later writes.
+    ///
+    /// If `marker` doesn't fit in the buffer at all,
+    /// this writes as many of its leading chars as fit.
+    ///
+    /// For more details, [look here](#truncation).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{StrWriter, StrWriterMut};
+    ///
+    /// let mut buffer = StrWriter::new([0; 12]);
+    /// let mut writer = StrWriterMut::new(&mut buffer).truncating("…");
+    ///
+    /// writer.write_str("Hello, ")?;
+    /// assert!( !writer.is_truncated() );
+    ///
+    /// writer.write_str("wörld!")?;
+    /// assert!( writer.is_truncated() );
+    ///
+    /// // Writes after truncation are ignored.
+    /// writer.write_str(" Bye!")?;
+    ///
+    /// assert_eq!(writer.as_str(), "Hello, w…");
+    ///
+    /// # Ok::<(), const_format::Error>(())
+    /// ```
+    ///
+    /// [`Error::NotEnoughSpace`]: crate::fmt::Error::NotEnoughSpace
+    #[inline]
+    pub const fn truncating(mut self, marker: &'static str) -> Self {
+        self.set_truncating(marker);
+        self
+    }
+
+    /// Whether this `StrWriterMut` truncated its output,
+    /// which can only happen in [truncating mode](#method.truncating).
+    ///
+    /// This is reset by the `clear` and `truncate` methods.
+    ///
+    /// The [`not_enough_space`](#method.not_enough_space) method
+    /// returns details about the truncated write.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{StrWriter, StrWriterMut};
+    ///
+    /// let mut buffer = StrWriter::new([0; 7]);
+    /// let mut writer = StrWriterMut::new(&mut buffer).truncating("...");
+    ///
+    /// writer.write_u32_display(1234)?;
+    /// assert!( !writer.is_truncated() );
+    ///
+    /// writer.write_u32_display(5678)?;
+    /// assert!( writer.is_truncated() );
+    /// assert_eq!(writer.as_str(), "1234...");
+    ///
+    /// writer.clear();
+    /// assert!( !writer.is_truncated() );
+    ///
+    /// # Ok::<(), const_format::Error>(())
+    /// ```
+    #[inline]
+    pub const fn is_truncated(&self) -> bool {
+        self.state.get().truncated
+    }
+
+    /// Details about the last write that didn't fit in the buffer,
+    /// `None` if every write fit.
+    ///
+    /// This is reset by the `clear` method.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::fmt::{Error, NotEnoughSpaceError, StrWriter, StrWriterMut};
+    ///
+    /// let mut buffer = StrWriter::new([0; 8]);
+    /// let mut writer = StrWriterMut::new(&mut buffer);
+    ///
+    /// writer.write_str("foo")?;
+    /// assert_eq!(writer.not_enough_space(), None);
+    ///
+    /// assert_eq!(writer.write_str("barbaz"), Err(Error::NotEnoughSpace));
+    /// assert_eq!(
+    ///     writer.not_enough_space(),
+    ///     Some(NotEnoughSpaceError { position: 3, needed: 9, capacity: 8 }),
+    /// );
+    ///
+    /// writer.clear();
+    /// assert_eq!(writer.not_enough_space(), None);
+    ///
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn not_enough_space(&self) -> Option<NotEnoughSpaceError> {
+        self.state.get().space_error
+    }
+
+    /// Takes a snapshot of the length of this `StrWriterMut`,
+    /// which can be passed to [`rollback`](#method.rollback)
+    /// to undo everything written after this was called.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{Error, StrWriter, StrWriterMut};
+    ///
+    /// let mut buffer = StrWriter::new([0; 16]);
+    /// let mut writer = StrWriterMut::new(&mut buffer);
+    ///
+    /// writer.write_str("ids:")?;
+    ///
+    /// let checkpoint = writer.checkpoint();
+    /// let res = (|| {
+    ///     writer.write_str(" 1000,")?;
+    ///     writer.write_str(" 1001,")?;
+    ///     writer.write_str(" 1002")
+    /// })();
+    ///
+    /// assert_eq!(res, Err(Error::NotEnoughSpace));
+    /// assert_eq!(writer.as_str(), "ids: 1000, 1001,");
+    ///
+    /// writer.rollback(checkpoint);
+    /// assert_eq!(writer.as_str(), "ids:");
+    ///
+    /// writer.write_str(" ...")?;
+    /// assert_eq!(writer.as_str(), "ids: ...");
+    ///
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub const fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            len: *self.len,
+            line_start: *self.len,
+            truncated: self.is_truncated(),
+        }
+    }
+
+    /// Undoes everything written after `checkpoint` was
+    /// [taken](#method.checkpoint) from this `StrWriterMut`,
+    /// including truncation in [truncating mode](#method.truncating).
+    ///
+    /// This does nothing if the length of this `StrWriterMut`
+    /// is already shorter than that of the checkpoint,
+    /// or if it is not on a char boundary,
+    /// which can only happen if the checkpoint is from a different writer,
+    /// or the writer was cleared after the checkpoint was taken.
+    ///
+    /// For an example, [look here](#method.checkpoint)
+    #[inline]
+    pub const fn rollback(&mut self, checkpoint: Checkpoint) {
+        if checkpoint.len <= *self.len && is_valid_str_index(self.buffer, checkpoint.len) {
+            *self.len = checkpoint.len;
+            self.state.get_mut().truncated = checkpoint.truncated;
+        }
+    }
+
+    // Makes this truncate its output, without sharing the truncated flag with the parent.
+    pub(crate) const fn set_truncating(&mut self, marker: &'static str) {
+        let mut state = *self.state.get();
+        state.marker = Some(marker);
+        state.truncated = false;
+        self.state = WriterState::Owned(state);
+    }
+
+    // Whether this is in truncating mode.
+    #[inline]
+    pub(crate) const fn is_truncating(&self) -> bool {
+        self.state.get().marker.is_some()
+    }
+
+    // The marker written at the end of truncated output.
+    const fn marker(&self) -> &'static str {
+        match self.state.get().marker {
+            Some(marker) => marker,
+            None => "",
+        }
+    }
+
+    // Called when the value being written doesn't fit,
+    // after `self.buffer[self.len..written]` was written with (the start of) that value,
+    // `needed` is the buffer length required to write the entire value.
+    //
+    // If this is in truncating mode,
+    // this cuts the written text at a char boundary, and appends the marker.
+    // Otherwise this returns an error, without changing the length.
+    pub(crate) const fn overflow(&mut self, written: usize, needed: usize) -> Result<(), Error> {
+        let capacity = self.buffer.len();
+        let state = self.state.get_mut();
+        state.space_error = Some(NotEnoughSpaceError {
+            position: *self.len,
+            needed,
+            capacity,
+        });
+        let marker = match state.marker {
+            Some(marker) => marker.as_bytes(),
+            None => return Err(Error::NotEnoughSpace),
+        };
+        state.truncated = true;
+
+        let mut end = min_usize(written, capacity.saturating_sub(marker.len()));
+        while end != 0 && !is_valid_str_index(self.buffer, end) {
+            end -= 1;
+        }
+
+        let mut marker_len = min_usize(marker.len(), capacity - end);
+        while !is_valid_str_index(marker, marker_len) {
+            marker_len -= 1;
+        }
+
+        __for_range! {i in 0..marker_len =>
+            self.buffer[end + i] = marker[i];
+        }
+        *self.len = end + marker_len;
+
+        Ok(())
+    }
+}
+
+impl<'w> WriterState<'w> {
+    pub(crate) const NEW: Self = WriterState::Owned(StateFields {
+        marker: None,
+        truncated: false,
+        space_error: None,
+    });
+
+    const fn get(&self) -> &StateFields {
+        match self {
+            WriterState::Owned(x) => x,
+            WriterState::Borrowed(x) => x,
+        }
+    }
+
+    const fn get_mut(&mut self) -> &mut StateFields {
+        match self {
+            WriterState::Owned(x) => x,
+            WriterState::Borrowed(x) => x,
+        }
+    }
+
+    const fn reborrow(&mut self) -> WriterState<'_> {
+        WriterState::Borrowed(self.get_mut())
+    }
 }
 
 impl<'w> StrWriterMut<'w, Utf8Encoding> {
@@ -368,6 +695,7 @@ impl<'w> StrWriterMut<'w, Utf8Encoding> {
             }
 
             *self.len = length;
+            self.state.get_mut().truncated = false;
         }
         Ok(())
     }
@@ -405,6 +733,7 @@ impl<'w> StrWriterMut<'w, NoEncoding> {
     pub const fn truncate(&mut self, length: usize) {
         if length < *self.len {
             *self.len = length;
+            self.state.get_mut().truncated = false;
         }
     }
 }
@@ -436,6 +765,9 @@ impl<'w, E> StrWriterMut<'w, E> {
     #[inline]
     pub const fn clear(&mut self) {
         *self.len = 0;
+        let state = self.state.get_mut();
+        state.truncated = false;
+        state.space_error = None;
     }
 
     /// Gets the written part of this `StrWriterMut` as a `&[u8]`
@@ -452,8 +784,6 @@ impl<'w, E> StrWriterMut<'w, E> {
     /// # Example
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::{StrWriter, StrWriterMut};
     ///
     /// let mut buffer = StrWriter::new([0; 64]);
@@ -483,10 +813,8 @@ impl<'w> StrWriterMut<'w, Utf8Encoding> {
     /// # Example
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::{StrWriter, StrWriterMut};
-    /// use const_format::{unwrap, writec};
+    /// use const_format::{unwrap_fmt, writec};
     ///
     ///
     /// const CAP: usize = 128;
@@ -496,7 +824,7 @@ impl<'w> StrWriterMut<'w, Utf8Encoding> {
     ///     let mut writer = StrWriterMut::new(&mut buffer);
     ///
     ///     // Writing the array with debug formatting, and the integers with hexadecimal formatting.
-    ///     unwrap!(writec!(writer, "{:X}", [3u32, 5, 8, 13, 21, 34]));
+    ///     unwrap_fmt!(writer, |f| writec!(f, "{:X}", [3u32, 5, 8, 13, 21, 34]));
     ///
     ///     buffer
     /// };
@@ -531,8 +859,6 @@ impl<'w> StrWriterMut<'w, Utf8Encoding> {
         /// # Example
         ///
         /// ```rust
-        /// #![feature(const_mut_refs)]
-        ///
         /// use const_format::{StrWriter, StrWriterMut};
         ///
         /// let mut buffer = StrWriter::new([0; 64]);
@@ -567,8 +893,6 @@ impl<'w, E> StrWriterMut<'w, E> {
         /// # Example
         ///
         /// ```rust
-        /// #![feature(const_mut_refs)]
-        ///
         /// use const_format::{StrWriter, StrWriterMut};
         ///
         /// let mut buffer = StrWriter::new([0; 64]);
@@ -593,8 +917,6 @@ impl<'w, E> StrWriterMut<'w, E> {
     /// # Example
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::{Error, Formatter, FormattingFlags, StrWriter, StrWriterMut};
     /// use const_format::call_debug_fmt;
     ///
@@ -631,6 +953,7 @@ impl<'w, E> StrWriterMut<'w, E> {
             StrWriterMut::<NoEncoding> {
                 len: self.len,
                 buffer: self.buffer,
+                state: self.state.reborrow(),
                 _encoding: PhantomData,
             },
             flags,
@@ -649,8 +972,6 @@ impl<'w, E> StrWriterMut<'w, E> {
     /// # Example
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::{Error, FormattingFlags, StrWriter, StrWriterMut, call_debug_fmt};
     ///
     /// use std::ops::Range;
@@ -682,6 +1003,7 @@ impl<'w, E> StrWriterMut<'w, E> {
         StrWriterMut {
             len: self.len,
             buffer: self.buffer,
+            state: self.state.reborrow(),
             _encoding: PhantomData,
         }
     }
@@ -691,6 +1013,7 @@ impl<'w, E> StrWriterMut<'w, E> {
         StrWriterMut {
             len: self.len,
             buffer: self.buffer,
+            state: self.state,
             _encoding: PhantomData,
         }
     }
@@ -733,15 +1056,37 @@ macro_rules! write_integer_fn {
             /// Writes a
             #[doc = $ty_name]
             /// with Display formatting.
+            ///
+            /// This is padded to the width of the flags of the formatter.
             pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-                f.$display_fn(self.0)
+                if f.flags().width() == 0 {
+                    return f.$display_fn(self.0);
+                }
+                let len = self.compute_display_len(f.flags());
+                let after = match f.write_padding_before(len, len, Alignment::Right) {
+                    Ok(after) => after,
+                    Err(e) => return Err(e),
+                };
+                try_!(f.$display_fn(self.0));
+                f.write_fill(after)
             }
 
             /// Writes a
             #[doc = $ty_name]
             /// with Debug formatting.
+            ///
+            /// This is padded to the width of the flags of the formatter.
             pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-                f.$debug_fn(self.0)
+                if f.flags().width() == 0 {
+                    return f.$debug_fn(self.0);
+                }
+                let len = self.compute_debug_len(f.flags());
+                let after = match f.write_padding_before(len, len, Alignment::Right) {
+                    Ok(after) => after,
+                    Err(e) => return Err(e),
+                };
+                try_!(f.$debug_fn(self.0));
+                f.write_fill(after)
             }
         }
     };
@@ -764,7 +1109,8 @@ macro_rules! write_integer_fn {
             let mut cursor = *this_len + len;
 
             if cursor > this_buffer.len() {
-                return Err(Error::NotEnoughSpace);
+                let written = *this_len;
+                return self.overflow(written, cursor);
             }
 
             write_integer_fn!(@unsigned_abs $sign, n);
@@ -802,7 +1148,8 @@ macro_rules! write_integer_fn {
                 let mut cursor = *this_len + len;
 
                 if cursor > this_buffer.len() {
-                    return Err(Error::NotEnoughSpace);
+                    let written = *this_len;
+                    return this.overflow(written, cursor);
                 }
 
                 if is_alternate {
@@ -837,7 +1184,8 @@ macro_rules! write_integer_fn {
                 let mut cursor = *this_len + len;
 
                 if cursor > this_buffer.len() {
-                    return Err(Error::NotEnoughSpace);
+                    let written = *this_len;
+                    return this.overflow(written, cursor);
                 }
 
                 if is_alternate {
@@ -1080,7 +1428,13 @@ impl<'w, E> StrWriterMut<'w, E> {
         let end = *self_len + repeated;
 
         if end > self_buffer.len() {
-            return Err(Error::NotEnoughSpace);
+            let capacity = self.buffer.len();
+            let mut written = *self.len;
+            while self.is_truncating() && written < capacity {
+                self.buffer[written] = character;
+                written += 1;
+            }
+            return self.overflow(written, end);
         }
 
         while *self_len < end {
@@ -1103,7 +1457,15 @@ impl<'w, E> StrWriterMut<'w, E> {
         let len = end - start;
 
         if *self_len + len > self_buffer.len() {
-            return Err(Error::NotEnoughSpace);
+            let needed = *self_len + len;
+            let capacity = self.buffer.len();
+            let mut written = *self.len;
+            while self.is_truncating() && written < capacity {
+                self.buffer[written] = bytes[start];
+                written += 1;
+                start += 1;
+            }
+            return self.overflow(written, needed);
         }
 
         while start < end {
@@ -1284,11 +1646,13 @@ impl<'w, E> StrWriterMut<'w, E> {
 
         // + 2 for the quote characters around the string.
         if *self_len + len + 2 > self_buffer.len() {
-            return Err(Error::NotEnoughSpace);
+            return self.write_str_debug_overflowed(bytes, start, end);
         }
 
+        let str_start = start;
+
         // The amount of bytes available for escapes,
-        // not counting the `writte_c`.
+        // which is reduced by how much longer each escaped char is than the unescaped one.
         let mut remaining_for_escapes = (self_buffer.len() - 2 - len - *self_len) as isize;
         let mut written = *self_len;
 
@@ -1297,41 +1661,35 @@ impl<'w, E> StrWriterMut<'w, E> {
 
         while start != end {
             let c = bytes[start];
-            let mut written_c = c;
 
-            let mut shifted = 0;
-
-            if c < 128
-                && ({
-                    shifted = 1 << c;
-                    (FOR_ESCAPING.is_escaped & shifted) != 0
-                })
-            {
-                self_buffer[written] = b'\\';
-                written += 1;
-
-                if (FOR_ESCAPING.is_backslash_escaped & shifted) != 0 {
+            if let 0x20..=0x7E = c {
+                if c == b'"' || c == b'\\' {
                     remaining_for_escapes -= 1;
                     if remaining_for_escapes < 0 {
-                        return Err(Error::NotEnoughSpace);
+                        return self.write_str_debug_overflowed(bytes, str_start, end);
                     }
-                    written_c = ForEscaping::get_backslash_escape(c);
-                } else {
-                    remaining_for_escapes -= 3;
-                    if remaining_for_escapes < 0 {
-                        return Err(Error::NotEnoughSpace);
-                    }
-                    self_buffer[written] = b'x';
-                    written += 1;
-                    self_buffer[written] = hex_as_ascii(c >> 4, HexFormatting::Upper);
+                    self_buffer[written] = b'\\';
                     written += 1;
-                    written_c = hex_as_ascii(c & 0xF, HexFormatting::Upper);
-                };
+                }
+                self_buffer[written] = c;
+                written += 1;
+                start += 1;
+                continue;
             }
 
-            self_buffer[written] = written_c;
-            written += 1;
-            start += 1;
+            let (fmt, char_len) = crate::char_encoding::str_char_to_debug(bytes, start, end);
+
+            remaining_for_escapes -= (fmt.len() - char_len) as isize;
+            if remaining_for_escapes < 0 {
+                return self.write_str_debug_overflowed(bytes, str_start, end);
+            }
+
+            let encoded = fmt.encoded();
+            __for_range! {i in 0..fmt.len() =>
+                self_buffer[written] = encoded[i];
+                written += 1;
+            }
+            start += char_len;
         }
 
         self_buffer[written] = b'"';
@@ -1341,6 +1699,202 @@ impl<'w, E> StrWriterMut<'w, E> {
 
         Ok(())
     }
+
+    // Writes the Debug-formatted `bytes[start..end]` string when it doesn't fit,
+    // writing as much of it as fits in truncating mode, returning an error otherwise.
+    const fn write_str_debug_overflowed(
+        &mut self,
+        bytes: &[u8],
+        mut start: usize,
+        end: usize,
+    ) -> Result<(), Error> {
+        let needed = *self.len + crate::char_encoding::str_debug_len(bytes, start, end);
+        if !self.is_truncating() {
+            let written = *self.len;
+            return self.overflow(written, needed);
+        }
+
+        // Leaving space for the marker, so that escapes aren't cut in half.
+        let capacity = self.buffer.len().saturating_sub(self.marker().len());
+        let mut written = *self.len;
+
+        if written < capacity {
+            self.buffer[written] = b'"';
+            written += 1;
+        }
+
+        while start != end {
+            let (fmt, char_len) = crate::char_encoding::str_char_to_debug(bytes, start, end);
+            if written + fmt.len() > capacity {
+                break;
+            }
+
+            let encoded = fmt.encoded();
+            __for_range! {i in 0..fmt.len() =>
+                self.buffer[written] = encoded[i];
+                written += 1;
+            }
+            start += char_len;
+        }
+
+        self.overflow(written, needed)
+    }
+}
+
+/// Byte string writing
+impl<'w, E> StrWriterMut<'w, E> {
+    /// Writes `bytes` as a Debug-formatted byte string literal.
+    ///
+    /// The bytes are escaped the same way that
+    /// [`u8::escape_ascii`](https://doc.rust-lang.org/std/primitive.u8.html#method.escape_ascii)
+    /// escapes them, surrounded by `b"` and `"`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::StrWriterMut;
+    ///
+    /// let mut len = 0;
+    /// let mut buffer = [0; 64];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    ///
+    /// let _ = writer.write_bytes_debug(b"foo\n\xFF\"bar\"");
+    ///
+    /// assert_eq!(writer.as_str(), r#"b"foo\n\xff\"bar\"""#);
+    ///
+    /// ```
+    ///
+    pub const fn write_bytes_debug(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        borrow_fields!(self, self_len, self_buffer);
+
+        let end = *self_len + crate::char_encoding::bytes_debug_len(bytes);
+        if end > self_buffer.len() {
+            let written = *self_len;
+            return self.overflow(written, end);
+        }
+
+        let mut written = *self_len;
+
+        self_buffer[written] = b'b';
+        self_buffer[written + 1] = b'"';
+        written += 2;
+
+        iter_copy_slice! {b in bytes =>
+            let (escaped, len) = crate::char_encoding::byte_to_escaped_ascii(b);
+            __for_range! {i in 0..len =>
+                self_buffer[written] = escaped[i];
+                written += 1;
+            }
+        }
+
+        self_buffer[written] = b'"';
+        *self_len = end;
+
+        Ok(())
+    }
+
+    /// Writes `bytes` as a hexdump,
+    /// in the same format as the `hexdump -C` command.
+    ///
+    /// Each line has the offset of its first byte (as 8 hexadecimal digits),
+    /// up to 16 bytes as hexadecimal, and then those bytes as ascii,
+    /// where non-printable bytes are replaced with `.`.
+    ///
+    /// Lines are separated with `\n`, without a trailing newline,
+    /// and empty slices write nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::StrWriterMut;
+    ///
+    /// let mut len = 0;
+    /// let mut buffer = [0; 256];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    ///
+    /// let _ = writer.write_bytes_hexdump(b"Hello, world!\n\0\x01\x02ab");
+    ///
+    /// assert_eq!(
+    ///     writer.as_str(),
+    ///     "\
+    /// 00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 01  |Hello, world!...|
+    /// 00000010  02 61 62                                          |.ab|\
+    /// ",
+    /// );
+    ///
+    /// ```
+    ///
+    pub const fn write_bytes_hexdump(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        borrow_fields!(self, self_len, self_buffer);
+
+        let end = *self_len + crate::char_encoding::bytes_hexdump_len(bytes);
+        if end > self_buffer.len() {
+            let written = *self_len;
+            return self.overflow(written, end);
+        }
+
+        let mut written = *self_len;
+        let mut line_start = 0;
+
+        while line_start < bytes.len() {
+            if line_start != 0 {
+                self_buffer[written] = b'\n';
+                written += 1;
+            }
+
+            __for_range! {i in 0..8 =>
+                let nibble = ((line_start >> (4 * (7 - i))) & 0xF) as u8;
+                self_buffer[written] = hex_as_ascii(nibble, HexFormatting::Lower);
+                written += 1;
+            }
+            self_buffer[written] = b' ';
+            written += 1;
+
+            let line_end = min_usize(line_start + 16, bytes.len());
+
+            __for_range! {i in 0..16 =>
+                self_buffer[written] = b' ';
+                written += 1;
+                if i == 8 {
+                    self_buffer[written] = b' ';
+                    written += 1;
+                }
+                if line_start + i < line_end {
+                    let b = bytes[line_start + i];
+                    self_buffer[written] = hex_as_ascii(b >> 4, HexFormatting::Lower);
+                    self_buffer[written + 1] = hex_as_ascii(b & 0xF, HexFormatting::Lower);
+                } else {
+                    self_buffer[written] = b' ';
+                    self_buffer[written + 1] = b' ';
+                }
+                written += 2;
+            }
+
+            self_buffer[written] = b' ';
+            self_buffer[written + 1] = b' ';
+            self_buffer[written + 2] = b'|';
+            written += 3;
+
+            __for_range! {i in line_start..line_end =>
+                self_buffer[written] = match bytes[i] {
+                    b @ 0x20..=0x7E => b,
+                    _ => b'.',
+                };
+                written += 1;
+            }
+
+            self_buffer[written] = b'|';
+            written += 1;
+
+            line_start = line_end;
+        }
+
+        *self_len = end;
+
+        Ok(())
+    }
 }
 
 write_integer_fn! {
@@ -1370,8 +1924,6 @@ write_integer_fn! {
         /// # Example
         ///
         /// ```rust
-        /// #![feature(const_mut_refs)]
-        ///
         /// use const_format::{FormattingFlags, StrWriterMut};
         ///
         /// const fn debug_fmt<'a>(
@@ -1434,3 +1986,60 @@ write_integer_fn! {
     (write_i128_display, write_i128_debug, signed, i128, u128)
     (write_isize_display, write_isize_debug, signed, isize, usize)
 }
+
+////////////////////////////////////////////////////////////////////////////////
+
+impl<'w> StrWriterMut<'w, Utf8Encoding> {
+    /// Writes runtime formatted arguments into this `StrWriterMut`,
+    /// so that it can be used as the writer of the `write`/`writeln` macros
+    /// without importing the [`core::fmt::Write`] trait.
+    ///
+    /// This can't be called in const contexts,
+    /// since `core::fmt::Arguments` can only be formatted at runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `core::fmt::Error` when the buffer runs out of space,
+    /// keeping the arguments written before the one that didn't fit
+    /// ([`not_enough_space`](#method.not_enough_space)
+    /// reports the write that didn't fit).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::StrWriterMut;
+    ///
+    /// use std::fmt::Write as _;
+    ///
+    /// let mut len = 0;
+    /// let mut buffer = [0; 32];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    ///
+    /// // Writing with const methods first
+    /// let _ = writer.write_str("id: ");
+    /// let _ = writer.write_u32_display(3);
+    ///
+    /// // Then runtime formatting
+    /// let runtime_value = std::env::args().count();
+    /// writeln!(writer, ", args: {}", runtime_value).unwrap();
+    ///
+    /// assert_eq!(writer.as_str(), format!("id: 3, args: {}\n", runtime_value));
+    ///
+    /// // Using the `core::fmt::Write` impl
+    /// let res = core::fmt::Write::write_char(&mut writer, 'a');
+    /// assert!(res.is_ok());
+    ///
+    /// // `core::fmt::Error` is returned on error
+    /// assert!(write!(writer, "{}", "very".repeat(100)).is_err());
+    ///
+    /// ```
+    pub fn write_fmt(&mut self, args: core::fmt::Arguments<'_>) -> core::fmt::Result {
+        core::fmt::Write::write_fmt(self, args)
+    }
+}
+
+impl core::fmt::Write for StrWriterMut<'_, Utf8Encoding> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        StrWriterMut::write_str(self, s).map_err(|_| core::fmt::Error)
+    }
+}
@@ -0,0 +1,194 @@
+use super::{Error, Formatter, FormattingFlags, StrWriterMut};
+
+use alloc::{string::String, vec::Vec};
+
+use core::cmp::max;
+
+/// A heap-allocated buffer that grows to fit whatever is formatted into it,
+/// for using const formatting code at runtime.
+///
+/// This is only available with the "alloc" feature.
+///
+/// # Growing
+///
+/// A [`Formatter`] writes into a fixed-size buffer,
+/// so `StringWriter` can't grow while it's being written to.
+/// Instead, the [`write_with`] method calls the formatting function,
+/// and if it runs out of space, grows the buffer and calls the function again,
+/// discarding the partially written output.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::fmt::{Error, Formatter, FormattingFlags, StringWriter};
+/// use const_format::{call_debug_fmt, impl_fmt};
+///
+/// struct Matrix([[u32; 4]; 4]);
+///
+/// impl_fmt! {
+///     impl Matrix;
+///
+///     const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+///         let mut f = f.debug_list();
+///         let mut i = 0;
+///         while i < self.0.len() {
+///             call_debug_fmt!(array, self.0[i], f.entry());
+///             i += 1;
+///         }
+///         f.finish()
+///     }
+/// }
+///
+/// let matrix = Matrix([[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11], [12, 13, 14, 15]]);
+///
+/// let mut writer = StringWriter::new();
+///
+/// writer.write_with(FormattingFlags::NEW, |f| matrix.const_debug_fmt(f))?;
+///
+/// assert_eq!(
+///     writer.as_str(),
+///     "[[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11], [12, 13, 14, 15]]",
+/// );
+///
+/// # Ok::<(), const_format::Error>(())
+/// ```
+///
+/// [`Formatter`]: ./struct.Formatter.html
+/// [`write_with`]: #method.write_with
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "alloc")))]
+#[derive(Debug, Default, Clone)]
+pub struct StringWriter {
+    buffer: Vec<u8>,
+    len: usize,
+}
+
+// The capacity that the buffer is grown to the first time that it's written to.
+const MIN_CAPACITY: usize = 64;
+
+impl StringWriter {
+    /// Constructs an empty `StringWriter`, without allocating.
+    pub const fn new() -> Self {
+        Self {
+            buffer: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Constructs an empty `StringWriter` with `capacity` bytes of space.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: alloc::vec![0; capacity],
+            len: 0,
+        }
+    }
+
+    /// Calls `func` with a [`Formatter`] that appends to this writer,
+    /// growing the buffer and calling `func` again whenever it returns
+    /// an `Error::NotEnoughSpace` error.
+    ///
+    /// `func` can be called multiple times,
+    /// only the output of the last call is kept.
+    ///
+    /// # Errors
+    ///
+    /// This returns any error that `func` returns other than `Error::NotEnoughSpace`,
+    /// keeping the output written before the error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::fmt::{FormattingFlags, StringWriter};
+    /// use const_format::writec;
+    ///
+    /// let mut writer = StringWriter::new();
+    ///
+    /// writer.write_with(FormattingFlags::NEW, |f| writec!(f, "{:#?} {:?}", [3u8, 5], "foo"))?;
+    ///
+    /// assert_eq!(writer.as_str(), "[\n    3,\n    5,\n] \"foo\"");
+    ///
+    /// # Ok::<(), const_format::Error>(())
+    /// ```
+    ///
+    /// [`Formatter`]: ./struct.Formatter.html
+    pub fn write_with<F>(&mut self, flags: FormattingFlags, mut func: F) -> Result<(), Error>
+    where
+        F: FnMut(&mut Formatter<'_>) -> Result<(), Error>,
+    {
+        let start = self.len;
+        loop {
+            let mut writer = StrWriterMut::from_custom(&mut self.buffer, &mut self.len);
+
+            let needed = match func(&mut writer.make_formatter(flags)) {
+                // `not_enough_space` is `None` if `func` returned the error itself,
+                // in which case growing the buffer wouldn't help.
+                Err(Error::NotEnoughSpace) => match writer.not_enough_space() {
+                    Some(e) => e.needed,
+                    None => return Err(Error::NotEnoughSpace),
+                },
+                res => return res,
+            };
+
+            self.len = start;
+            let new_len = max(max(needed, self.buffer.len() * 2), MIN_CAPACITY);
+            self.buffer.resize(new_len, 0);
+        }
+    }
+
+    /// Gets the written part of this `StringWriter` as a `&str`.
+    pub fn as_str(&self) -> &str {
+        // The written part of the buffer is always valid utf8,
+        // since it's only written through `StrWriterMut<'_, Utf8Encoding>`.
+        unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Gets the written part of this `StringWriter` as a `&[u8]`.
+    ///
+    /// The slice is guaranteed to be valid utf8.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+
+    /// Gets how many bytes are written into this `StringWriter`.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether this `StringWriter` is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets how many bytes this `StringWriter` can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Truncates this `StringWriter` to length 0, keeping the allocated buffer.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Converts this `StringWriter` into a `String` with the written text.
+    pub fn into_string(mut self) -> String {
+        self.buffer.truncate(self.len);
+        // The written part of the buffer is always valid utf8.
+        unsafe { String::from_utf8_unchecked(self.buffer) }
+    }
+}
+
+/// Formats `func` into a `String`,
+/// panicking if `func` returns an error.
+#[doc(hidden)]
+pub fn __to_string<F>(flags: FormattingFlags, func: F) -> String
+where
+    F: FnMut(&mut Formatter<'_>) -> Result<(), Error>,
+{
+    let mut writer = StringWriter::new();
+    if let Err(e) = writer.write_with(flags, func) {
+        panic!(
+            "a formatting method returned an error unexpectedly: {:?}",
+            e
+        );
+    }
+    writer.into_string()
+}
@@ -0,0 +1,343 @@
+use crate::{
+    fmt::{Error, FormattingFlags, StrWriterMut},
+    formatting::{hex_as_ascii, HexFormatting},
+    utils::{min_usize, saturate_range},
+    wrapper_types::AsciiStr,
+};
+
+use core::ops::Range;
+
+// The length of the buffer that writes overlapping the window are formatted into,
+// it fits every integer, char, and piece of a longer write below.
+const SCRATCH_LEN: usize = 136;
+
+// How many bytes of a string/byte slice are formatted at a time
+// when it's written with Debug formatting
+// (string pieces are extended to the next char boundary),
+// which is at most 6 bytes of output per input byte.
+const DEBUG_PIECE_LEN: usize = 16;
+
+// Formats into a stack buffer with the `StrWriterMut` method of the same name,
+// then writes the output without its first `$skip_start` and last `$skip_end` bytes.
+macro_rules! scratch_write {
+    ($self:ident, $method:ident($($arg:expr),*) $(; skip($skip_start:expr, $skip_end:expr))?) => {{
+        let mut buffer = [0u8; SCRATCH_LEN];
+        let mut len = 0;
+        try_!(StrWriterMut::from_custom(&mut buffer, &mut len).$method($($arg),*));
+
+        let (start, end) = (0, len);
+        $( let (start, end) = (start + $skip_start, end - $skip_end); )?
+        $self.push_range(&buffer, start, end);
+    }};
+}
+
+// The methods for writes that always fit in the scratch buffer.
+macro_rules! scratch_write_methods {
+    ($( fn $method:ident($($arg:ident: $arg_ty:ty),*); )*) => {
+        impl WindowWriter<'_> {
+            $(
+                pub(crate) const fn $method(&mut self, $($arg: $arg_ty),*) -> Result<(), Error> {
+                    scratch_write!(self, $method($($arg),*));
+                    Ok(())
+                }
+            )*
+        }
+    };
+}
+
+// Writes the part of the formatted output in the `start..start + buffer.len()` range
+// (the window) into `buffer`, and skips the rest of the output.
+//
+// This is used by `ConstFmtAdapter` to write output that doesn't fit in its buffer
+// in buffer-sized chunks, formatting the value once per chunk.
+// Since it's only used at runtime, it's kept out of `StrWriterMut`,
+// so that formatting at compile-time doesn't need to check for the window.
+pub(crate) struct WindowWriter<'w> {
+    buffer: &'w mut [u8],
+    start: usize,
+    // The length of the output written so far, including the parts outside the window.
+    len: &'w mut usize,
+}
+
+impl<'w> WindowWriter<'w> {
+    #[cfg(not(feature = "alloc"))]
+    pub(crate) const fn new(buffer: &'w mut [u8], start: usize, len: &'w mut usize) -> Self {
+        *len = 0;
+        Self { buffer, start, len }
+    }
+
+    pub(crate) const fn reborrow(&mut self) -> WindowWriter<'_> {
+        WindowWriter {
+            buffer: self.buffer,
+            start: self.start,
+            len: self.len,
+        }
+    }
+
+    pub(crate) const fn len(&self) -> usize {
+        *self.len
+    }
+
+    pub(crate) const fn rollback(&mut self, len: usize) {
+        *self.len = len;
+    }
+
+    // The longest prefix of the text in the window that ends on a char boundary.
+    #[cfg(not(feature = "alloc"))]
+    pub(crate) const fn window_str(&self) -> &str {
+        let bytes: &[u8] = self.buffer;
+        let written = min_usize(self.len.saturating_sub(self.start), bytes.len());
+
+        let mut end = written;
+        if written == bytes.len() && written != 0 {
+            // Finding where the last (possibly cut-off) char starts
+            let mut last = written;
+            while last != 0 {
+                last -= 1;
+                if (bytes[last] as i8) >= -0x40 {
+                    break;
+                }
+            }
+            if last + utf8_len(bytes[last]) > written {
+                end = last;
+            }
+        }
+
+        match core::str::from_utf8(bytes.split_at(end).0) {
+            Ok(x) => x,
+            Err(_) => "",
+        }
+    }
+
+    // Whether writing `len` bytes writes anything into the window.
+    pub(crate) const fn overlaps(&self, len: usize) -> bool {
+        *self.len < self.start + self.buffer.len() && *self.len + len > self.start
+    }
+
+    pub(crate) const fn skip(&mut self, len: usize) {
+        *self.len += len;
+    }
+
+    const fn push(&mut self, bytes: &[u8]) {
+        self.push_range(bytes, 0, bytes.len())
+    }
+
+    const fn push_range(&mut self, bytes: &[u8], mut start: usize, end: usize) {
+        if !self.overlaps(end - start) {
+            *self.len += end - start;
+            return;
+        }
+        while start < end {
+            if let Some(i) = self.len.checked_sub(self.start) {
+                if i < self.buffer.len() {
+                    self.buffer[i] = bytes[start];
+                }
+            }
+            *self.len += 1;
+            start += 1;
+        }
+    }
+
+    pub(crate) const fn write_indent(&mut self, indent: &str, mut levels: usize) {
+        while levels != 0 {
+            self.push(indent.as_bytes());
+            levels -= 1;
+        }
+    }
+
+    pub(crate) const fn write_str_range(
+        &mut self,
+        s: &str,
+        range: Range<usize>,
+    ) -> Result<(), Error> {
+        let bytes = s.as_bytes();
+        let Range { start, end } = saturate_range(bytes, &range);
+
+        if !is_char_boundary(bytes, start) || !is_char_boundary(bytes, end) {
+            return Err(Error::NotOnCharBoundary);
+        }
+
+        self.push_range(bytes, start, end);
+        Ok(())
+    }
+
+    pub(crate) const fn write_str(&mut self, s: &str) -> Result<(), Error> {
+        self.push(s.as_bytes());
+        Ok(())
+    }
+
+    pub(crate) const fn write_ascii_range(
+        &mut self,
+        ascii: AsciiStr<'_>,
+        range: Range<usize>,
+    ) -> Result<(), Error> {
+        let bytes = ascii.as_bytes();
+        let Range { start, end } = saturate_range(bytes, &range);
+
+        self.push_range(bytes, start, end);
+        Ok(())
+    }
+
+    pub(crate) const fn write_ascii(&mut self, ascii: AsciiStr<'_>) -> Result<(), Error> {
+        self.push(ascii.as_bytes());
+        Ok(())
+    }
+
+    pub(crate) const fn write_ascii_repeated(
+        &mut self,
+        character: u8,
+        mut repeated: usize,
+    ) -> Result<(), Error> {
+        // Truncating non-ascii u8s
+        let character = [character & 0b111_1111];
+
+        while repeated != 0 {
+            self.push(&character);
+            repeated -= 1;
+        }
+        Ok(())
+    }
+
+    pub(crate) const fn write_str_range_debug(
+        &mut self,
+        s: &str,
+        range: Range<usize>,
+    ) -> Result<(), Error> {
+        let bytes = s.as_bytes();
+        let Range { start, end } = saturate_range(bytes, &range);
+
+        if !is_char_boundary(bytes, start) || !is_char_boundary(bytes, end) {
+            return Err(Error::NotOnCharBoundary);
+        }
+
+        self.push(b"\"");
+        let mut piece_start = start;
+        while piece_start < end {
+            let mut piece_end = min_usize(piece_start + DEBUG_PIECE_LEN, end);
+            while !is_char_boundary(bytes, piece_end) {
+                piece_end += 1;
+            }
+
+            // Writing the escaped piece without its quotes
+            scratch_write!(self, write_str_range_debug(s, piece_start..piece_end); skip(1, 1));
+            piece_start = piece_end;
+        }
+        self.push(b"\"");
+        Ok(())
+    }
+
+    pub(crate) const fn write_str_debug(&mut self, s: &str) -> Result<(), Error> {
+        self.write_str_range_debug(s, 0..s.len())
+    }
+
+    pub(crate) const fn write_ascii_range_debug(
+        &mut self,
+        ascii: AsciiStr<'_>,
+        range: Range<usize>,
+    ) -> Result<(), Error> {
+        let Range { start, end } = saturate_range(ascii.as_bytes(), &range);
+
+        self.push(b"\"");
+        let mut piece_start = start;
+        while piece_start < end {
+            let piece_end = min_usize(piece_start + DEBUG_PIECE_LEN, end);
+
+            // Writing the escaped piece without its quotes
+            scratch_write!(self, write_ascii_range_debug(ascii, piece_start..piece_end); skip(1, 1));
+            piece_start = piece_end;
+        }
+        self.push(b"\"");
+        Ok(())
+    }
+
+    pub(crate) const fn write_ascii_debug(&mut self, ascii: AsciiStr<'_>) -> Result<(), Error> {
+        self.write_ascii_range_debug(ascii, 0..ascii.len())
+    }
+
+    pub(crate) const fn write_bytes_debug(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        self.push(b"b\"");
+        let mut rem = bytes;
+        while !rem.is_empty() {
+            let (piece, rest) = rem.split_at(min_usize(DEBUG_PIECE_LEN, rem.len()));
+
+            // Writing the escaped piece without its `b"` prefix and `"` suffix
+            scratch_write!(self, write_bytes_debug(piece); skip(2, 1));
+            rem = rest;
+        }
+        self.push(b"\"");
+        Ok(())
+    }
+
+    pub(crate) const fn write_bytes_hexdump(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let mut line_start = 0;
+        let mut rem = bytes;
+        while !rem.is_empty() {
+            if line_start != 0 {
+                self.push(b"\n");
+            }
+            let (line, rest) = rem.split_at(min_usize(16, rem.len()));
+
+            let mut buffer = [0u8; SCRATCH_LEN];
+            let mut len = 0;
+            try_!(StrWriterMut::from_custom(&mut buffer, &mut len).write_bytes_hexdump(line));
+
+            // The line was written as if it was the first one,
+            // so its offset is replaced with the offset in `bytes`.
+            let mut i = 0;
+            while i < 8 {
+                let nibble = ((line_start >> (4 * (7 - i))) & 0xF) as u8;
+                buffer[i] = hex_as_ascii(nibble, HexFormatting::Lower);
+                i += 1;
+            }
+            self.push_range(&buffer, 0, len);
+
+            line_start += line.len();
+            rem = rest;
+        }
+        Ok(())
+    }
+}
+
+scratch_write_methods! {
+    fn write_char(character: char);
+    fn write_char_debug(character: char);
+
+    fn write_u8_display(n: u8);
+    fn write_u16_display(n: u16);
+    fn write_u32_display(n: u32);
+    fn write_u64_display(n: u64);
+    fn write_u128_display(n: u128);
+    fn write_usize_display(n: usize);
+    fn write_i8_display(n: i8);
+    fn write_i16_display(n: i16);
+    fn write_i32_display(n: i32);
+    fn write_i64_display(n: i64);
+    fn write_i128_display(n: i128);
+    fn write_isize_display(n: isize);
+
+    fn write_u8_debug(n: u8, flags: FormattingFlags);
+    fn write_u16_debug(n: u16, flags: FormattingFlags);
+    fn write_u32_debug(n: u32, flags: FormattingFlags);
+    fn write_u64_debug(n: u64, flags: FormattingFlags);
+    fn write_u128_debug(n: u128, flags: FormattingFlags);
+    fn write_usize_debug(n: usize, flags: FormattingFlags);
+    fn write_i8_debug(n: i8, flags: FormattingFlags);
+    fn write_i16_debug(n: i16, flags: FormattingFlags);
+    fn write_i32_debug(n: i32, flags: FormattingFlags);
+    fn write_i64_debug(n: i64, flags: FormattingFlags);
+    fn write_i128_debug(n: i128, flags: FormattingFlags);
+    fn write_isize_debug(n: isize, flags: FormattingFlags);
+}
+
+const fn is_char_boundary(bytes: &[u8], index: usize) -> bool {
+    index == bytes.len() || (bytes[index] as i8) >= -0x40
+}
+
+// The length of the utf8 encoded char that starts with `lead`.
+#[cfg(not(feature = "alloc"))]
+const fn utf8_len(lead: u8) -> usize {
+    match lead.leading_ones() {
+        0 => 1,
+        n => n as usize,
+    }
+}
@@ -2,10 +2,10 @@
 //!
 //! # Features
 //!
-//! This module is only exported with the "fmt" feature, and the nightly compiler,
-//! because at the time of writing these docs (2021-08-XX) mutable references in const fn
-//! require the unstable
-//! [`const_mut_refs`](https://github.com/rust-lang/rust/issues/57349) feature.
+//! This module is only exported with the "fmt" feature,
+//! and either Rust 1.83.0 with the "rust_1_83" feature, or the nightly compiler,
+//! because it uses mutable references in const fn,
+//! which were stabilized in Rust 1.83.0.
 
 use crate::{impl_fmt, try_, Error, Formatter, PWrapper};
 
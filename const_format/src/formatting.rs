@@ -41,6 +41,19 @@ pub enum HexFormatting {
     Lower = b'a' - 10,
 }
 
+/// How a value is aligned within the [width](./struct.FormattingFlags.html#width)
+/// it's padded to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Alignment {
+    /// Aligns the value to the left (eg: `formatc!("{:<8}", FOO)`).
+    Left,
+    /// Centers the value (eg: `formatc!("{:^8}", FOO)`),
+    /// putting the extra fill character after it when the padding is uneven.
+    Center,
+    /// Aligns the value to the right (eg: `formatc!("{:>8}", FOO)`).
+    Right,
+}
+
 impl NumberFormatting {
     #[cfg(test)]
     #[cfg(feature = "fmt")]
@@ -94,7 +107,55 @@ impl NumberFormatting {
 /// - The binary formater (eg: `formatc!("{:#b}", FOO)`):
 /// prefixes numbers with `0b`.`
 ///
+/// # Debug limits
+///
+/// Limits on how much of a data structure the [`Formatter`]'s
+/// debug builders write, set with the `set_max_depth` and `set_max_entries` methods:
+///
+/// - max depth (eg: `formatc!("{:?(depth = 2)}", FOO)`):
+/// structs/tuples/lists/sets/maps nested deeper than this are written as `..`.
+///
+/// - max entries (eg: `formatc!("{:?(entries = 8)}", FOO)`):
+/// lists/sets/maps write this many entries, followed by `...` if there were more.
+///
+/// # Line width
+///
+/// How many bytes long a line can be for the alternate Debug formatter
+/// (eg: `formatc!("{:#?(line_width = 80)}", FOO)`)
+/// to write a struct/tuple/list/set/map in a single line, instead of one line per field.
+/// It can be set with the `set_line_width` method.
+///
+/// The default line width is 0, which writes every data structure with fields over
+/// multiple lines.
+///
+/// # Indentation
+///
+/// The string that the alternate Debug formatter indents fields with, once per nesting level.
+/// It can be set with the `set_indent` method,
+/// or in format strings (eg: `formatc!("{:#?(indent = 2)}", FOO)` for 2 spaces,
+/// `formatc!("{:#?(indent = tab)}", FOO)` for a tab).
+///
+/// The default indentation is 4 spaces.
+///
+/// # Width
+///
+/// The minimum amount of characters that a value is padded to
+/// (eg: `formatc!("{:8}", FOO)`),
+/// by writing the fill character (a space by default, eg: `formatc!("{:*<8}", FOO)`)
+/// before and/or after the value, depending on the [`Alignment`].
+/// They can be set with the `set_width`, `set_fill`, and `set_alignment` methods.
+///
+/// Values are only padded by the [`Formatter::pad`] and [`Formatter::pad_integral`] methods,
+/// and by the `const_display_fmt`/`const_debug_fmt` methods which use them,
+/// like the ones for integers, `&str`, `bool`, and `char`.
+/// The default alignment is to the left for strings, and to the right for numbers.
+///
+/// The default width is 0, which doesn't pad values.
+///
 /// [`Formatter`]: ./struct.Formatter.html
+/// [`Alignment`]: ./enum.Alignment.html
+/// [`Formatter::pad`]: ./struct.Formatter.html#method.pad
+/// [`Formatter::pad_integral`]: ./struct.Formatter.html#method.pad_integral
 ///
 #[must_use]
 #[derive(Debug, Copy, Clone)]
@@ -106,6 +167,13 @@ pub struct FormattingFlags {
     // move this in 0.3.0 to `NumberFormatting`.
     hex_fmt: HexFormatting,
     is_alternate: bool,
+    max_depth: usize,
+    max_entries: usize,
+    line_width: usize,
+    indent: &'static str,
+    width: usize,
+    fill: char,
+    alignment: Option<Alignment>,
 }
 
 #[doc(hidden)]
@@ -126,6 +194,13 @@ impl FormattingFlags {
         num_fmt: NumberFormatting::Decimal,
         hex_fmt: HexFormatting::Upper,
         is_alternate: false,
+        max_depth: usize::MAX,
+        max_entries: usize::MAX,
+        line_width: 0,
+        indent: "    ",
+        width: 0,
+        fill: ' ',
+        alignment: None,
     };
 
     /// Constructs a `FormattingFlags` with these values:
@@ -134,10 +209,31 @@ impl FormattingFlags {
     ///
     /// - is alternate: false
     ///
+    /// - max depth: unlimited
+    ///
+    /// - max entries: unlimited
+    ///
+    /// - line width: 0
+    ///
+    /// - indentation: 4 spaces
+    ///
+    /// - width: 0
+    ///
+    /// - fill: `' '`
+    ///
+    /// - alignment: `None`
+    ///
     pub const NEW: Self = Self {
         num_fmt: NumberFormatting::Decimal,
         hex_fmt: HexFormatting::Upper,
         is_alternate: false,
+        max_depth: usize::MAX,
+        max_entries: usize::MAX,
+        line_width: 0,
+        indent: "    ",
+        width: 0,
+        fill: ' ',
+        alignment: None,
     };
 
     /// Constructs a `FormattingFlags` with these values:
@@ -146,6 +242,20 @@ impl FormattingFlags {
     ///
     /// - is alternate: false
     ///
+    /// - max depth: unlimited
+    ///
+    /// - max entries: unlimited
+    ///
+    /// - line width: 0
+    ///
+    /// - indentation: 4 spaces
+    ///
+    /// - width: 0
+    ///
+    /// - fill: `' '`
+    ///
+    /// - alignment: `None`
+    ///
     #[inline]
     pub const fn new() -> Self {
         Self::NEW
@@ -206,6 +316,108 @@ impl FormattingFlags {
         self
     }
 
+    /// Sets how many levels of nested structs/tuples/lists/sets/maps are written
+    /// before the more deeply nested ones are replaced with `..`.
+    ///
+    /// `usize::MAX` means that there is no limit.
+    #[inline]
+    pub const fn set_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets how many entries of a list/set/map are written
+    /// before the rest are replaced with `...`.
+    ///
+    /// `usize::MAX` means that there is no limit.
+    #[inline]
+    pub const fn set_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Sets how many bytes long a line can be for the alternate Debug formatter
+    /// to write a data structure in a single line.
+    ///
+    /// A data structure is written in a single line if it fits
+    /// (including the text before it in the same line, and a trailing comma if it's a field),
+    /// and none of its fields were written over multiple lines.
+    ///
+    /// `0` means that data structures with fields are always written over multiple lines.
+    #[inline]
+    pub const fn set_line_width(mut self, line_width: usize) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    /// Sets the string that the alternate Debug formatter indents fields with,
+    /// once for each level of nesting.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "fmt", doc = "```rust")]
+    #[cfg_attr(not(feature = "fmt"), doc = "```ignore")]
+    /// use const_format::{FormattingFlags, StrWriter};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    /// let flags = FormattingFlags::NEW.set_alternate(true).set_indent("\t");
+    ///
+    /// let mut fmt = writer.make_formatter(flags);
+    /// {
+    ///     let mut list = fmt.debug_list();
+    ///     list.entry().write_u8_debug(3).unwrap();
+    ///     list.entry().write_u8_debug(5).unwrap();
+    ///     list.finish().unwrap();
+    /// }
+    ///
+    /// assert_eq!(writer.as_str(), "[\n\t3,\n\t5,\n]");
+    /// ```
+    #[inline]
+    pub const fn set_indent(mut self, indent: &'static str) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the minimum amount of characters that a value is
+    /// [padded](#width) to.
+    ///
+    /// # Example
+    ///
+    #[cfg_attr(feature = "fmt", doc = "```rust")]
+    #[cfg_attr(not(feature = "fmt"), doc = "```ignore")]
+    /// use const_format::fmt::{Alignment, FormattingFlags, StrWriter};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+    /// let flags = FormattingFlags::NEW.set_width(5);
+    ///
+    /// writer.make_formatter(flags).pad("ab").unwrap();
+    /// writer.make_formatter(flags.set_fill('-')).write_u8_display(3).unwrap();
+    ///
+    /// let flags = flags.set_fill('*').set_alignment(Alignment::Center);
+    /// writer.make_formatter(flags).pad("ab").unwrap();
+    ///
+    /// assert_eq!(writer.as_str(), "ab   3*ab**");
+    /// ```
+    #[inline]
+    pub const fn set_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the character that values are [padded](#width) with.
+    #[inline]
+    pub const fn set_fill(mut self, fill: char) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Sets how values are aligned within the [width](#width) they're padded to.
+    #[inline]
+    pub const fn set_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
     /// Gets the current `NumberFormatting`.
     #[inline]
     pub const fn num_fmt(self) -> NumberFormatting {
@@ -218,6 +430,50 @@ impl FormattingFlags {
         self.is_alternate
     }
 
+    /// Gets the maximum nesting depth of debug formatted data structures.
+    #[inline]
+    pub const fn max_depth(self) -> usize {
+        self.max_depth
+    }
+
+    /// Gets the maximum amount of entries written for debug formatted lists/sets/maps.
+    #[inline]
+    pub const fn max_entries(self) -> usize {
+        self.max_entries
+    }
+
+    /// Gets how many bytes long a line can be for the alternate Debug formatter
+    /// to write a data structure in a single line.
+    #[inline]
+    pub const fn line_width(self) -> usize {
+        self.line_width
+    }
+
+    /// Gets the string that the alternate Debug formatter indents fields with.
+    #[inline]
+    pub const fn indent(self) -> &'static str {
+        self.indent
+    }
+
+    /// Gets the minimum amount of characters that a value is [padded](#width) to.
+    #[inline]
+    pub const fn width(self) -> usize {
+        self.width
+    }
+
+    /// Gets the character that values are [padded](#width) with.
+    #[inline]
+    pub const fn fill(self) -> char {
+        self.fill
+    }
+
+    /// Gets the alignment of values within the [width](#width) they're padded to,
+    /// `None` if it's the default alignment for the type.
+    #[inline]
+    pub const fn alignment(self) -> Option<Alignment> {
+        self.alignment
+    }
+
     pub(crate) const fn hex_fmt(self) -> HexFormatting {
         self.hex_fmt
     }
@@ -243,21 +499,6 @@ pub struct StartAndArray<T: ?Sized> {
 
 ////////////////////////////////////////////////////////////////////////////////
 
-#[doc(hidden)]
-pub struct ForEscaping {
-    pub is_escaped: u128,
-    pub is_backslash_escaped: u128,
-    pub escape_char: [u8; 16],
-}
-
-impl ForEscaping {
-    /// Gets the backslash escape for a character that is kwown to be escaped with a backslash.
-    #[inline(always)]
-    pub const fn get_backslash_escape(b: u8) -> u8 {
-        FOR_ESCAPING.escape_char[(b & 0b1111) as usize]
-    }
-}
-
 #[doc(hidden)]
 /// Converts 0..=0xF to its ascii representation of '0'..='9' and 'A'..='F'
 #[inline(always)]
@@ -268,42 +509,3 @@ pub const fn hex_as_ascii(n: u8, hex_fmt: HexFormatting) -> u8 {
         n + (hex_fmt as u8)
     }
 }
-
-#[doc(hidden)]
-// Really clippy? Array indexing can panic you know.
-#[allow(clippy::no_effect)]
-pub const FOR_ESCAPING: &ForEscaping = {
-    let mut is_backslash_escaped = 0;
-
-    let escaped = [
-        (b'\t', b't'),
-        (b'\n', b'n'),
-        (b'\r', b'r'),
-        (b'\'', b'\''),
-        (b'"', b'"'),
-        (b'\\', b'\\'),
-    ];
-
-    // Using the fact that the characters above all have different bit patterns for
-    // the lowest 4 bits.
-    let mut escape_char = [0u8; 16];
-
-    __for_range! {i in 0..escaped.len() =>
-        let (code, escape) = escaped[i];
-        is_backslash_escaped |= 1 << code;
-
-        let ei = (code & 0b1111) as usize;
-        let prev_escape = escape_char[ei] as usize;
-        ["Oh no, some escaped character uses the same 4 lower bits as another"][prev_escape];
-        escape_char[ei] = escape;
-    }
-
-    // Setting all the control characters as being escaped.
-    let is_escaped = is_backslash_escaped | 0xFFFF_FFFF;
-
-    &ForEscaping {
-        escape_char,
-        is_backslash_escaped,
-        is_escaped,
-    }
-};
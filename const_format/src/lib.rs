@@ -44,6 +44,20 @@
 //! Replaces all the instances of a pattern in a `&'static str` constant with
 //! another `&'static str` constant.
 //!
+//! - [`str_unescape`]:
+//! Decodes the escape sequences (eg: `\n`, `\u{..}`) in a `&'static str` constant.
+//!
+//! - [`str_from_utf8`]/[`str_from_utf8_lossy`]:
+//! Converts a `&'static [u8]` constant to a `&'static str`,
+//! either erroring on invalid utf8, or replacing it with `U+FFFD`.
+//!
+//! - [`hex_encode`]/[`hex_decode`]:
+//! Encodes a `&'static str`/`&'static [u8]` constant as hexadecimal, and decodes it back.
+//!
+//! - [`base64_encode`]/[`base64_decode`]:
+//! Encodes a `&'static str`/`&'static [u8]` constant as base64, and decodes it back,
+//! configured with a [`Base64Config`].
+//!
 //! ### Rust 1.57.0
 //!
 //! The "assertcp" feature enables the [`assertcp`], [`assertcp_eq`],
@@ -59,12 +73,13 @@
 //!
 //! -  [`str_split`]: splits a string constant
 //!
-//! ### Rust nightly
+//! ### Rust 1.83.0
 //!
 //! By enabling the "fmt" feature, you can use a [`std::fmt`]-like API.
 //!
-//! This requires the nightly compiler because it uses mutable references in const fn,
-//! which have not been stabilized as of writing these docs.
+//! This requires mutable references in const fn, which were stabilized in Rust 1.83.0.
+//! To use it on stable Rust, enable the "rust_1_83" feature (which implies the "fmt" feature),
+//! otherwise the "fmt" feature requires the nightly compiler.
 //!
 //! All the other features of this crate are implemented on top of the [`const_format::fmt`] API:
 //!
@@ -79,14 +94,22 @@
 //! [`write`]-like macro that can format many standard library and user defined types
 //! into a type that implements [`WriteMarker`].
 //!
-//! The "derive" feature enables the [`ConstDebug`] macro,
+//! - [`concatcp_generic`] and [`formatcp_generic`]:
+//! [`concatcp`] and [`formatcp`]-like macros that can be used in generic contexts,
+//! like associated constants that use type parameters.
+//!
+//! The "derive" feature enables the [`ConstDebug`] and [`ConstDisplay`] macros,
 //! and the "fmt" feature.<br>
 //! [`ConstDebug`] derives the [`FormatMarker`] trait,
-//! and implements an inherent `const_debug_fmt` method for compile-time debug formatting.
+//! and implements an inherent `const_debug_fmt` method for compile-time debug formatting.<br>
+//! [`ConstDisplay`] implements an inherent `const_display_fmt` method
+//! from a format string, for compile-time display formatting.
 //!
 //! The "assertc" feature enables the [`assertc`], [`assertc_eq`], [`assertc_ne`] macros,
 //! and the "fmt" feature.<br>
-//! These macros are like the standard library assert macros, but evaluated at compile-time.
+//! These macros are like the standard library assert macros, but evaluated at compile-time.<br>
+//! With the "derive" feature, it also enables the [`ConstEq`] macro,
+//! which derives the `const_eq` method that these macros use to compare user-defined types.
 //! # Examples
 //!
 //! ### Concatenation of primitive types
@@ -124,12 +147,10 @@
 //! This example demonstrates how you can use the [`ConstDebug`] derive macro,
 //! and then format the type into a `&'static str` constant.
 //!
-//! This example requires Rust nightly, and the "derive" feature.
+//! This example requires Rust 1.83.0, and the "derive" and "rust_1_83" features.
 //!
 #![cfg_attr(feature = "derive", doc = "```rust")]
 #![cfg_attr(not(feature = "derive"), doc = "```ignore")]
-//! #![feature(const_mut_refs)]
-//!
 //! use const_format::{ConstDebug, formatc};
 //!
 //! #[derive(ConstDebug)]
@@ -255,15 +276,18 @@
 //! # Cargo features
 //!
 //! - "fmt": Enables the [`std::fmt`]-like API,
-//! requires Rust nightly because it uses mutable references in const fn.<br>
+//! requires Rust nightly because it uses mutable references in const fn,
+//! unless the "rust_1_83" feature is also enabled.<br>
 //! This feature includes the [`formatc`]/[`writec`] formatting macros.
 //!
 //! - "derive": implies the "fmt" feature,
-//! provides the [`ConstDebug`] derive macro to format user-defined types at compile-time.<br>
+//! provides the [`ConstDebug`] and [`ConstDisplay`] derive macros
+//! to format user-defined types at compile-time.<br>
 //! This implicitly uses the `syn` crate, so clean compiles take a bit longer than without the feature.
 //!
 //! - "assertc": implies the "fmt" feature,
-//! enables the [`assertc`], [`assertc_eq`], and [`assertc_ne`] assertion macros.<br>
+//! enables the [`assertc`], [`assertc_eq`], and [`assertc_ne`] assertion macros,
+//! and the [`ConstEq`] derive macro if the "derive" feature is also enabled.<br>
 //! This feature was previously named "assert",
 //! but it was renamed to avoid confusion with the "assertcp" feature.
 //!
@@ -279,6 +303,19 @@
 //! Allows the `as_bytes_alt` methods and `slice_up_to_len_alt` methods to run
 //! in constant time, rather than linear time proportional to the truncated part of the slice.
 //!
+//! - "alloc": implies the "fmt" feature,
+//! enables the heap-allocated [`StringWriter`],
+//! and the [`to_string`]/[`to_debug_string`] macros,
+//! for using const formatting code at runtime without picking a buffer size.
+//!
+//! - "nightly_const_traits": Requires Rust nightly, implies the "fmt" feature.
+//! Enables the [`ConstDisplay` trait] and [`ConstDebug` trait] const traits,
+//! for formatting generic types with `T: [const] ConstDebug` bounds.
+//!
+//! - "rust_1_83": Requires Rust 1.83.0, implies the "fmt" feature.
+//! Allows the "fmt", "derive", and "assertc" features to be used on stable Rust,
+//! by not enabling the `const_mut_refs` nightly feature.
+//!
 //! # No-std support
 //!
 //! `const_format` is unconditionally `#![no_std]`, it can be used anywhere Rust can be used.
@@ -319,14 +356,32 @@
 //!
 //! [`writec`]: ./macro.writec.html
 //!
+//! [`concatcp_generic`]: ./macro.concatcp_generic.html
+//!
+//! [`formatcp_generic`]: ./macro.formatcp_generic.html
+//!
 //! [`write`]: https://doc.rust-lang.org/std/macro.write.html
 //!
 //! [`Formatter`]: ./fmt/struct.Formatter.html
 //!
 //! [`StrWriter`]: ./fmt/struct.StrWriter.html
 //!
+//! [`StringWriter`]: ./fmt/struct.StringWriter.html
+//!
+//! [`ConstDisplay` trait]: ./fmt/trait.ConstDisplay.html
+//!
+//! [`ConstDebug` trait]: ./fmt/trait.ConstDebug.html
+//!
+//! [`to_string`]: ./macro.to_string.html
+//!
+//! [`to_debug_string`]: ./macro.to_debug_string.html
+//!
 //! [`ConstDebug`]: ./derive.ConstDebug.html
 //!
+//! [`ConstDisplay`]: ./derive.ConstDisplay.html
+//!
+//! [`ConstEq`]: ./derive.ConstEq.html
+//!
 //! [`FormatMarker`]: ./marker_traits/trait.FormatMarker.html
 //!
 //! [`WriteMarker`]: ./marker_traits/trait.WriteMarker.html
@@ -346,12 +401,27 @@
 //!
 //! [`str_replace`]: ./macro.str_replace.html
 //!
+//! [`str_unescape`]: ./macro.str_unescape.html
+//! [`str_from_utf8`]: ./macro.str_from_utf8.html
+//! [`str_from_utf8_lossy`]: ./macro.str_from_utf8_lossy.html
+//!
+//! [`hex_encode`]: ./macro.hex_encode.html
+//! [`hex_decode`]: ./macro.hex_decode.html
+//! [`base64_encode`]: ./macro.base64_encode.html
+//! [`base64_decode`]: ./macro.base64_decode.html
+//! [`Base64Config`]: ./struct.Base64Config.html
+//!
 //! [`str_split`]: ./macro.str_split.html
 //!
 //! [`str::replace`]: https://doc.rust-lang.org/std/primitive.str.html#method.replace
 //!
 #![no_std]
-#![cfg_attr(feature = "fmt", feature(const_mut_refs))]
+#![cfg_attr(
+    all(feature = "fmt", not(feature = "rust_1_83")),
+    feature(const_mut_refs)
+)]
+#![cfg_attr(feature = "nightly_const_traits", feature(const_trait_impl))]
+#![cfg_attr(feature = "nightly_const_traits", feature(allow_internal_unstable))]
 #![cfg_attr(feature = "__docsrs", feature(doc_cfg))]
 #![deny(rust_2018_idioms)]
 // This lint is silly
@@ -366,8 +436,15 @@
 #![allow(clippy::init_numbered_fields)]
 #![deny(missing_docs)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 include! {"const_debug_derive.rs"}
 
+include! {"const_display_derive.rs"}
+
+include! {"const_eq_derive.rs"}
+
 #[macro_use]
 mod macros;
 
@@ -433,15 +510,25 @@ pub mod __ascii_case_conv;
 #[doc(hidden)]
 pub mod __str_methods;
 
+#[doc(hidden)]
+#[cfg(feature = "rust_1_51")]
+pub mod __binary_encoding;
+
 pub use __str_methods::SplicedStr;
 
 #[cfg_attr(feature = "__docsrs", doc(cfg(feature = "rust_1_51")))]
 #[cfg(feature = "rust_1_51")]
 pub use __ascii_case_conv::Case;
 
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "rust_1_51")))]
+#[cfg(feature = "rust_1_51")]
+pub use __binary_encoding::Base64Config;
+
 #[cfg(feature = "fmt")]
 #[doc(no_inline)]
-pub use crate::fmt::{Error, Formatter, FormattingFlags, Result, StrWriter, StrWriterMut};
+pub use crate::fmt::{
+    ConstString, Error, Formatter, FormattingFlags, Result, StrWriter, StrWriterMut,
+};
 
 #[cfg(feature = "fmt")]
 pub use crate::wrapper_types::ascii_str::AsciiStr;
@@ -449,6 +536,9 @@ pub use crate::wrapper_types::ascii_str::AsciiStr;
 #[cfg(feature = "fmt")]
 pub use crate::wrapper_types::sliced::Sliced;
 
+#[cfg(feature = "fmt")]
+pub use crate::wrapper_types::byte_str::{ByteStr, HexDump};
+
 #[cfg_attr(not(feature = "fmt"), doc(hidden))]
 pub use crate::wrapper_types::pwrapper::PWrapper;
 
@@ -465,7 +555,9 @@ pub mod pmr {
     pub use const_format_proc_macros::{__concatcp_impl, __formatcp_impl, respan_to};
 
     #[cfg(feature = "fmt")]
-    pub use const_format_proc_macros::{__formatc_if_impl, __formatc_impl, __writec_impl};
+    pub use const_format_proc_macros::{
+        __formatc_if_impl, __formatc_impl, __formatcp_generic_impl, __writec_impl,
+    };
 
     #[cfg(feature = "assertcp")]
     pub use const_format_proc_macros::__formatcp_if_impl;
@@ -488,16 +580,25 @@ pub mod pmr {
 
     #[cfg(feature = "fmt")]
     pub use crate::{
-        fmt::{ComputeStrLength, Error, Formatter, StrWriter, StrWriterMut, ToResult},
+        fmt::{
+            ComputeStrLength, ConstFmtAdapter, Error, Formatter, StrWriter, StrWriterMut, ToResult,
+        },
         marker_traits::{
             FormatMarker, IsAFormatMarker, IsAWriteMarker, IsNotStdKind, IsStdKind, WriteMarker,
         },
     };
 
+    #[cfg(feature = "alloc")]
+    pub use crate::fmt::string_writer::__to_string;
+
+    #[cfg(feature = "fmt")]
+    pub use crate::fmt::const_string::{__capacity_error, __concatcp_generic};
+
     pub use crate::{
+        char_encoding::str_char_to_debug,
         formatting::{
-            hex_as_ascii, ForEscaping, Formatting, FormattingFlags, HexFormatting, LenAndArray,
-            NumberFormatting, StartAndArray, FOR_ESCAPING,
+            hex_as_ascii, Alignment, Formatting, FormattingFlags, HexFormatting, LenAndArray,
+            NumberFormatting, StartAndArray,
         },
         pargument::{PArgument, PConvWrapper, PVariant},
         wrapper_types::PWrapper,
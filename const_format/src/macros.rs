@@ -1,6 +1,10 @@
 #[macro_use]
 mod assertions;
 
+#[macro_use]
+#[cfg(feature = "rust_1_51")]
+mod binary_encoding;
+
 #[macro_use]
 #[cfg(feature = "fmt")]
 mod call_debug_fmt;
@@ -30,8 +34,6 @@ mod str_methods;
 /// # Example
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Error, StrWriter};
 /// use const_format::{try_, writec};
 ///
@@ -65,8 +67,6 @@ macro_rules! try_ {
 /// # Example
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{StrWriter, unwrap, writec};
 ///
 /// const CAP: usize = 11;
@@ -93,6 +93,73 @@ macro_rules! unwrap {
     };
 }
 
+/// Equivalent to [`unwrap`], for writing into a writer (or a [`Formatter`]),
+/// which reports how long the buffer needed to be when the written text doesn't fit.
+///
+/// This takes the writer, and a closure-like expression that writes into the
+/// `Formatter` constructed from the writer, which is passed by name.
+///
+/// When the text doesn't fit, this panics with the details of the
+/// [`NotEnoughSpaceError`] that the `Formatter` recorded, eg:
+/// `needed 13 bytes, capacity 11, when writing at byte 4`.
+///
+/// [`Formatter`]: ./fmt/struct.Formatter.html
+/// [`NotEnoughSpaceError`]: ./fmt/struct.NotEnoughSpaceError.html
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{StrWriter, unwrap_fmt, writec};
+///
+/// const CAP: usize = 11;
+/// const TEXT: &str = {
+///     const S: &StrWriter = &{
+///         let mut writer = StrWriter::new([0; CAP]);
+///         unwrap_fmt!(writer, |f| writec!(f, "foo bar baz"));
+///         writer
+///     };
+///     S.as_str_alt()
+/// };
+/// assert_eq!(TEXT, "foo bar baz")
+///
+/// ```
+///
+/// Writing more text than fits in the buffer:
+///
+/// ```compile_fail
+/// use const_format::{StrWriter, unwrap_fmt, writec};
+///
+/// const S: &StrWriter = &{
+///     let mut writer = StrWriter::new([0; 11]);
+///     unwrap_fmt!(writer, |f| writec!(f, "foo bar baz qux"));
+///     writer
+/// };
+/// ```
+///
+/// The above example fails to compile with an error that includes this message:
+///
+/// ```text
+/// There was not enough space to write the formatted output, needed 15 bytes, capacity 11, when writing at byte 0
+/// ```
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "fmt")))]
+#[cfg(feature = "fmt")]
+#[macro_export]
+macro_rules! unwrap_fmt {
+    ($writer:expr, |$fmt:ident| $e:expr $(,)*) => {{
+        let writer = $writer.borrow_mutably();
+        let mut marker = $crate::pmr::IsAWriteMarker::NEW;
+        if false {
+            marker = marker.infer_type(&writer);
+        }
+        let mut writer = marker.coerce(writer);
+        let mut $fmt = writer.make_formatter($crate::fmt::FormattingFlags::NEW);
+        match $e {
+            $crate::pmr::Ok(x) => x,
+            $crate::pmr::Err(error) => $crate::Error::unwrap_space(&error, $fmt.not_enough_space()),
+        }
+    }};
+}
+
 /// Equivalent to `Result::unwrap_or_else` but allows returning from the enclosing function.
 ///
 /// # Examples
@@ -100,8 +167,6 @@ macro_rules! unwrap {
 /// ### Early return
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::unwrap_or_else;
 ///
 /// const fn unwrap_square(number: Result<u32, u32>) -> u64 {
@@ -118,8 +183,6 @@ macro_rules! unwrap {
 /// ### As unwrap_or
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{AsciiStr, unwrap_or_else};
 ///
 /// const FOO: AsciiStr = unwrap_or_else!(AsciiStr::new(b"AB\x80"), |_| AsciiStr::empty() );
@@ -156,8 +219,6 @@ macro_rules! unwrap_or_else {
 /// # Example
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{
 ///     for_examples::Unit,
 ///     Formatter, FormattingFlags, PWrapper, StrWriter,
@@ -241,10 +302,8 @@ macro_rules! coerce_to_fmt {
 /// # Example
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::StrWriter;
-/// use const_format::{strwriter_as_str, unwrap, writec};
+/// use const_format::{strwriter_as_str, unwrap_fmt, writec};
 ///
 ///
 /// const CAP: usize = 128;
@@ -253,7 +312,7 @@ macro_rules! coerce_to_fmt {
 ///     let mut writer =  StrWriter::new([0; CAP]);
 ///
 ///     // Writing the array with debug formatting, and the integers with hexadecimal formatting.
-///     unwrap!(writec!(writer, "{:x}", [3u32, 5, 8, 13, 21, 34]));
+///     unwrap_fmt!(writer, |f| writec!(f, "{:x}", [3u32, 5, 8, 13, 21, 34]));
 ///
 ///     writer
 /// };
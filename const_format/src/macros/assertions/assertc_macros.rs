@@ -56,8 +56,6 @@ with_shared_docs! {
     /// ### Passing assertion
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::assertc;
     ///
     /// use std::mem::size_of;
@@ -78,8 +76,6 @@ with_shared_docs! {
     /// and how the compiler error looks like as of 2021-09-18.
     ///
     /// ```compile_fail
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::assertc;
     ///
     /// const L: u64 = 2;
@@ -139,6 +135,7 @@ macro_rules! assert_eq_docs {
             ///
             /// - non-standard-library types that implement [`FormatMarker`] with debug formatting<br>
             /// and have a `const fn const_eq(&self, other:&Self) -> bool` inherent method,
+            /// which can be derived with the [`ConstEq`](./derive.ConstEq.html) derive macro.
             ///
             ;syntax
             ;error_message
@@ -158,8 +155,6 @@ assert_eq_docs! {
     /// ### Passing assertion
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::assertc_eq;
     ///
     /// use std::mem::size_of;
@@ -178,8 +173,6 @@ assert_eq_docs! {
     /// and how the compiler error looks like as of 2021-09-18.
     ///
     /// ```compile_fail
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::assertc_eq;
     ///
     /// use std::mem::size_of;
@@ -210,8 +203,6 @@ assert_eq_docs! {
     ///
     #[cfg_attr(feature = "derive", doc = "```compile_fail")]
     #[cfg_attr(not(feature = "derive"), doc = "```ignore")]
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::{Formatter, PWrapper};
     /// use const_format::{ConstDebug, assertc_eq, try_};
     ///
@@ -284,8 +275,6 @@ assert_eq_docs! {
     /// ### Passing assertion
     ///
     /// ```rust
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::assertc_ne;
     ///
     /// use std::mem::size_of;
@@ -305,8 +294,6 @@ assert_eq_docs! {
     /// and how the compiler error looks like as of 2021-09-18.
     ///
     /// ```compile_fail
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::assertc_ne;
     ///
     /// use std::mem::size_of;
@@ -339,8 +326,6 @@ assert_eq_docs! {
     ///
     #[cfg_attr(feature = "derive", doc = "```compile_fail")]
     #[cfg_attr(not(feature = "derive"), doc = "```ignore")]
-    /// #![feature(const_mut_refs)]
-    ///
     /// use const_format::{Formatter, PWrapper};
     /// use const_format::{ConstDebug, assertc_ne, try_};
     ///
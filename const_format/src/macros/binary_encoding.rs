@@ -0,0 +1,314 @@
+/// Encodes a `&'static str` or `&'static [u8]` constant as hexadecimal.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # use const_format::Case;
+/// # trait Bytes {}
+/// fn hex_encode(input: impl Bytes, case: Case) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `input` can be any of these types:
+///
+/// - `&'static str`
+///
+/// - `&'static [u8]`
+///
+/// - `&'static [u8; N]`
+///
+/// The `case` argument is optional, defaulting to `Case::Lower`.
+/// Only [`Case::Lower`] and [`Case::Upper`] are allowed,
+/// passing any other [`Case`] causes a compile-time error.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{hex_encode, Case};
+///
+/// assert_eq!(hex_encode!("hello"), "68656c6c6f");
+///
+/// assert_eq!(hex_encode!(&[0u8, 15, 16, 255], Case::Upper), "000F10FF");
+///
+/// {
+///     const IN: &[u8] = b"\xCA\xFE";
+///     const OUT: &str = hex_encode!(IN);
+///     assert_eq!(OUT, "cafe");
+/// }
+/// ```
+///
+/// [`Case`]: ./enum.Case.html
+/// [`Case::Lower`]: ./enum.Case.html#variant.Lower
+/// [`Case::Upper`]: ./enum.Case.html#variant.Upper
+#[cfg_attr(
+    feature = "__test",
+    doc = r#"
+```compile_fail
+const_format::hex_encode!("hello", const_format::Case::Snake);
+```
+"#
+)]
+#[macro_export]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "rust_1_51")))]
+macro_rules! hex_encode {
+    ($input:expr $(,)*) => {
+        $crate::hex_encode!($input, $crate::Case::Lower)
+    };
+    ($input:expr, $case:expr $(,)*) => {{
+        const ARGS_OSRCTFL4A: $crate::__binary_encoding::HexEncodeArgs =
+            $crate::__binary_encoding::HexEncodeArgs::new(
+                $crate::__binary_encoding::BytesConv($input).conv(),
+                $case,
+            );
+
+        {
+            $crate::pmr::respan_to! {
+                ($case)
+                const _ASSERT_VALID_CASE: () = ARGS_OSRCTFL4A.assert_valid();
+            }
+
+            const OB: &[$crate::pmr::u8; ARGS_OSRCTFL4A.out_len] = &ARGS_OSRCTFL4A.encode();
+
+            const OS: &$crate::pmr::str = unsafe { $crate::__priv_transmute_bytes_to_str!(OB) };
+
+            OS
+        }
+    }};
+}
+
+/// Decodes a hexadecimal `&'static str` or `&'static [u8]` constant into bytes.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # const LEN: usize = 0;
+/// # trait Bytes {}
+/// fn hex_decode(input: impl Bytes) -> &'static [u8; LEN]
+/// # { &[] }
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `input` can be any of these types:
+///
+/// - `&'static str`
+///
+/// - `&'static [u8]`
+///
+/// - `&'static [u8; N]`
+///
+/// Both uppercase and lowercase hexadecimal digits are accepted.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::hex_decode;
+///
+/// assert_eq!(hex_decode!("68656c6c6f"), b"hello");
+///
+/// {
+///     const IN: &str = "000F10fF";
+///     const OUT: &[u8; 4] = hex_decode!(IN);
+///     assert_eq!(OUT, &[0, 15, 16, 255]);
+/// }
+/// ```
+///
+/// ### Invalid input
+///
+/// Non-hexadecimal digits, or an odd amount of digits, cause compilation errors.
+/// The index in the error message is the byte offset of the invalid digit,
+/// or the length of the input if it has an odd amount of digits.
+///
+/// ```compile_fail
+/// const_format::hex_decode!("0x12");
+/// ```
+///
+/// ```compile_fail
+/// const_format::hex_decode!("123");
+/// ```
+#[macro_export]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "rust_1_51")))]
+macro_rules! hex_decode {
+    ($input:expr $(,)*) => {{
+        const ARGS_OSRCTFL4A: $crate::__binary_encoding::HexDecodeArgs =
+            $crate::__binary_encoding::HexDecodeArgs::new(
+                $crate::__binary_encoding::BytesConv($input).conv(),
+            );
+
+        {
+            $crate::pmr::respan_to! {
+                ($input)
+                const _ASSERT_VALID_HEX: () = ARGS_OSRCTFL4A.validity.assert_valid();
+            }
+
+            const OB: &[$crate::pmr::u8; ARGS_OSRCTFL4A.out_len] = &ARGS_OSRCTFL4A.decode();
+
+            OB
+        }
+    }};
+}
+
+/// Encodes a `&'static str` or `&'static [u8]` constant as base64.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # use const_format::Base64Config;
+/// # trait Bytes {}
+/// fn base64_encode(input: impl Bytes, config: Base64Config) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `input` can be any of these types:
+///
+/// - `&'static str`
+///
+/// - `&'static [u8]`
+///
+/// - `&'static [u8; N]`
+///
+/// The [`Base64Config`] argument is optional,
+/// defaulting to [`Base64Config::STANDARD`].
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{base64_encode, Base64Config};
+///
+/// assert_eq!(base64_encode!("hello"), "aGVsbG8=");
+/// assert_eq!(base64_encode!("hello", Base64Config::STANDARD_NO_PAD), "aGVsbG8");
+///
+/// {
+///     const IN: &[u8] = &[0xFB, 0xFF];
+///     assert_eq!(base64_encode!(IN), "+/8=");
+///     assert_eq!(base64_encode!(IN, Base64Config::URL_SAFE), "-_8=");
+///     assert_eq!(base64_encode!(IN, Base64Config::URL_SAFE_NO_PAD), "-_8");
+/// }
+/// ```
+///
+/// [`Base64Config`]: ./struct.Base64Config.html
+/// [`Base64Config::STANDARD`]: ./struct.Base64Config.html#associatedconstant.STANDARD
+#[macro_export]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "rust_1_51")))]
+macro_rules! base64_encode {
+    ($input:expr $(,)*) => {
+        $crate::base64_encode!($input, $crate::Base64Config::STANDARD)
+    };
+    ($input:expr, $config:expr $(,)*) => {{
+        const ARGS_OSRCTFL4A: $crate::__binary_encoding::Base64EncodeArgs =
+            $crate::__binary_encoding::Base64EncodeArgs::new(
+                $crate::__binary_encoding::BytesConv($input).conv(),
+                $config,
+            );
+
+        {
+            const OB: &[$crate::pmr::u8; ARGS_OSRCTFL4A.out_len] = &ARGS_OSRCTFL4A.encode();
+
+            const OS: &$crate::pmr::str = unsafe { $crate::__priv_transmute_bytes_to_str!(OB) };
+
+            OS
+        }
+    }};
+}
+
+/// Decodes a base64 `&'static str` or `&'static [u8]` constant into bytes.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # use const_format::Base64Config;
+/// # const LEN: usize = 0;
+/// # trait Bytes {}
+/// fn base64_decode(input: impl Bytes, config: Base64Config) -> &'static [u8; LEN]
+/// # { &[] }
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `input` can be any of these types:
+///
+/// - `&'static str`
+///
+/// - `&'static [u8]`
+///
+/// - `&'static [u8; N]`
+///
+/// The [`Base64Config`] argument is optional,
+/// defaulting to [`Base64Config::STANDARD`].
+///
+/// Decoding is strict:
+/// padding is required if the config has padding, and forbidden otherwise,
+/// and the unused bits of the last symbol must be zero.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{base64_decode, Base64Config};
+///
+/// assert_eq!(base64_decode!("aGVsbG8="), b"hello");
+/// assert_eq!(base64_decode!("aGVsbG8", Base64Config::STANDARD_NO_PAD), b"hello");
+/// assert_eq!(base64_decode!("-_8", Base64Config::URL_SAFE_NO_PAD), &[0xFB, 0xFF]);
+/// ```
+///
+/// ### Invalid input
+///
+/// Invalid input causes compilation errors,
+/// the index in the error message is the byte offset where the input became invalid.
+///
+/// ```compile_fail
+/// // `-` is not in the standard alphabet
+/// const_format::base64_decode!("-_8=");
+/// ```
+///
+/// ```compile_fail
+/// // padding is required by default
+/// const_format::base64_decode!("aGVsbG8");
+/// ```
+///
+/// [`Base64Config`]: ./struct.Base64Config.html
+/// [`Base64Config::STANDARD`]: ./struct.Base64Config.html#associatedconstant.STANDARD
+#[cfg_attr(
+    feature = "__test",
+    doc = r#"
+```compile_fail
+const_format::base64_decode!("aGVsbG8=", const_format::Base64Config::STANDARD_NO_PAD);
+```
+
+```compile_fail
+const_format::base64_decode!("aGVsbG9=");
+```
+
+```compile_fail
+const_format::base64_decode!("aGVsb");
+```
+"#
+)]
+#[macro_export]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "rust_1_51")))]
+macro_rules! base64_decode {
+    ($input:expr $(,)*) => {
+        $crate::base64_decode!($input, $crate::Base64Config::STANDARD)
+    };
+    ($input:expr, $config:expr $(,)*) => {{
+        const ARGS_OSRCTFL4A: $crate::__binary_encoding::Base64DecodeArgs =
+            $crate::__binary_encoding::Base64DecodeArgs::new(
+                $crate::__binary_encoding::BytesConv($input).conv(),
+                $config,
+            );
+
+        {
+            $crate::pmr::respan_to! {
+                ($input)
+                const _ASSERT_VALID_BASE64: () = ARGS_OSRCTFL4A.validity.assert_valid();
+            }
+
+            const OB: &[$crate::pmr::u8; ARGS_OSRCTFL4A.out_len] = &ARGS_OSRCTFL4A.decode();
+
+            OB
+        }
+    }};
+}
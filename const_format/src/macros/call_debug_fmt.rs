@@ -30,8 +30,6 @@
 /// Printing all of the kinds of types this supports.
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{
 ///     for_examples::{Point3, Unit},
 ///     Error, Formatter, FormattingFlags, StrWriter,
@@ -89,8 +87,6 @@
 ///
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{
 ///     for_examples::{Point3, Unit},
 ///     Error, Formatter, FormattingFlags, StrWriter,
@@ -157,7 +157,7 @@ macro_rules! __concatcp_inner {
 ///
 /// - Use Debug-like formatting (eg: `formatcp!("{:?}", "hello" )`:<br>
 /// Similar to how `Debug` formatting in the standard library works,
-/// except that it does not escape unicode characters.
+/// strings and chars are escaped exactly like the standard library does it.
 ///
 /// - Use LowerHex formatting (eg: `formatcp!("{:x}", "hello" )`):<br>
 /// Formats numbers as lowercase hexadecimal.
@@ -197,20 +197,26 @@ macro_rules! __concatcp_inner {
 ///
 /// For `&'static str` it does these things:
 /// - Prepend and append the double quote character (`"`).
-/// - Escape the `'\t'`,`'\n'`,`'\r'`,`'\\'`, `'\''`, and`'\"'` characters.
-/// - Escape control characters with `\xYY`,
-/// where `YY` is the hexadecimal value of the control character.
+/// - Escape the `'\0'`, `'\t'`,`'\n'`,`'\r'`,`'\\'`, and`'\"'` characters with a backslash.
+/// - Escape non-printable characters (eg: control characters),
+/// and characters with the `Grapheme_Extend` unicode property, with `\u{YY}`,
+/// where `YY` is the lowercase hexadecimal value of the character.
 ///
 /// Example:
 /// ```
 /// use const_format::formatcp;
 ///
 /// assert_eq!(formatcp!("{:?}", r#" \ " ó "#), r#"" \\ \" ó ""#);
+/// assert_eq!(formatcp!("{:?}", "\x00\x01\u{301}"), r#""\0\u{1}\u{301}""#);
 /// ```
 ///
 /// For `char` it does these things:
 /// - Prepend and append the single quote character (`'`).
-/// - Uses the same escapes as `&'static str`.
+/// - Uses the same escapes as `&'static str`,
+/// except that `'\''` is escaped with a backslash and `'"'` is not.
+///
+/// This produces the same output as `Debug` formatting in the standard library
+/// (up to the unicode version that this crate was updated to).
 ///
 /// ### Display
 ///
@@ -255,19 +261,23 @@ macro_rules! __concatcp_inner {
 ///     assert_eq!(MSG, r#"hello " \ world____"hello \" \\ world""#);
 /// }
 /// {
-///     const CHARS: &str = formatcp!("{0:?} - {0} - {1} - {1:?}", '"', '👀');
+///     const CHARS: &str = formatcp!("{0:?} - {0} - {1} - {1:?}", '\'', '👀');
 ///    
-///     assert_eq!(CHARS, r#"'\"' - " - 👀 - '👀'"#);
+///     assert_eq!(CHARS, r#"'\'' - ' - 👀 - '👀'"#);
 /// }
 /// ```
 ///
 /// ### Additional specifiers
 ///
-/// `const_format` macros don't support width, fill, alignment, sign,
+/// `formatcp` doesn't support width, fill, alignment, sign,
 /// or precision specifiers.
 ///
+/// Width, fill, and alignment are supported by the [`formatc`] macro.
+///
 /// [`format`]: https://doc.rust-lang.org/std/macro.format.html
 ///
+/// [`formatc`]: ./macro.formatc.html
+///
 /// [`format_args_implicits` RFC]:
 /// https://github.com/rust-lang/rfcs/blob/master/text/2795-format-args-implicit-identifiers.md
 ///
@@ -308,8 +318,6 @@ macro_rules! formatcp {
 /// ### With standard library types
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::concatc;
 ///
 /// assert_eq!(concatc!("There is ", 99u8, " monkeys!"), "There is 99 monkeys!");
@@ -319,8 +327,6 @@ macro_rules! formatcp {
 /// ### With user-defined types
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Formatter, Sliced, concatc, impl_fmt};
 ///
 /// const STRING: &str = "foo bar baz";
@@ -390,7 +396,7 @@ macro_rules! __concatc_inner {
                 let mut strlen = __cf_osRcTFl4A::pmr::ComputeStrLength::new();
                 let fmt = strlen.make_formatter(__cf_osRcTFl4A::FormattingFlags::NEW);
                 match $debug_fmt_fn(fmt) {
-                    __cf_osRcTFl4A::pmr::Ok(()) => strlen.len(),
+                    __cf_osRcTFl4A::pmr::Ok(()) => strlen.required_capacity(),
                     __cf_osRcTFl4A::pmr::Err(_) => 0,
                 }
             } else {
@@ -458,8 +464,6 @@ macro_rules! __concatc_inner {
 /// # Example
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::for_examples::Point3;
 /// use const_format::formatc;
 ///
@@ -490,8 +494,6 @@ macro_rules! __concatc_inner {
 /// [in the fmt module](./fmt/index.html#custom-formatting-section).
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::for_examples::Point3;
 /// use const_format::{formatc, try_};
 ///
@@ -571,8 +573,6 @@ macro_rules! formatc {
 /// ### Ẁriting a Display impl.
 ///
 /// ```
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Error, Formatter, StrWriter};
 /// use const_format::{impl_fmt, try_, writec};
 ///
@@ -604,8 +604,6 @@ macro_rules! formatc {
 /// in this case it's a buffer that is cleared every time it's written.
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::marker_traits::{IsNotAStrWriter, WriteMarker};
 /// use const_format::{Formatter, FormattingFlags};
 /// use const_format::writec;
@@ -659,8 +657,6 @@ macro_rules! formatc {
 /// [in the fmt module](./fmt/index.html#custom-formatting-section).
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::for_examples::Point3;
 /// use const_format::{StrWriter, call_debug_fmt, try_, writec};
 ///
@@ -686,8 +682,6 @@ macro_rules! formatc {
 /// by using their identifiers in the format string.
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Formatter, FormattingFlags, StrWriter, try_, writec};
 ///
 /// const fn writeit(mut fmt: Formatter<'_>, foo: u32, bar: &str) -> const_format::Result {
@@ -725,3 +719,400 @@ macro_rules! writec {
         }
     });
 }
+
+/// Wraps a value that has `const_display_fmt`/`const_debug_fmt` methods in a
+/// [`ConstFmtAdapter`], which implements the [`core::fmt`] traits.
+///
+/// This allows printing types that use const formatting
+/// (eg: with [`impl_fmt`] or the [`ConstDebug`] derive)
+/// with the `println`, `format`, and `write` macros.
+///
+/// # Macro variants
+///
+/// The macro has these variants:
+///
+/// - `as_std_fmt!(value)`: formats `value` with its `const_display_fmt` method
+/// for `Display` formatting, and `const_debug_fmt` for every other kind.
+///
+/// - `as_std_fmt!(display, value)`: formats `value` with its `const_display_fmt` method.
+///
+/// - `as_std_fmt!(debug, value)`: formats `value` with its `const_debug_fmt` method.
+///
+/// The value is borrowed, and it can be any type that can be passed to
+/// the [`formatc`] macro.
+///
+/// # Example
+///
+#[cfg_attr(feature = "derive", doc = "```rust")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use const_format::{ConstDebug, as_std_fmt};
+///
+/// #[derive(ConstDebug)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// let point = Point { x: 3, y: 5 };
+///
+/// assert_eq!(format!("{:?}", as_std_fmt!(debug, point)), "Point { x: 3, y: 5 }");
+///
+/// // ConstDebug types are debug formatted by `Display` too.
+/// assert_eq!(format!("{}", as_std_fmt!(debug, point)), "Point { x: 3, y: 5 }");
+///
+/// assert_eq!(
+///     format!("{:#b}", as_std_fmt!(debug, point)),
+///     "Point {\n    x: 0b11,\n    y: 0b101,\n}",
+/// );
+///
+/// assert_eq!(format!("{:>5}|{:?}", as_std_fmt!(10u8), as_std_fmt!("foo")), "   10|\"foo\"");
+///
+/// ```
+///
+/// [`ConstFmtAdapter`]: ./fmt/struct.ConstFmtAdapter.html
+/// [`impl_fmt`]: ./macro.impl_fmt.html
+/// [`ConstDebug`]: ./derive.ConstDebug.html
+/// [`formatc`]: ./macro.formatc.html
+#[macro_export]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "fmt")))]
+#[cfg(feature = "fmt")]
+macro_rules! as_std_fmt {
+    (display, $value:expr $(,)?) => {
+        $crate::fmt::ConstFmtAdapter::from_display(&$value, |this, f| {
+            $crate::coerce_to_fmt!(this).const_display_fmt(f)
+        })
+    };
+    (debug, $value:expr $(,)?) => {
+        $crate::fmt::ConstFmtAdapter::from_debug(&$value, |this, f| {
+            $crate::coerce_to_fmt!(this).const_debug_fmt(f)
+        })
+    };
+    ($value:expr $(,)?) => {
+        $crate::fmt::ConstFmtAdapter::new(
+            &$value,
+            |this, f| $crate::coerce_to_fmt!(this).const_display_fmt(f),
+            |this, f| $crate::coerce_to_fmt!(this).const_debug_fmt(f),
+        )
+    };
+}
+
+/// Formats a value with its `const_display_fmt` method into a `String`.
+///
+/// This formats into a [`StringWriter`], which grows as needed,
+/// and it's only available with the "alloc" feature.
+///
+/// # Macro variants
+///
+/// - `to_string!(value)`: formats `value` with the default [`FormattingFlags`].
+///
+/// - `to_string!(value, flags)`: formats `value` with the `flags` [`FormattingFlags`].
+///
+/// The value is borrowed, and it can be any type that can be passed to
+/// the [`formatc`] macro.
+///
+/// # Panics
+///
+/// Panics if the `const_display_fmt` method returns an error.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{Error, Formatter};
+/// use const_format::{impl_fmt, to_string, try_};
+///
+/// struct Version(u32, u32, u32);
+///
+/// impl_fmt! {
+///     impl Version;
+///
+///     const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+///         try_!(f.write_u32_display(self.0));
+///         try_!(f.write_str("."));
+///         try_!(f.write_u32_display(self.1));
+///         try_!(f.write_str("."));
+///         f.write_u32_display(self.2)
+///     }
+/// }
+///
+/// assert_eq!(to_string!(Version(0, 2, 32)), "0.2.32");
+/// assert_eq!(to_string!("hello"), "hello");
+///
+/// ```
+///
+/// [`StringWriter`]: ./fmt/struct.StringWriter.html
+/// [`FormattingFlags`]: ./fmt/struct.FormattingFlags.html
+/// [`formatc`]: ./macro.formatc.html
+#[macro_export]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+macro_rules! to_string {
+    ($value:expr $(,)?) => {
+        $crate::to_string!($value, $crate::fmt::FormattingFlags::NEW)
+    };
+    ($value:expr, $flags:expr $(,)?) => {
+        match &$value {
+            value => $crate::pmr::__to_string($flags, |f| {
+                $crate::coerce_to_fmt!(value).const_display_fmt(f)
+            }),
+        }
+    };
+}
+
+/// Formats a value with its `const_debug_fmt` method into a `String`.
+///
+/// This formats into a [`StringWriter`], which grows as needed,
+/// and it's only available with the "alloc" feature.
+///
+/// # Macro variants
+///
+/// - `to_debug_string!(value)`: formats `value` with the default [`FormattingFlags`].
+///
+/// - `to_debug_string!(value, flags)`:
+/// formats `value` with the `flags` [`FormattingFlags`],
+/// eg: to use alternate or hexadecimal formatting.
+///
+/// The value is borrowed, and it can be any type that can be passed to
+/// the [`formatc`] macro.
+///
+/// # Panics
+///
+/// Panics if the `const_debug_fmt` method returns an error.
+///
+/// # Example
+///
+#[cfg_attr(feature = "derive", doc = "```rust")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use const_format::{ConstDebug, FormattingFlags, to_debug_string};
+///
+/// #[derive(ConstDebug)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// let points = [Point { x: 3, y: 5 }, Point { x: 8, y: 13 }];
+///
+/// assert_eq!(
+///     to_debug_string!(points[0]),
+///     "Point { x: 3, y: 5 }",
+/// );
+///
+/// assert_eq!(
+///     to_debug_string!(points[1], FormattingFlags::NEW.set_alternate(true)),
+///     "Point {\n    x: 8,\n    y: 13,\n}",
+/// );
+///
+/// assert_eq!(to_debug_string!([0u8; 100]), format!("{:?}", [0u8; 100]));
+///
+/// ```
+///
+/// [`StringWriter`]: ./fmt/struct.StringWriter.html
+/// [`FormattingFlags`]: ./fmt/struct.FormattingFlags.html
+/// [`formatc`]: ./macro.formatc.html
+#[macro_export]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+macro_rules! to_debug_string {
+    ($value:expr $(,)?) => {
+        $crate::to_debug_string!($value, $crate::fmt::FormattingFlags::NEW)
+    };
+    ($value:expr, $flags:expr $(,)?) => {
+        match &$value {
+            value => $crate::pmr::__to_string($flags, |f| {
+                $crate::coerce_to_fmt!(value).const_debug_fmt(f)
+            }),
+        }
+    };
+}
+
+/// Concatenates constants of primitive types into an exactly-sized [`ConstString`].
+///
+/// This takes the same arguments as [`concatcp`],
+/// and the capacity of the returned `ConstString` is the length of the concatenated string.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{ConstString, const_string};
+///
+/// const PREFIX: &str = "v";
+///
+/// const fn version() -> ConstString<7> {
+///     const_string!(PREFIX, 0u8, '.', 2u8, '.', 32u8)
+/// }
+///
+/// const VERSION: ConstString<7> = version();
+///
+/// assert_eq!(VERSION, "v0.2.32");
+/// assert_eq!(VERSION.capacity(), VERSION.len());
+///
+/// ```
+///
+/// [`ConstString`]: ./fmt/struct.ConstString.html
+/// [`concatcp`]: ./macro.concatcp.html
+#[macro_export]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "fmt")))]
+#[cfg(feature = "fmt")]
+macro_rules! const_string {
+    ($($arg:tt)*) => {{
+        const __CF_STRING: &$crate::pmr::str = $crate::concatcp!($($arg)*);
+        $crate::unwrap!($crate::fmt::ConstString::<{ __CF_STRING.len() }>::try_from_str(
+            __CF_STRING
+        ))
+    }};
+}
+
+/// Concatenates constants of primitive types into a `&'static str`, like [`concatcp`],
+/// but also usable in generic contexts (eg: associated constants of generic impls).
+///
+/// [`concatcp`] needs to know the length of the string in a non-generic constant,
+/// which isn't possible when the arguments depend on generic parameters.
+/// This macro instead concatenates the arguments into a [`ConstString`]
+/// with the capacity passed as the first argument,
+/// causing a compile-time error if the capacity is too small.
+///
+/// # Syntax
+///
+/// `concatcp_generic!(capacity; args...)`
+///
+/// `capacity` must be a constant expression that doesn't depend on generic parameters.
+///
+/// The arguments are the same as in [`concatcp`],
+/// except that they can also be associated constants of type parameters
+/// (eg: `T::NAME`) and const parameters (eg: `N`).
+///
+/// # Compile-time errors
+///
+/// When the concatenated string is longer than `capacity` bytes,
+/// this causes a compile-time error that says how long the string is.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::concatcp_generic;
+///
+/// trait Named {
+///     const NAME: &'static str;
+/// }
+///
+/// impl Named for u32 {
+///     const NAME: &'static str = "u32";
+/// }
+///
+/// impl<T: Named> Named for Option<T> {
+///     const NAME: &'static str = concatcp_generic!(64; "Option<", T::NAME, ">");
+/// }
+///
+/// impl<T: Named, const N: usize> Named for [T; N] {
+///     const NAME: &'static str = concatcp_generic!(64; "[", T::NAME, "; ", N, "]");
+/// }
+///
+/// assert_eq!(<Option<u32>>::NAME, "Option<u32>");
+/// assert_eq!(<[Option<u32>; 3]>::NAME, "[Option<u32>; 3]");
+///
+/// ```
+///
+/// A capacity that's too small causes a compile-time error:
+///
+/// ```compile_fail
+/// use const_format::concatcp_generic;
+///
+/// trait Named {
+///     const NAME: &'static str;
+/// }
+///
+/// impl Named for u32 {
+///     const NAME: &'static str = "u32";
+/// }
+///
+/// struct Wrapper<T>(T);
+///
+/// impl<T: Named> Wrapper<T> {
+///     const NAME: &'static str = concatcp_generic!(4; "Wrapper<", T::NAME, ">");
+/// }
+///
+/// let _ = Wrapper::<u32>::NAME;
+/// ```
+///
+/// [`concatcp`]: ./macro.concatcp.html
+/// [`ConstString`]: ./fmt/struct.ConstString.html
+#[macro_export]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "fmt")))]
+#[cfg(feature = "fmt")]
+macro_rules! concatcp_generic {
+    ($capacity:expr; $($arg:expr),* $(,)?) => (
+        match &$crate::pmr::__concatcp_generic::<{ $capacity }>(&[
+            $(
+                $crate::pmr::PConvWrapper($arg)
+                    .to_pargument_display($crate::pmr::FormattingFlags::NEW),
+            )*
+        ]) {
+            string => string.as_str(),
+        }
+    );
+}
+
+/// Formats constants of primitive types into a `&'static str`, like [`formatcp`],
+/// but also usable in generic contexts (eg: associated constants of generic impls).
+///
+/// This is the formatting equivalent of [`concatcp_generic`],
+/// for more details you can look at its documentation.
+///
+/// # Syntax
+///
+/// `formatcp_generic!(capacity; format_string, args...)`
+///
+/// `capacity` must be a constant expression that doesn't depend on generic parameters.
+///
+/// The format string and arguments are the same as in [`formatcp`],
+/// except that the arguments can also be associated constants of type parameters
+/// (eg: `T::NAME`) and const parameters (eg: `N`).
+///
+/// Arguments that are used multiple times in the format string
+/// are evaluated once for every use.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::formatcp_generic;
+///
+/// trait Named {
+///     const NAME: &'static str;
+/// }
+///
+/// struct Point<T>(T, T);
+///
+/// impl<T: Named> Named for Point<T> {
+///     const NAME: &'static str = formatcp_generic!(64; "Point<{}>", T::NAME);
+/// }
+///
+/// impl Named for u8 {
+///     const NAME: &'static str = "u8";
+/// }
+///
+/// impl<A: Named, B: Named> Named for (A, B) {
+///     const NAME: &'static str =
+///         formatcp_generic!(64; "({a}, {b}) {a:?}", a = A::NAME, b = B::NAME);
+/// }
+///
+/// assert_eq!(<Point<u8>>::NAME, "Point<u8>");
+/// assert_eq!(<(u8, Point<u8>)>::NAME, r#"(u8, Point<u8>) "u8""#);
+///
+/// ```
+///
+/// [`formatcp`]: ./macro.formatcp.html
+/// [`concatcp_generic`]: ./macro.concatcp_generic.html
+#[macro_export]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "fmt")))]
+#[cfg(feature = "fmt")]
+macro_rules! formatcp_generic {
+    ($capacity:expr; $format_string:expr $( $(, $expr:expr )+ )? $(,)? ) => ({
+        use $crate::__cf_osRcTFl4A;
+
+        $crate::pmr::__formatcp_generic_impl!(
+            ($capacity)
+            ($format_string)
+            $(, $($expr,)+)?
+        )
+    });
+}
@@ -101,44 +101,17 @@ macro_rules! __write_pvariant {
             $out.len += 1;
 
             while i < str.len() {
-                use $crate::pmr::{hex_as_ascii, ForEscaping, FOR_ESCAPING};
+                let (elem, char_len) = $crate::pmr::str_char_to_debug(str, i, str.len());
+                let encoded = elem.encoded();
+                let mut j = 0;
 
                 #[allow(clippy::indexing_slicing)]
-                let c = str[i];
-                let mut written_c = c;
-
-                if c < 128 {
-                    let shifted = 1 << c;
-
-                    if (FOR_ESCAPING.is_escaped & shifted) != 0 {
-                        #[allow(clippy::indexing_slicing)]
-                        {
-                            $out.array[$out.len] = b'\\';
-                        }
-                        $out.len += 1;
-
-                        if (FOR_ESCAPING.is_backslash_escaped & shifted) == 0 {
-                            #[allow(clippy::indexing_slicing)]
-                            {
-                                $out.array[$out.len] = b'x';
-                                $out.array[$out.len + 1] =
-                                    hex_as_ascii(c >> 4, $crate::pmr::HexFormatting::Upper);
-                            }
-
-                            $out.len += 2;
-                            written_c = hex_as_ascii(c & 0b1111, $crate::pmr::HexFormatting::Upper);
-                        } else {
-                            written_c = ForEscaping::get_backslash_escape(c);
-                        };
-                    }
+                while j < elem.len() {
+                    $out.array[$out.len] = encoded[j];
+                    $out.len += 1;
+                    j += 1;
                 }
-
-                #[allow(clippy::indexing_slicing)]
-                {
-                    $out.array[$out.len] = written_c;
-                }
-                $out.len += 1;
-                i += 1;
+                i += char_len;
             }
 
             #[allow(clippy::indexing_slicing)]
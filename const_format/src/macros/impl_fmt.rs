@@ -16,8 +16,6 @@
 /// This demonstrates how you can implement debug formatting for a generic struct.
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Error, Formatter, PWrapper, StrWriter};
 /// use const_format::{formatc, impl_fmt, try_};
 ///
@@ -69,8 +67,6 @@
 /// using this macro purely for implementing the [`FormatMarker`] trait.
 ///
 /// ```rust
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Error, Formatter, PWrapper, StrWriter};
 /// use const_format::{formatc, impl_fmt, try_};
 ///
@@ -322,3 +318,61 @@ macro_rules! __impl_fmt_inner {
     };
 
 }
+
+// Implements the `ConstDebug` or `ConstDisplay` trait for every impl passed to it,
+// delegating to the inherent method, used by the `ConstDebug` and `ConstDisplay` derives.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "nightly_const_traits")]
+#[allow_internal_unstable(const_trait_impl)]
+macro_rules! __impl_const_fmt_trait {
+    (
+        $trait:ident :: $method:ident;
+        $(
+            impl[$($impl_:tt)*] $type:ty
+            $(where[ $($where:tt)* ])?;
+        )*
+    ) => (
+        $(
+            impl<$($impl_)*> const $crate::fmt::$trait for $type
+            where
+                $($($where)*)?
+            {
+                #[inline(always)]
+                fn $method(
+                    &self,
+                    f: &mut $crate::pmr::Formatter<'_>,
+                ) -> $crate::pmr::Result<(), $crate::pmr::Error> {
+                    self.$method(f)
+                }
+            }
+        )*
+    );
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "nightly_const_traits"))]
+macro_rules! __impl_const_fmt_trait {
+    ($($tt:tt)*) => {};
+}
+
+// Errors when the `#[cdeb(const_trait_bounds)]` attribute is used
+// without the "nightly_const_traits" feature.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "nightly_const_traits")]
+macro_rules! __assert_const_trait_bounds_supported {
+    () => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "nightly_const_traits"))]
+macro_rules! __assert_const_trait_bounds_supported {
+    () => {
+        ::core::compile_error! {
+            "the `#[cdeb(const_trait_bounds)]` attribute requires the \"nightly_const_traits\" feature"
+        }
+    };
+}
@@ -82,6 +82,264 @@ macro_rules! str_replace {
     }};
 }
 
+/// Decodes the escape sequences in a `&'static str` constant.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// fn str_unescape(input: &'static str) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// These are the supported escape sequences, the same as in Rust string literals
+/// (except for line continuations):
+///
+/// - `\n`, `\r`, `\t`, `\0`, `\\`, `\'`, `\"`
+///
+/// - `\xNN`: an ascii character, with `NN` being two hexadecimal digits
+/// from `00` up to `7F` inclusive.
+///
+/// - `\u{N}`: a unicode scalar value, with `N` being 1 to 6 hexadecimal digits
+/// (underscores are allowed after the first digit).
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_unescape;
+///
+/// assert_eq!(str_unescape!(r"hello\nworld"), "hello\nworld");
+///
+/// assert_eq!(str_unescape!(r"\t\\\x41\u{1F600}"), "\t\\A😀");
+///
+/// // This shows that the argument can be a `const`, it doesn't have to be a literal.
+/// {
+///     const IN: &str = r#"\"quoted\" \u{00F1}"#;
+///     assert_eq!(str_unescape!(IN), "\"quoted\" ñ");
+/// }
+/// ```
+///
+/// ### Invalid escapes
+///
+/// Malformed escape sequences cause compilation errors,
+/// the index in the error message is the byte offset of the `\`
+/// that starts the malformed escape sequence.
+///
+/// ```compile_fail
+/// const_format::str_unescape!(r"foo\q");
+/// ```
+#[cfg_attr(
+    feature = "__test",
+    doc = r#"
+```rust
+assert_eq!(const_format::str_unescape!(r"\x7F\u{10_FFFF}"), "\x7F\u{10FFFF}");
+```
+
+```compile_fail
+const_format::str_unescape!(r"\x80");
+```
+
+```compile_fail
+const_format::str_unescape!(r"\x4");
+```
+
+```compile_fail
+const_format::str_unescape!(r"\u{D800}");
+```
+
+```compile_fail
+const_format::str_unescape!(r"\u{41");
+```
+
+```compile_fail
+const_format::str_unescape!(r"foo\");
+```
+"#
+)]
+#[macro_export]
+#[cfg(feature = "rust_1_51")]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "rust_1_51")))]
+macro_rules! str_unescape {
+    ($string:expr $(,)*) => {{
+        const ARGS_OSRCTFL4A: $crate::__str_methods::UnescapeInput =
+            $crate::__str_methods::UnescapeInput::new($string);
+
+        {
+            $crate::pmr::respan_to! {
+                ($string)
+                const _ASSERT_VALID_ESCAPES: () = ARGS_OSRCTFL4A.assert_valid();
+            }
+
+            const OB: &[$crate::pmr::u8; ARGS_OSRCTFL4A.unescaped_length()] =
+                &ARGS_OSRCTFL4A.unescape();
+
+            const OS: &$crate::pmr::str = unsafe { $crate::__priv_transmute_bytes_to_str!(OB) };
+
+            OS
+        }
+    }};
+}
+
+/// Converts a `&'static [u8]` constant to a `&'static str`,
+/// checking at compile-time that it's valid utf8.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Bytes {}
+/// fn str_from_utf8(input: impl Bytes) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `input` can be any of these types:
+///
+/// - `&'static [u8]`
+///
+/// - `&'static [u8; N]` (eg: the type of [`include_bytes`])
+///
+/// - `&'static str`
+///
+/// For converting invalid utf8 by replacing it with `U+FFFD`,
+/// there's the [`str_from_utf8_lossy`] macro.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_from_utf8;
+///
+/// assert_eq!(str_from_utf8!(b"hello"), "hello");
+///
+/// {
+///     const BYTES: &[u8] = &[0xF0, 0x9F, 0x98, 0x80, b'!'];
+///     const TEXT: &str = str_from_utf8!(BYTES);
+///     assert_eq!(TEXT, "😀!");
+/// }
+/// ```
+///
+/// ### Invalid utf8
+///
+/// Invalid utf8 causes a compilation error,
+/// the index in the error message is the byte offset of the first invalid byte,
+/// the same as [`Utf8Error::valid_up_to`].
+///
+/// ```compile_fail
+/// const_format::str_from_utf8!(b"foo\xFFbar");
+/// ```
+///
+/// [`include_bytes`]: https://doc.rust-lang.org/core/macro.include_bytes.html
+/// [`str_from_utf8_lossy`]: ./macro.str_from_utf8_lossy.html
+/// [`Utf8Error::valid_up_to`]:
+/// https://doc.rust-lang.org/core/str/struct.Utf8Error.html#method.valid_up_to
+#[cfg_attr(
+    feature = "__test",
+    doc = r#"
+```rust
+assert_eq!(const_format::str_from_utf8!(b""), "");
+assert_eq!(const_format::str_from_utf8!("\u{10FFFF}"), "\u{10FFFF}");
+```
+
+```compile_fail
+// surrogate
+const_format::str_from_utf8!(b"\xED\xA0\x80");
+```
+
+```compile_fail
+// overlong encoding
+const_format::str_from_utf8!(b"\xC0\x80");
+```
+
+```compile_fail
+// incomplete sequence
+const_format::str_from_utf8!(b"foo\xE2\x82");
+```
+"#
+)]
+#[macro_export]
+#[cfg(feature = "rust_1_51")]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "rust_1_51")))]
+macro_rules! str_from_utf8 {
+    ($input:expr $(,)*) => {{
+        const ARGS_OSRCTFL4A: $crate::__str_methods::FromUtf8Input =
+            $crate::__str_methods::FromUtf8Input::new(
+                $crate::__binary_encoding::BytesConv($input).conv(),
+            );
+
+        {
+            $crate::pmr::respan_to! {
+                ($input)
+                const _ASSERT_VALID_UTF8: () = ARGS_OSRCTFL4A.assert_valid();
+            }
+
+            const OB: &[$crate::pmr::u8; ARGS_OSRCTFL4A.valid_up_to()] =
+                &ARGS_OSRCTFL4A.valid_prefix();
+
+            const OS: &$crate::pmr::str = unsafe { $crate::__priv_transmute_bytes_to_str!(OB) };
+
+            OS
+        }
+    }};
+}
+
+/// Converts a `&'static [u8]` constant to a `&'static str`,
+/// replacing invalid utf8 with `U+FFFD` (`�`).
+///
+/// This replaces invalid utf8 the same way that [`String::from_utf8_lossy`] does.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Bytes {}
+/// fn str_from_utf8_lossy(input: impl Bytes) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `input` can be any of these types:
+///
+/// - `&'static [u8]`
+///
+/// - `&'static [u8; N]` (eg: the type of [`include_bytes`])
+///
+/// - `&'static str`
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_from_utf8_lossy;
+///
+/// assert_eq!(str_from_utf8_lossy!(b"hello"), "hello");
+///
+/// assert_eq!(str_from_utf8_lossy!(b"foo\xFFbar\xE2\x82"), "foo\u{FFFD}bar\u{FFFD}");
+///
+/// ```
+///
+/// [`include_bytes`]: https://doc.rust-lang.org/core/macro.include_bytes.html
+/// [`String::from_utf8_lossy`]:
+/// https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8_lossy
+#[macro_export]
+#[cfg(feature = "rust_1_51")]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "rust_1_51")))]
+macro_rules! str_from_utf8_lossy {
+    ($input:expr $(,)*) => {{
+        const ARGS_OSRCTFL4A: $crate::__str_methods::FromUtf8Input =
+            $crate::__str_methods::FromUtf8Input::new(
+                $crate::__binary_encoding::BytesConv($input).conv(),
+            );
+
+        {
+            const OB: &[$crate::pmr::u8; ARGS_OSRCTFL4A.lossy_length()] = &ARGS_OSRCTFL4A.lossy();
+
+            const OS: &$crate::pmr::str = unsafe { $crate::__priv_transmute_bytes_to_str!(OB) };
+
+            OS
+        }
+    }};
+}
+
 /// Creates a `&'static str` by repeating a `&'static str` constant `times` times
 ///
 /// This is evaluated at compile-time.
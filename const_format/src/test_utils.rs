@@ -63,8 +63,82 @@ pub const ALL_ASCII: &str = "\
 ";
 
 pub const ALL_ASCII_ESCAPED: &str = "\
- \\x00\\x01\\x02\\x03\\x04\\x05\\x06\\x07\\x08\\t\\n\\x0B\\x0C\\r\\x0E\\x0F\
- \\x10\\x11\\x12\\x13\\x14\\x15\\x16\\x17\\x18\\x19\\x1A\\x1B\\x1C\\x1D\\x1E\\x1F \
- !\\\"#$%&\\\'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\\\]\
- ^_`abcdefghijklmnopqrstuvwxyz{|}~\x7f\u{80}\u{81}\u{90}\u{91}\
+ \\0\\u{1}\\u{2}\\u{3}\\u{4}\\u{5}\\u{6}\\u{7}\\u{8}\\t\\n\\u{b}\\u{c}\\r\\u{e}\\u{f}\
+ \\u{10}\\u{11}\\u{12}\\u{13}\\u{14}\\u{15}\\u{16}\\u{17}\\u{18}\\u{19}\\u{1a}\\u{1b}\
+ \\u{1c}\\u{1d}\\u{1e}\\u{1f} \
+ !\\\"#$%&\'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\\\]\
+ ^_`abcdefghijklmnopqrstuvwxyz{|}~\\u{7f}\\u{80}\\u{81}\\u{90}\\u{91}\
 ";
+
+/// The chars that are debug-formatted differently by
+/// some of the Rust versions that this crate is tested with,
+/// either because they predate Unicode 17.0.0 (Rust 1.46.0 uses Unicode 13.0.0),
+/// or because they escaped `'\0'`, `'"'`, and `'\''` differently.
+///
+/// This is a sorted list of non-overlapping inclusive ranges.
+#[rustfmt::skip]
+pub const STD_DEBUG_CHANGED_CHARS: &[(u32, u32)] = &[
+    (0x00000, 0x00000), (0x00022, 0x00022), (0x00027, 0x00027), (0x0061D, 0x0061D),
+    (0x00870, 0x0088F), (0x008B5, 0x008B5), (0x008C8, 0x008C9), (0x00C5C, 0x00C5D),
+    (0x00CC0, 0x00CC0), (0x00CC7, 0x00CC8), (0x00CCA, 0x00CCB), (0x00CDC, 0x00CDD),
+    (0x00CF3, 0x00CF3), (0x0170D, 0x0170D), (0x01715, 0x01715), (0x0171F, 0x0171F),
+    (0x01734, 0x01734), (0x01B3B, 0x01B3B), (0x01B3D, 0x01B3D), (0x01B43, 0x01B44),
+    (0x01B4C, 0x01B4C), (0x01B4E, 0x01B4F), (0x01B7D, 0x01B7F), (0x01BAA, 0x01BAA),
+    (0x01BF2, 0x01BF3), (0x01C89, 0x01C8A), (0x020C0, 0x020C1), (0x02427, 0x02429),
+    (0x02B96, 0x02B96), (0x02C2F, 0x02C2F), (0x02C5F, 0x02C5F), (0x02E53, 0x02E5D),
+    (0x02FFC, 0x02FFF), (0x031E4, 0x031E5), (0x031EF, 0x031EF), (0x09FFD, 0x09FFF),
+    (0x0A7C0, 0x0A7C1), (0x0A7CB, 0x0A7DC), (0x0A7F1, 0x0A7F4), (0x0A953, 0x0A953),
+    (0x0A9C0, 0x0A9C0), (0x0FBC2, 0x0FBD2), (0x0FD40, 0x0FD4F), (0x0FD90, 0x0FD91),
+    (0x0FDC8, 0x0FDCF), (0x0FDFE, 0x0FDFF), (0x10570, 0x1057A), (0x1057C, 0x1058A),
+    (0x1058C, 0x10592), (0x10594, 0x10595), (0x10597, 0x105A1), (0x105A3, 0x105B1),
+    (0x105B3, 0x105B9), (0x105BB, 0x105BC), (0x105C0, 0x105F3), (0x10780, 0x10785),
+    (0x10787, 0x107B0), (0x107B2, 0x107BA), (0x10940, 0x10959), (0x10D40, 0x10D65),
+    (0x10D6E, 0x10D85), (0x10D8E, 0x10D8F), (0x10EC2, 0x10EC7), (0x10ED0, 0x10ED8),
+    (0x10F70, 0x10F81), (0x10F86, 0x10F89), (0x11071, 0x11072), (0x11075, 0x11075),
+    (0x111C0, 0x111C0), (0x11235, 0x11235), (0x1123F, 0x11240), (0x1134D, 0x1134D),
+    (0x11380, 0x11389), (0x1138B, 0x1138B), (0x1138E, 0x1138E), (0x11390, 0x113B5),
+    (0x113B7, 0x113B7), (0x113B9, 0x113BA), (0x113CA, 0x113CA), (0x113CC, 0x113CD),
+    (0x113D1, 0x113D1), (0x113D3, 0x113D5), (0x113D7, 0x113D8), (0x116B6, 0x116B6),
+    (0x116B9, 0x116B9), (0x116D0, 0x116E3), (0x1171E, 0x1171E), (0x11740, 0x11746),
+    (0x1193D, 0x1193D), (0x11AB0, 0x11ABF), (0x11B00, 0x11B09), (0x11B61, 0x11B61),
+    (0x11B65, 0x11B65), (0x11B67, 0x11B67), (0x11BC0, 0x11BE1), (0x11BF0, 0x11BF9),
+    (0x11DB0, 0x11DDB), (0x11DE0, 0x11DE9), (0x11F02, 0x11F10), (0x11F12, 0x11F35),
+    (0x11F3E, 0x11F3F), (0x11F43, 0x11F59), (0x12F90, 0x12FF2), (0x1342F, 0x1342F),
+    (0x13441, 0x13446), (0x13460, 0x143FA), (0x16100, 0x1611D), (0x1612A, 0x1612C),
+    (0x16130, 0x16139), (0x16A70, 0x16ABE), (0x16AC0, 0x16AC9), (0x16D40, 0x16D79),
+    (0x16EA0, 0x16EB8), (0x16EBB, 0x16ED3), (0x16FF0, 0x16FF6), (0x187F8, 0x187FF),
+    (0x18CFF, 0x18CFF), (0x18D09, 0x18D1E), (0x18D80, 0x18DF2), (0x1AFF0, 0x1AFF3),
+    (0x1AFF5, 0x1AFFB), (0x1AFFD, 0x1AFFE), (0x1B11F, 0x1B122), (0x1B132, 0x1B132),
+    (0x1B155, 0x1B155), (0x1CC00, 0x1CCFC), (0x1CD00, 0x1CEB3), (0x1CEBA, 0x1CED0),
+    (0x1CEE0, 0x1CEF0), (0x1CF50, 0x1CFC3), (0x1D166, 0x1D166), (0x1D16D, 0x1D16D),
+    (0x1D1E9, 0x1D1EA), (0x1D2C0, 0x1D2D3), (0x1DF00, 0x1DF1E), (0x1DF25, 0x1DF2A),
+    (0x1E030, 0x1E06D), (0x1E290, 0x1E2AD), (0x1E4D0, 0x1E4EB), (0x1E4F0, 0x1E4F9),
+    (0x1E5D0, 0x1E5ED), (0x1E5F0, 0x1E5FA), (0x1E5FF, 0x1E5FF), (0x1E6C0, 0x1E6DE),
+    (0x1E6E0, 0x1E6E2), (0x1E6E4, 0x1E6E5), (0x1E6E7, 0x1E6ED), (0x1E6F0, 0x1E6F4),
+    (0x1E6FE, 0x1E6FF), (0x1E7E0, 0x1E7E6), (0x1E7E8, 0x1E7EB), (0x1E7ED, 0x1E7EE),
+    (0x1E7F0, 0x1E7FE), (0x1F6D8, 0x1F6D8), (0x1F6DC, 0x1F6DF), (0x1F774, 0x1F77F),
+    (0x1F7D9, 0x1F7D9), (0x1F7F0, 0x1F7F0), (0x1F8B2, 0x1F8BB), (0x1F8C0, 0x1F8C1),
+    (0x1F8D0, 0x1F8D8), (0x1F979, 0x1F979), (0x1F9CC, 0x1F9CC), (0x1FA54, 0x1FA57),
+    (0x1FA75, 0x1FA77), (0x1FA7B, 0x1FA7C), (0x1FA87, 0x1FA8A), (0x1FA8E, 0x1FA8F),
+    (0x1FAA9, 0x1FAAF), (0x1FAB7, 0x1FABF), (0x1FAC3, 0x1FAC6), (0x1FAC8, 0x1FAC8),
+    (0x1FACD, 0x1FACF), (0x1FAD7, 0x1FADC), (0x1FADF, 0x1FAEA), (0x1FAEF, 0x1FAF8),
+    (0x1FBCB, 0x1FBEF), (0x1FBFA, 0x1FBFA), (0x2A6DE, 0x2A6DF), (0x2B735, 0x2B73F),
+    (0x2CEA2, 0x2CEAD), (0x2EBF0, 0x2EE5D), (0x31350, 0x33479),
+];
+
+/// Whether the standard library debug-formats `c` differently depending on the Rust version,
+/// so it can't be used as the expected output for `c`.
+pub fn std_debug_changed(c: char) -> bool {
+    let c = u32::from(c);
+    STD_DEBUG_CHANGED_CHARS
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                core::cmp::Ordering::Less
+            } else if c < start {
+                core::cmp::Ordering::Greater
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
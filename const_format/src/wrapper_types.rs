@@ -7,6 +7,9 @@
 #[cfg(feature = "fmt")]
 pub(crate) mod ascii_str;
 
+#[cfg(feature = "fmt")]
+pub(crate) mod byte_str;
+
 pub(crate) mod pwrapper;
 
 #[cfg(feature = "fmt")]
@@ -17,7 +20,7 @@ pub use self::ascii_str::NotAsciiError;
 
 #[doc(no_inline)]
 #[cfg(feature = "fmt")]
-pub use crate::{AsciiStr, Sliced};
+pub use crate::{AsciiStr, ByteStr, HexDump, Sliced};
 
 #[doc(no_inline)]
 pub use crate::PWrapper;
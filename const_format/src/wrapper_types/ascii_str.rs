@@ -309,7 +309,7 @@ mod tests {
                     foo,
                     writer,
                     flag,
-                    "\"\\x00\\x10hello\\tworld\\n\"",
+                    "\"\\0\\u{10}hello\\tworld\\n\"",
                     foo.as_str(),
                 );
             }
@@ -0,0 +1,78 @@
+use crate::fmt::{Error, Formatter};
+
+/// Wrapper for Debug formatting a byte slice as a byte string literal.
+///
+/// The bytes are escaped the same way that
+/// [`u8::escape_ascii`](https://doc.rust-lang.org/std/primitive.u8.html#method.escape_ascii)
+/// escapes them, surrounded by `b"` and `"`.
+///
+/// This only implements Debug formatting,
+/// since there's no obvious way to Display format arbitrary bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::ByteStr;
+/// use const_format::formatc;
+///
+/// const BYTES: &[u8] = b"\x00foo\n\xFF";
+///
+/// assert_eq!(formatc!("{:?}", ByteStr(BYTES)), r#"b"\x00foo\n\xff""#);
+///
+/// ```
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "fmt")))]
+#[derive(Copy, Clone)]
+pub struct ByteStr<'a>(pub &'a [u8]);
+
+impl_fmt! {
+    impl['a,] ByteStr<'a>;
+
+    ///
+    #[inline]
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_bytes_debug(self.0)
+    }
+}
+
+/// Wrapper for formatting a byte slice as a hexdump,
+/// in the same format as the `hexdump -C` command.
+///
+/// Both Display and Debug formatting output the same hexdump.
+/// Lines are separated with `\n`, without a trailing newline.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::HexDump;
+/// use const_format::formatc;
+///
+/// const BYTES: &[u8] = b"The quick brown fox\n";
+///
+/// assert_eq!(
+///     formatc!("{}", HexDump(BYTES)),
+///     "\
+/// 00000000  54 68 65 20 71 75 69 63  6b 20 62 72 6f 77 6e 20  |The quick brown |
+/// 00000010  66 6f 78 0a                                       |fox.|\
+/// ",
+/// );
+///
+/// ```
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "fmt")))]
+#[derive(Copy, Clone)]
+pub struct HexDump<'a>(pub &'a [u8]);
+
+impl_fmt! {
+    impl['a,] HexDump<'a>;
+
+    ///
+    #[inline]
+    pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_bytes_hexdump(self.0)
+    }
+
+    ///
+    #[inline]
+    pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_bytes_hexdump(self.0)
+    }
+}
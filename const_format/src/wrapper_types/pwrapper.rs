@@ -1,5 +1,5 @@
 use crate::{
-    formatting::{FormattingFlags, NumberFormatting, StartAndArray, FOR_ESCAPING},
+    formatting::{FormattingFlags, NumberFormatting, StartAndArray},
     pargument::Integer,
 };
 
@@ -37,8 +37,6 @@ mod tests;
 ///
 #[cfg_attr(feature = "fmt", doc = "```rust")]
 #[cfg_attr(not(feature = "fmt"), doc = "```ignore")]
-/// #![feature(const_mut_refs)]
-///
 /// use const_format::{Error, Formatter, PWrapper};
 /// use const_format::{impl_fmt, formatc, try_};
 ///
@@ -409,23 +407,8 @@ impl PWrapper<&[u8]> {
 
     /// Computes how much space is necessary to write `&self.0[range]` as a utf8 string,
     /// with debug formatting
-    pub const fn compute_utf8_debug_len_in_range(self, mut range: Range<usize>) -> usize {
-        let mut sum = range.end - range.start;
-        while range.start < range.end {
-            let c = self.0[range.start];
-            if c < 128 {
-                let shifted = 1 << c;
-                if (FOR_ESCAPING.is_escaped & shifted) != 0 {
-                    sum += if (FOR_ESCAPING.is_backslash_escaped & shifted) == 0 {
-                        3 // `\x01` only add 3 characters
-                    } else {
-                        1 // Escaped with a backslash
-                    };
-                }
-            }
-            range.start += 1;
-        }
-        sum + 2 // The quote characters
+    pub const fn compute_utf8_debug_len_in_range(self, range: Range<usize>) -> usize {
+        crate::char_encoding::str_debug_len(self.0, range.start, range.end)
     }
 }
 
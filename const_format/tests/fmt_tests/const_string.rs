@@ -0,0 +1,113 @@
+use cfmt_a::{
+    const_string,
+    fmt::{ConstString, Error, FormattingFlags},
+    formatc, unwrap, writec,
+};
+
+use core::cmp::Ordering;
+
+const fn make_list(a: u32, b: u32) -> ConstString<32> {
+    let mut string = ConstString::new();
+    unwrap!(writec!(string, "{:?}", [a, b]));
+    unwrap!(string.push_str("!"));
+    string
+}
+
+static LIST: ConstString<32> = make_list(3, 5);
+
+#[test]
+fn construction() {
+    assert_eq!(LIST, "[3, 5]!");
+    assert_eq!(LIST.len(), 7);
+    assert_eq!(LIST.capacity(), 32);
+    assert_eq!(LIST.remaining_capacity(), 25);
+    assert_eq!(LIST.as_bytes(), b"[3, 5]!");
+
+    let empty = ConstString::<4>::default();
+    assert!(empty.is_empty());
+    assert_eq!(empty, "");
+
+    const EXACT: ConstString<12> = const_string!("foo", 100u8, 'ñ', true);
+    assert_eq!(EXACT, "foo100ñtrue");
+    assert_eq!(EXACT.len(), EXACT.capacity());
+}
+
+#[test]
+fn mutation() {
+    let mut string = ConstString::<8>::new();
+
+    string.push_str("ñandú").unwrap();
+    assert_eq!(string, "ñandú");
+    assert_eq!(string.push_str("abc"), Err(Error::NotEnoughSpace));
+    assert_eq!(string, "ñandú");
+    string.push_str("a").unwrap();
+    assert_eq!(string.remaining_capacity(), 0);
+
+    assert_eq!(string.truncate(1), Err(Error::NotOnCharBoundary));
+    string.truncate(2).unwrap();
+    assert_eq!(string, "ñ");
+
+    string.as_mut().write_u8_display(10).unwrap();
+    assert_eq!(string, "ñ10");
+
+    string
+        .make_formatter(FormattingFlags::NEW.set_hexadecimal())
+        .write_u8_debug(255)
+        .unwrap();
+    assert_eq!(string, "ñ10FF");
+
+    string.clear();
+    assert!(string.is_empty());
+}
+
+#[test]
+fn concat_and_resize() {
+    const FOO: ConstString<3> = const_string!("foo");
+    const BAR: ConstString<4> = const_string!("-bar");
+
+    const BOTH: ConstString<7> = unwrap!(FOO.concat(&BAR));
+    assert_eq!(BOTH, "foo-bar");
+
+    assert_eq!(FOO.concat::<4, 6>(&BAR), Err(Error::NotEnoughSpace));
+    assert_eq!(FOO.concat::<4, 2>(&BAR), Err(Error::NotEnoughSpace));
+
+    let bigger: ConstString<10> = BOTH.resize().unwrap();
+    assert_eq!(bigger, BOTH);
+    assert_eq!(bigger.capacity(), 10);
+    assert_eq!(BOTH.resize::<6>(), Err(Error::NotEnoughSpace));
+}
+
+#[test]
+fn comparison() {
+    let strs = ["", "a", "aa", "ab", "b", "ba", "ñ", "ña"];
+
+    for &l in &strs {
+        let left = ConstString::<4>::try_from_str(l).unwrap();
+        assert!(left.eq_str(l));
+        assert_eq!(left, l);
+        assert_eq!(left, *l);
+
+        for &r in &strs {
+            let right = ConstString::<8>::try_from_str(r).unwrap();
+
+            assert_eq!(left.const_eq(&right), l == r, "{:?} {:?}", l, r);
+            assert_eq!(left == right, l == r, "{:?} {:?}", l, r);
+            assert_eq!(left.const_cmp(&right), l.cmp(r), "{:?} {:?}", l, r);
+            assert_eq!(left.partial_cmp(&right), Some(l.cmp(r)));
+        }
+    }
+
+    let mut sorted = [const_string!("b"), const_string!("c"), const_string!("a")];
+    sorted.sort();
+    assert_eq!(sorted, ["a", "b", "c"]);
+    assert_eq!(sorted[0].const_cmp(&sorted[1]), Ordering::Less);
+}
+
+#[test]
+fn formatting() {
+    const STR: ConstString<3> = const_string!("a\nb");
+
+    assert_eq!(formatc!("{0} {0:?}", STR), "a\nb \"a\\nb\"");
+    assert_eq!(format!("{0} {0:?}", STR), "a\nb \"a\\nb\"");
+    assert_eq!(format!("{:>5}|", STR), "  a\nb|");
+}
@@ -0,0 +1,219 @@
+use cfmt_a::{
+    fmt::{ConstDebug, ConstDisplay, ConstString, Error, Formatter, FormattingFlags},
+    try_,
+};
+
+use core::{cmp::Ordering, marker::PhantomData, num::NonZeroU8};
+
+const fn display<T: ?Sized + [const] ConstDisplay>(value: &T) -> ConstString<128> {
+    let mut string = ConstString::new();
+    match value.const_display_fmt(&mut string.make_formatter(FormattingFlags::NEW)) {
+        Ok(()) => string,
+        Err(_) => panic!("formatting failed"),
+    }
+}
+
+const fn debug<T: ?Sized + [const] ConstDebug>(
+    value: &T,
+    flags: FormattingFlags,
+) -> ConstString<128> {
+    let mut string = ConstString::new();
+    match value.const_debug_fmt(&mut string.make_formatter(flags)) {
+        Ok(()) => string,
+        Err(_) => panic!("formatting failed"),
+    }
+}
+
+struct Pair<A, B>(A, B);
+
+impl<A: [const] ConstDebug, B: [const] ConstDebug> const ConstDebug for Pair<A, B> {
+    fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut f = f.debug_tuple("Pair");
+        try_!(self.0.const_debug_fmt(f.field()));
+        try_!(self.1.const_debug_fmt(f.field()));
+        f.finish()
+    }
+}
+
+#[test]
+fn std_display() {
+    const NEW: FormattingFlags = FormattingFlags::NEW;
+    macro_rules! case {
+        ($value:expr, $expected:expr) => {{
+            const S: &ConstString<128> = &display(&$value);
+            assert_eq!(S.as_str(), $expected);
+            const D: &ConstString<128> = &debug(&$value, NEW);
+            assert_eq!(D.as_str(), format!("{:?}", $value));
+        }};
+    }
+
+    case!(3u8, "3");
+    case!(-5i128, "-5");
+    case!(usize::MAX, usize::MAX.to_string());
+    case!(true, "true");
+    case!('\n', "\n");
+    case!("foo\"", "foo\"");
+    case!(&&"bar", "bar");
+    case!(NonZeroU8::new(8).unwrap(), "8");
+
+    const STR: &ConstString<128> = &display("hello");
+    assert_eq!(STR.as_str(), "hello");
+}
+
+#[test]
+fn std_debug() {
+    const NEW: FormattingFlags = FormattingFlags::NEW;
+    const ALT: FormattingFlags = FormattingFlags::NEW.set_alternate(true);
+    const HEX: FormattingFlags = FormattingFlags::NEW.set_lower_hexadecimal();
+
+    macro_rules! case {
+        ($value:expr, $flags:expr, $expected:expr) => {{
+            const S: &ConstString<128> = &debug(&$value, $flags);
+            assert_eq!(S.as_str(), $expected);
+        }};
+    }
+
+    case!([3u8, 5], NEW, "[3, 5]");
+    case!([10u8, 255], HEX, "[a, ff]");
+    case!([Some("a"), None], NEW, r#"[Some("a"), None]"#);
+    case!([Some(1u8)], ALT, "[\n    Some(\n        1,\n    ),\n]");
+    case!(Ordering::Less, NEW, "Less");
+    case!(PhantomData::<str>, NEW, "PhantomData");
+    case!((), NEW, "()");
+    case!(3..5usize, NEW, "3..5");
+    case!(..=5usize, NEW, "..=5");
+    case!(.., NEW, "..");
+
+    const NUMS: &[u16] = &[1, 2, 3];
+    const SLICE: &ConstString<128> = &debug(NUMS, NEW);
+    assert_eq!(SLICE.as_str(), "[1, 2, 3]");
+}
+
+#[test]
+fn generic_impls() {
+    const PAIR: &ConstString<128> = &debug(
+        &Pair(3u8, Pair("foo", [Some('a'), None])),
+        FormattingFlags::NEW,
+    );
+    assert_eq!(PAIR.as_str(), r#"Pair(3, Pair("foo", [Some('a'), None]))"#);
+
+    const STRING: ConstString<3> = cfmt_a::const_string!("bar");
+    const NESTED: &ConstString<128> = &debug(&Pair(STRING, Some(STRING)), FormattingFlags::NEW);
+    assert_eq!(NESTED.as_str(), r#"Pair("bar", Some("bar"))"#);
+}
+
+#[cfg(feature = "derive")]
+mod derived {
+    use super::*;
+
+    use cfmt_a::{ConstDebug, ConstDisplay};
+
+    #[derive(ConstDebug)]
+    #[cdeb(crate = "::cfmt_a")]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    #[derive(ConstDebug)]
+    #[cdeb(crate = "::cfmt_a")]
+    #[cdeb(impls("Wrapper<u8>", "<T> Wrapper<PhantomData<T>>"))]
+    struct Wrapper<T>(T);
+
+    #[derive(ConstDisplay)]
+    #[cdisplay(crate = "::cfmt_a")]
+    #[cdisplay("({x}, {y})")]
+    struct DisplayPoint {
+        x: u32,
+        y: u32,
+    }
+
+    #[test]
+    fn derived_impls() {
+        const POINT: &ConstString<128> =
+            &debug(&Pair(Point { x: 3, y: 5 }, 8u8), FormattingFlags::NEW);
+        assert_eq!(POINT.as_str(), "Pair(Point { x: 3, y: 5 }, 8)");
+
+        const WRAPPERS: &ConstString<128> = &debug(
+            &[Pair(Wrapper(3u8), Wrapper(PhantomData::<u8>))],
+            FormattingFlags::NEW,
+        );
+        assert_eq!(
+            WRAPPERS.as_str(),
+            "[Pair(Wrapper(3), Wrapper(PhantomData))]"
+        );
+    }
+
+    #[test]
+    fn derived_display_impls() {
+        const POINT: &ConstString<128> = &display(&&DisplayPoint { x: 3, y: 5 });
+        assert_eq!(POINT.as_str(), "(3, 5)");
+    }
+
+    #[derive(ConstDebug)]
+    #[cdeb(crate = "::cfmt_a")]
+    #[cdeb(const_trait_bounds)]
+    #[cdeb(std_debug)]
+    struct Generic<A, B> {
+        left: A,
+        right: Option<B>,
+        count: u32,
+    }
+
+    #[derive(ConstDebug)]
+    #[cdeb(crate = "::cfmt_a")]
+    #[cdeb(const_trait_bounds)]
+    enum GenericEnum<T> {
+        Unit,
+        Tuple(T, u8),
+        Braced { list: [T; 2] },
+    }
+
+    #[test]
+    fn const_trait_bounds() {
+        const NEW: FormattingFlags = FormattingFlags::NEW;
+
+        const GENERIC: &ConstString<128> = &debug(
+            &Generic {
+                left: 3u8,
+                right: Some(Generic {
+                    left: "foo",
+                    right: None::<char>,
+                    count: 0,
+                }),
+                count: 1,
+            },
+            NEW,
+        );
+        assert_eq!(
+            GENERIC.as_str(),
+            r#"Generic { left: 3, right: Some(Generic { left: "foo", right: None, count: 0 }), count: 1 }"#
+        );
+
+        let value = Generic {
+            left: 'a',
+            right: Some(5u16),
+            count: 2,
+        };
+        assert_eq!(
+            format!("{:?}", value),
+            "Generic { left: 'a', right: Some(5), count: 2 }"
+        );
+
+        const ENUMS: &ConstString<128> = &debug(
+            &[
+                GenericEnum::Unit,
+                GenericEnum::Tuple(Point { x: 1, y: 2 }, 3),
+                GenericEnum::Braced {
+                    list: [Point { x: 4, y: 5 }, Point { x: 6, y: 7 }],
+                },
+            ],
+            NEW,
+        );
+        assert_eq!(
+            ENUMS.as_str(),
+            "[Unit, Tuple(Point { x: 1, y: 2 }, 3), \
+             Braced { list: [Point { x: 4, y: 5 }, Point { x: 6, y: 7 }] }]"
+        );
+    }
+}
@@ -137,7 +137,7 @@ fn write_str_methods() {
 
     let expected = "\
         ello\n;;\nABCD\n;;\x1F bar;;what\0the;;----;;\
-        \"ello\\n\";;\"\\nABCD\\n\";;\"\\x1F bar\";;\"what\\x00the\";;\
+        \"ello\\n\";;\"\\nABCD\\n\";;\"\\u{1f} bar\";;\"what\\0the\";;\
     ";
 
     write_with_flag(FormattingFlags::NEW, expected, &inner);
@@ -1,7 +1,7 @@
 use super::{remove_margin, write_with_flag};
 
 use cfmt_a::{
-    fmt::{Error, Formatter, FormattingFlags},
+    fmt::{ComputeStrLength, Error, Formatter, FormattingFlags},
     impl_fmt, try_, PWrapper,
 };
 
@@ -327,3 +327,706 @@ fn formatting_set() {
         set.const_debug_fmt(&mut fmt).unwrap();
     })
 }
+
+////////////////////////////////////////////////////////////////////////////////
+
+struct Map {
+    a: u32,
+    b: &'static [u32],
+    rec: Option<&'static Map>,
+}
+
+impl_fmt! {
+    impl Map;
+
+    pub const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut fmt = fmt.debug_map();
+        {
+            let mut fmt = fmt.entry("margin");
+            let margin = fmt.margin();
+            try_!(fmt.write_usize_display(margin));
+        }
+        try_!(fmt.key().write_u32_debug(self.a));
+        try_!(fmt.value().write_str_debug("a"));
+        try_!(format_b_field(self.b, fmt.key()));
+        try_!(fmt.value().write_str_debug("b"));
+        if let Some(x) = self.rec {
+            try_!(fmt.key().write_str_debug("rec"));
+            try_!(x.const_debug_fmt(fmt.value()));
+        }
+        fmt.finish()
+    }
+}
+
+#[test]
+fn formatting_map() {
+    let expected = remove_margin(
+        r#"
+        {
+            "margin": 4,
+            3: "a",
+            [
+                0x9,
+                0xC,
+                0xF,
+            ]: "b",
+            "rec": {
+                "margin": 8,
+                8: "a",
+                [A, E, 12]: "b",
+                "rec": {
+                    "margin": 12,
+                    21: "a",
+                    [
+                        0xF,
+                        0x14,
+                        0x19,
+                    ]: "b",
+                },
+            },
+        }"#,
+    );
+
+    let map = Map {
+        a: 3,
+        b: &[9, 12, 15],
+        rec: Some(&Map {
+            a: 8,
+            b: &[10, 14, 18],
+            rec: Some(&Map {
+                a: 21,
+                b: &[15, 20, 25],
+                rec: None,
+            }),
+        }),
+    };
+
+    let flags = FormattingFlags::NEW.set_alternate(true);
+
+    write_with_flag(flags, &expected, &|mut fmt| {
+        map.const_debug_fmt(&mut fmt).unwrap();
+    })
+}
+
+struct Table(&'static [(&'static str, &'static [u8])]);
+
+impl_fmt! {
+    impl Table;
+
+    pub const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut fmt = fmt.debug_map();
+        let mut i = 0;
+        while i < self.0.len() {
+            let (key, value) = self.0[i];
+            try_!(PWrapper(value).const_debug_fmt(fmt.entry(key)));
+            i += 1;
+        }
+        fmt.finish()
+    }
+}
+
+impl std::fmt::Debug for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.0.iter().copied()).finish()
+    }
+}
+
+#[test]
+fn formatting_map_like_std() {
+    let tables = [
+        Table(&[]),
+        Table(&[("foo", &[])]),
+        Table(&[("foo", &[3]), ("bar\n", &[5, 8])]),
+    ];
+
+    for table in &tables {
+        write_with_flag(FormattingFlags::NEW, &format!("{:?}", table), &|mut fmt| {
+            table.const_debug_fmt(&mut fmt).unwrap();
+        });
+
+        let flags = FormattingFlags::NEW.set_alternate(true);
+        write_with_flag(flags, &format!("{:#?}", table), &|mut fmt| {
+            table.const_debug_fmt(&mut fmt).unwrap();
+        });
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+struct NonExhaustive {
+    fields: &'static [u32],
+    tupled: bool,
+    rec: Option<&'static NonExhaustive>,
+}
+
+impl_fmt! {
+    impl NonExhaustive;
+
+    pub const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut i = 0;
+        if self.tupled {
+            let mut fmt = fmt.debug_tuple("Tupled");
+            while i < self.fields.len() {
+                try_!(fmt.field().write_u32_debug(self.fields[i]));
+                i += 1;
+            }
+            if let Some(x) = self.rec {
+                try_!(x.const_debug_fmt(fmt.field()));
+            }
+            fmt.finish_non_exhaustive()
+        } else {
+            let mut fmt = fmt.debug_struct("Braced");
+            while i < self.fields.len() {
+                try_!(fmt.field("f").write_u32_debug(self.fields[i]));
+                i += 1;
+            }
+            if let Some(x) = self.rec {
+                try_!(x.const_debug_fmt(fmt.field("rec")));
+            }
+            fmt.finish_non_exhaustive()
+        }
+    }
+}
+
+impl std::fmt::Debug for NonExhaustive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.tupled {
+            let mut f = f.debug_tuple("Tupled");
+            for x in self.fields {
+                f.field(x);
+            }
+            if let Some(x) = self.rec {
+                f.field(x);
+            }
+            f.finish_non_exhaustive()
+        } else {
+            let mut f = f.debug_struct("Braced");
+            for x in self.fields {
+                f.field("f", x);
+            }
+            if let Some(x) = self.rec {
+                f.field("rec", x);
+            }
+            f.finish_non_exhaustive()
+        }
+    }
+}
+
+#[test]
+fn formatting_non_exhaustive_like_std() {
+    static INNER: [NonExhaustive; 2] = [
+        NonExhaustive {
+            fields: &[13],
+            tupled: false,
+            rec: None,
+        },
+        NonExhaustive {
+            fields: &[13],
+            tupled: true,
+            rec: None,
+        },
+    ];
+
+    for &tupled in &[false, true] {
+        for &fields in &[&[][..], &[3], &[5, 8]] {
+            for rec in [None, Some(&INNER[tupled as usize])] {
+                let value = NonExhaustive {
+                    fields,
+                    tupled,
+                    rec,
+                };
+
+                write_with_flag(FormattingFlags::NEW, &format!("{:?}", value), &|mut fmt| {
+                    value.const_debug_fmt(&mut fmt).unwrap();
+                });
+
+                let flags = FormattingFlags::NEW.set_alternate(true);
+                write_with_flag(flags, &format!("{:#?}", value), &|mut fmt| {
+                    value.const_debug_fmt(&mut fmt).unwrap();
+                });
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+struct Node {
+    values: &'static [u32],
+    children: &'static [Node],
+}
+
+impl_fmt! {
+    impl Node;
+
+    pub const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut fmt = fmt.debug_struct("Node");
+        try_!(PWrapper(self.values).const_debug_fmt(fmt.field("values")));
+        {
+            let mut fmt = fmt.field("children").debug_list();
+            let mut i = 0;
+            while i < self.children.len() {
+                try_!(self.children[i].const_debug_fmt(fmt.entry()));
+                i += 1;
+            }
+            try_!(fmt.finish());
+        }
+        fmt.finish()
+    }
+}
+
+const NODE: Node = Node {
+    values: &[1, 2, 3],
+    children: &[
+        Node {
+            values: &[4],
+            children: &[],
+        },
+        Node {
+            values: &[],
+            children: &[Node {
+                values: &[5, 6],
+                children: &[],
+            }],
+        },
+    ],
+};
+
+#[test]
+fn formatting_debug_limits() {
+    let cases: &[(usize, usize, &str)] = &[
+        (
+            usize::MAX,
+            usize::MAX,
+            "Node { values: [1, 2, 3], children: [Node { values: [4], children: [] }, \
+             Node { values: [], children: [Node { values: [5, 6], children: [] }] }] }",
+        ),
+        (0, usize::MAX, ".."),
+        (1, usize::MAX, "Node { values: .., children: .. }"),
+        (2, usize::MAX, "Node { values: [1, 2, 3], children: [.., ..] }"),
+        (
+            3,
+            usize::MAX,
+            "Node { values: [1, 2, 3], children: [Node { values: .., children: .. }, \
+             Node { values: .., children: .. }] }",
+        ),
+        (
+            usize::MAX,
+            1,
+            "Node { values: [1, ...], children: [Node { values: [4], children: [] }, ...] }",
+        ),
+        (usize::MAX, 0, "Node { values: [...], children: [...] }"),
+        (3, 2, "Node { values: [1, 2, ...], children: [Node { values: .., children: .. }, Node { values: .., children: .. }] }"),
+    ];
+
+    for &(max_depth, max_entries, expected) in cases {
+        let flags = FormattingFlags::NEW
+            .set_max_depth(max_depth)
+            .set_max_entries(max_entries);
+
+        write_with_flag(flags, expected, &|mut fmt| {
+            NODE.const_debug_fmt(&mut fmt).unwrap();
+        });
+    }
+
+    let expected = remove_margin(
+        "
+        Node {
+            values: [
+                1,
+                ...
+            ],
+            children: [
+                ..,
+                ...
+            ],
+        }",
+    );
+    let flags = FormattingFlags::NEW
+        .set_alternate(true)
+        .set_max_depth(2)
+        .set_max_entries(1);
+
+    write_with_flag(flags, &expected, &|mut fmt| {
+        NODE.const_debug_fmt(&mut fmt).unwrap();
+    });
+}
+
+#[test]
+fn formatting_map_debug_limits() {
+    const TABLE: Table = Table(&[("foo", &[3]), ("bar", &[5, 8]), ("baz", &[])]);
+
+    let cases: &[(usize, usize, &str)] = &[
+        (usize::MAX, 2, r#"{"foo": [3], "bar": [5, 8], ...}"#),
+        (usize::MAX, 0, "{...}"),
+        (1, usize::MAX, r#"{"foo": .., "bar": .., "baz": ..}"#),
+    ];
+
+    for &(max_depth, max_entries, expected) in cases {
+        let flags = FormattingFlags::NEW
+            .set_max_depth(max_depth)
+            .set_max_entries(max_entries);
+
+        write_with_flag(flags, expected, &|mut fmt| {
+            TABLE.const_debug_fmt(&mut fmt).unwrap();
+        });
+    }
+
+    let expected = remove_margin(
+        r#"
+        {
+            "foo": [
+                3,
+            ],
+            ...
+        }"#,
+    );
+    let flags = FormattingFlags::NEW.set_alternate(true).set_max_entries(1);
+
+    write_with_flag(flags, &expected, &|mut fmt| {
+        TABLE.const_debug_fmt(&mut fmt).unwrap();
+    });
+}
+
+#[test]
+fn formatting_line_width() {
+    let cases: &[(usize, &str)] = &[
+        (
+            50,
+            "
+            Node {
+                values: [1, 2, 3],
+                children: [
+                    Node { values: [4], children: [] },
+                    Node {
+                        values: [],
+                        children: [
+                            Node {
+                                values: [5, 6],
+                                children: [],
+                            },
+                        ],
+                    },
+                ],
+            }",
+        ),
+        (
+            80,
+            "
+            Node {
+                values: [1, 2, 3],
+                children: [
+                    Node { values: [4], children: [] },
+                    Node { values: [], children: [Node { values: [5, 6], children: [] }] },
+                ],
+            }",
+        ),
+    ];
+
+    for &(line_width, expected) in cases {
+        let flags = FormattingFlags::NEW
+            .set_alternate(true)
+            .set_line_width(line_width);
+
+        write_with_flag(flags, &remove_margin(expected), &|mut fmt| {
+            NODE.const_debug_fmt(&mut fmt).unwrap();
+        });
+    }
+
+    let single_line = "Node { values: [1, 2, 3], children: [Node { values: [4], children: [] }, \
+                       Node { values: [], children: [Node { values: [5, 6], children: [] }] }] }";
+
+    for &line_width in &[single_line.len(), usize::MAX] {
+        let flags = FormattingFlags::NEW
+            .set_alternate(true)
+            .set_line_width(line_width);
+
+        write_with_flag(flags, single_line, &|mut fmt| {
+            NODE.const_debug_fmt(&mut fmt).unwrap();
+        });
+    }
+
+    let expected = remove_margin(
+        "
+        Node {
+            values: [1, 2, 3],
+            children: [Node { values: [4], children: [] }, \
+                       Node { values: [], children: [Node { values: [5, 6], children: [] }] }],
+        }",
+    );
+    let flags = FormattingFlags::NEW
+        .set_alternate(true)
+        .set_line_width(single_line.len() - 1);
+
+    write_with_flag(flags, &expected, &|mut fmt| {
+        NODE.const_debug_fmt(&mut fmt).unwrap();
+    });
+}
+
+struct WrapsNode(&'static Node);
+
+impl_fmt! {
+    impl WrapsNode;
+
+    pub const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut fmt = fmt.debug_tuple("WrapsNode");
+        {
+            let fmt = fmt.field();
+            let flags = fmt.flags();
+            try_!(self.0.const_debug_fmt(&mut fmt.make_formatter(flags)));
+        }
+        fmt.finish()
+    }
+}
+
+#[test]
+fn formatting_line_width_make_formatter() {
+    const WRAPPED: WrapsNode = WrapsNode(&Node {
+        values: &[3],
+        children: &[Node {
+            values: &[5, 8],
+            children: &[],
+        }],
+    });
+
+    // The field is formatted with a Formatter from `make_formatter`,
+    // `WrapsNode` must not be written in a single line if the field isn't.
+    let expected = remove_margin(
+        "
+        WrapsNode(
+            Node {
+                values: [3],
+                children: [
+                    Node { values: [5, 8], children: [] },
+                ],
+            },
+        )",
+    );
+    let flags = FormattingFlags::NEW.set_alternate(true).set_line_width(50);
+
+    write_with_flag(flags, &expected, &|mut fmt| {
+        WRAPPED.const_debug_fmt(&mut fmt).unwrap();
+    });
+
+    let expected =
+        "WrapsNode(Node { values: [3], children: [Node { values: [5, 8], children: [] }] })";
+    let flags = FormattingFlags::NEW.set_alternate(true).set_line_width(100);
+
+    write_with_flag(flags, expected, &|mut fmt| {
+        WRAPPED.const_debug_fmt(&mut fmt).unwrap();
+    });
+}
+
+#[test]
+fn line_width_required_capacity() {
+    use cfmt_a::fmt::ComputeStrLength;
+
+    let flags = FormattingFlags::NEW.set_alternate(true).set_line_width(80);
+
+    let mut computer = ComputeStrLength::new();
+    NODE.const_debug_fmt(&mut computer.make_formatter(flags))
+        .unwrap();
+    assert!(computer.len() < computer.required_capacity());
+
+    let mut buffer = vec![0; computer.required_capacity()];
+    let mut len = 0;
+    NODE.const_debug_fmt(&mut Formatter::from_custom(&mut buffer, &mut len, flags))
+        .unwrap();
+    assert_eq!(len, computer.len());
+
+    let mut buffer = vec![0; computer.required_capacity() - 1];
+    let mut len = 0;
+    NODE.const_debug_fmt(&mut Formatter::from_custom(&mut buffer, &mut len, flags))
+        .unwrap_err();
+}
+
+#[test]
+fn formatting_truncating() {
+    for flags in [
+        FormattingFlags::NEW,
+        FormattingFlags::NEW.set_alternate(true),
+    ] {
+        let mut computer = ComputeStrLength::new();
+        NODE.const_debug_fmt(&mut computer.make_formatter(flags))
+            .unwrap();
+
+        let mut full = vec![0; computer.required_capacity()];
+        let mut len = 0;
+        NODE.const_debug_fmt(&mut Formatter::from_custom(&mut full, &mut len, flags))
+            .unwrap();
+        let full = std::str::from_utf8(&full[..len]).unwrap();
+
+        for cap in 0..computer.required_capacity() {
+            let mut buffer = vec![0; cap];
+            let mut len = 0;
+            let mut fmt = Formatter::from_custom(&mut buffer, &mut len, flags).truncating("…");
+            NODE.const_debug_fmt(&mut fmt).unwrap();
+
+            let fits = !fmt.is_truncated();
+            let found = std::str::from_utf8(&buffer[..len]).unwrap();
+            if fits {
+                assert_eq!(found, full);
+            } else if let Some(found) = found.strip_suffix('…') {
+                assert!(full.starts_with(found), "\n{}\n{}\n", found, full);
+                assert!(found.len() + '…'.len_utf8() <= cap);
+            } else {
+                assert!(cap < '…'.len_utf8() && found.is_empty(), "{:?}", found);
+            }
+        }
+    }
+}
+
+#[test]
+fn formatting_truncating_padded() {
+    let mut buffer = [0; 16];
+    let mut len = 0;
+    let flags = FormattingFlags::NEW.set_width(20).set_fill('·');
+    let mut fmt = Formatter::from_custom(&mut buffer, &mut len, flags).truncating("~");
+
+    PWrapper("hello").const_display_fmt(&mut fmt).unwrap();
+    assert!(fmt.is_truncated());
+    assert_eq!(std::str::from_utf8(&buffer[..len]).unwrap(), "hello·····~");
+}
+
+struct Rolled {
+    a: &'static [u32],
+    b: u32,
+}
+
+impl_fmt! {
+    impl Rolled;
+
+    pub const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut fmt = fmt.debug_struct("Rolled");
+        {
+            let fmt = fmt.field("a");
+            let checkpoint = fmt.checkpoint();
+            try_!(PWrapper(self.a).const_debug_fmt(fmt));
+            fmt.rollback(checkpoint);
+            try_!(fmt.write_str(".."));
+        }
+        try_!(fmt.field("b").write_u32_debug(self.b));
+        fmt.finish()
+    }
+}
+
+#[test]
+fn formatting_checkpoint_rollback() {
+    const ROLLED: Rolled = Rolled {
+        a: &[3, 5, 8],
+        b: 13,
+    };
+
+    write_with_flag(
+        FormattingFlags::NEW,
+        "Rolled { a: .., b: 13 }",
+        &|mut fmt| {
+            ROLLED.const_debug_fmt(&mut fmt).unwrap();
+        },
+    );
+
+    let expected = remove_margin(
+        "
+        Rolled {
+            a: ..,
+            b: 13,
+        }",
+    );
+    let flags = FormattingFlags::NEW.set_alternate(true);
+    write_with_flag(flags, &expected, &|mut fmt| {
+        ROLLED.const_debug_fmt(&mut fmt).unwrap();
+    });
+
+    // The rolled back list doesn't prevent writing the struct in a single line
+    let flags = FormattingFlags::NEW.set_alternate(true).set_line_width(40);
+    write_with_flag(flags, "Rolled { a: .., b: 13 }", &|mut fmt| {
+        ROLLED.const_debug_fmt(&mut fmt).unwrap();
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+// Replaces the four space indentation of `expected` with `indent`.
+fn reindent(expected: &str, indent: &str) -> String {
+    expected
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_start_matches(' ');
+            indent.repeat((line.len() - trimmed.len()) / 4) + trimmed
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn formatting_indent() {
+    let node_and_set = remove_margin(
+        "
+        {
+            Node {
+                values: [
+                    5,
+                    6,
+                ],
+                children: [],
+            },
+            3,
+        }\
+        ",
+    );
+
+    let non_exhaustive = NonExhaustive {
+        fields: &[3, 5],
+        tupled: true,
+        rec: Some(&NonExhaustive {
+            fields: &[8],
+            tupled: false,
+            rec: None,
+        }),
+    };
+    let table = Table(&[("foo", &[3]), ("bar", &[5, 8])]);
+
+    for &indent in &["", " ", "  ", "\t", "    ", "--->"] {
+        let flags = FormattingFlags::NEW.set_alternate(true).set_indent(indent);
+        assert_eq!(flags.indent(), indent);
+
+        write_with_flag(flags, &reindent(&node_and_set, indent), &|mut fmt| {
+            let mut fmt = fmt.debug_set();
+            NODE.children[1].children[0]
+                .const_debug_fmt(fmt.entry())
+                .unwrap();
+            fmt.entry().write_u32_debug(3).unwrap();
+            fmt.finish().unwrap();
+        });
+
+        let expected = reindent(&format!("{:#?}", non_exhaustive), indent);
+        write_with_flag(flags, &expected, &|mut fmt| {
+            non_exhaustive.const_debug_fmt(&mut fmt).unwrap();
+        });
+
+        let expected = reindent(&format!("{:#?}", table), indent);
+        write_with_flag(flags, &expected, &|mut fmt| {
+            table.const_debug_fmt(&mut fmt).unwrap();
+        });
+
+        let expected = format!("(\n{}{},\n)", indent, indent.len());
+        write_with_flag(flags, &expected, &|mut fmt| {
+            let mut fmt = fmt.debug_tuple("");
+            {
+                let fmt = fmt.field();
+                let margin = fmt.margin();
+                fmt.write_usize_display(margin).unwrap();
+            }
+            fmt.finish().unwrap();
+        });
+
+        // The indentation doesn't affect how data structures are printed in a single line
+        let single_line = flags.set_line_width(usize::MAX);
+        write_with_flag(single_line, &format!("{:?}", non_exhaustive), &|mut fmt| {
+            non_exhaustive.const_debug_fmt(&mut fmt).unwrap();
+        });
+        write_with_flag(single_line, &format!("{:?}", table), &|mut fmt| {
+            table.const_debug_fmt(&mut fmt).unwrap();
+        });
+    }
+}
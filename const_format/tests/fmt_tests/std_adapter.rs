@@ -0,0 +1,174 @@
+use cfmt_a::{
+    as_std_fmt,
+    fmt::{ConstFmtAdapter, Error, Formatter},
+    impl_fmt, try_,
+};
+
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+impl_fmt! {
+    impl Point;
+
+    const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut f = f.debug_struct("Point");
+        try_!(f.field("x").write_u32_debug(self.x));
+        try_!(f.field("y").write_u32_debug(self.y));
+        f.finish()
+    }
+
+    const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        try_!(f.write_u32_display(self.x));
+        try_!(f.write_str(", "));
+        f.write_u32_display(self.y)
+    }
+}
+
+const POINT: Point = Point { x: 3, y: 10 };
+
+#[test]
+fn display_and_debug() {
+    assert_eq!(format!("{}", as_std_fmt!(POINT)), "3, 10");
+    assert_eq!(format!("{:?}", as_std_fmt!(POINT)), "Point { x: 3, y: 10 }");
+    assert_eq!(
+        format!("{:#?}", as_std_fmt!(POINT)),
+        "Point {\n    x: 3,\n    y: 10,\n}",
+    );
+
+    assert_eq!(format!("{}", as_std_fmt!(display, POINT)), "3, 10");
+    assert_eq!(format!("{:?}", as_std_fmt!(display, POINT)), "3, 10");
+    assert_eq!(
+        format!("{}", as_std_fmt!(debug, POINT)),
+        "Point { x: 3, y: 10 }"
+    );
+}
+
+#[test]
+fn integer_radixes() {
+    assert_eq!(format!("{:x}", as_std_fmt!(POINT)), "Point { x: 3, y: a }");
+    assert_eq!(format!("{:X}", as_std_fmt!(POINT)), "Point { x: 3, y: A }");
+    assert_eq!(
+        format!("{:b}", as_std_fmt!(POINT)),
+        "Point { x: 11, y: 1010 }"
+    );
+    assert_eq!(
+        format!("{:#b}", as_std_fmt!(POINT)),
+        "Point {\n    x: 0b11,\n    y: 0b1010,\n}",
+    );
+}
+
+#[test]
+fn width_fill_alignment() {
+    assert_eq!(format!("{:>5}", as_std_fmt!(debug, 13u8)), "   13");
+    assert_eq!(format!("{:*<5}", as_std_fmt!(debug, 13u8)), "13***");
+    assert_eq!(format!("{:^6}", as_std_fmt!(display, "ab")), "  ab  ");
+    assert_eq!(format!("{:_>4}", as_std_fmt!(display, "ab")), "__ab");
+}
+
+#[test]
+fn chunked_output() {
+    let array = [0u32; 300];
+    let string = "h\u{e9}llo\t\"w\u{f6}rld\" \u{1F600}\u{301}\\".repeat(20);
+    let bytes = string.as_bytes();
+
+    assert_eq!(
+        format!("{:?}", as_std_fmt!(debug, array)),
+        format!("{:?}", array)
+    );
+    assert_eq!(
+        format!("{:#x}", as_std_fmt!(debug, array)),
+        format!("{:#x?}", array)
+    );
+    assert_eq!(
+        format!("{:\u{e9}^1001}", as_std_fmt!(display, "ab")),
+        format!("{:\u{e9}^1001}", "ab")
+    );
+
+    let hexdump = ConstFmtAdapter::from_debug(bytes, |b, f| f.write_bytes_hexdump(b));
+    let hexdump = format!("{:?}", hexdump.with_capacity::<4096>());
+    assert_eq!(hexdump.lines().count(), bytes.len().div_ceil(16));
+
+    macro_rules! with_capacities {
+        ($($capacity:literal)*) => {$(
+            assert_eq!(
+                format!("{:?}", as_std_fmt!(debug, array).with_capacity::<$capacity>()),
+                format!("{:?}", array),
+            );
+            assert_eq!(
+                format!("{:#?}", as_std_fmt!(debug, array).with_capacity::<$capacity>()),
+                format!("{:#?}", array),
+            );
+            assert_eq!(
+                format!("{:?}", as_std_fmt!(debug, string.as_str()).with_capacity::<$capacity>()),
+                format!("{:?}", string),
+            );
+            assert_eq!(
+                format!("{}", as_std_fmt!(display, string.as_str()).with_capacity::<$capacity>()),
+                string,
+            );
+            assert_eq!(
+                format!("{:?}", as_std_fmt!(debug, bytes).with_capacity::<$capacity>()),
+                format!("{:?}", bytes),
+            );
+            assert_eq!(
+                format!(
+                    "{:?}",
+                    ConstFmtAdapter::from_debug(bytes, |b, f| f.write_bytes_debug(b))
+                        .with_capacity::<$capacity>()
+                ),
+                format!("b\"{}\"", bytes.escape_ascii()),
+            );
+            assert_eq!(
+                format!(
+                    "{:?}",
+                    ConstFmtAdapter::from_debug(bytes, |b, f| f.write_bytes_hexdump(b))
+                        .with_capacity::<$capacity>()
+                ),
+                hexdump,
+            );
+            assert_eq!(
+                format!("{:b}", as_std_fmt!(debug, u128::MAX).with_capacity::<$capacity>()),
+                format!("{:b}", u128::MAX),
+            );
+        )*};
+    }
+
+    with_capacities! {4 5 6 7 8 13 16 31 64 512}
+}
+
+#[test]
+fn buffer_too_small_for_char() {
+    use std::fmt::Write;
+
+    let mut out = String::new();
+    let adapter = as_std_fmt!(display, "ab\u{20AC}").with_capacity::<2>();
+    if cfg!(feature = "alloc") {
+        // The output is written from a heap-allocated buffer
+        write!(out, "{}", adapter).unwrap();
+        assert_eq!(out, "ab\u{20AC}");
+    } else {
+        assert!(write!(out, "{}", adapter).is_err());
+        assert_eq!(out, "ab");
+    }
+
+    assert_eq!(
+        format!("{:?}", as_std_fmt!(debug, [0u8; 8]).with_capacity::<1>()),
+        "[0, 0, 0, 0, 0, 0, 0, 0]",
+    );
+}
+
+#[test]
+fn custom_functions() {
+    let value = 5u8;
+    let adapter = ConstFmtAdapter::new(
+        &value,
+        |_, f| f.write_str("five"),
+        |n, f| f.write_u8_debug(*n),
+    );
+
+    assert_eq!(format!("{}", adapter), "five");
+    assert_eq!(format!("{:?}", adapter), "5");
+    assert_eq!(format!("{:#x}", adapter), "0x5");
+}
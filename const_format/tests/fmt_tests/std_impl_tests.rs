@@ -1,6 +1,6 @@
 use cfmt_a::{
     coerce_to_fmt,
-    fmt::{ComputeStrLength, Error, FormattingFlags, StrWriter},
+    fmt::{Alignment, ComputeStrLength, Error, FormattingFlags, StrWriter},
     try_,
     wrapper_types::PWrapper,
 };
@@ -74,10 +74,10 @@ fn array_impls() {
     test_fmt! {&[char];
         (
             ['f', 'o', '\n', '\t', 'ñ', '个', '\u{100000}'],
-            "['f', 'o', '\\n', '\\t', 'ñ', '个', '\u{100000}']",
+            "['f', 'o', '\\n', '\\t', 'ñ', '个', '\\u{100000}']",
             "[\n    'f',\n    'o',\n    '\\n',\
               \n    '\\t',\n    'ñ',\n    '个',\
-              \n    '\u{100000}',\n\
+              \n    '\\u{100000}',\n\
             ]"
         )
     }
@@ -111,7 +111,7 @@ fn options() {
     test_fmt! {Option<char>;
         (None::<char>, "None", "None")
         (Some('4'), "Some('4')", "Some(\n    '4',\n)")
-        (Some('\x00'), "Some('\\x00')", "Some(\n    '\\x00',\n)")
+        (Some('\x00'), "Some('\\0')", "Some(\n    '\\0',\n)")
     }
     test_fmt! {Option<NonZeroU8>;
         (None::<NonZeroU8>, "None", "None")
@@ -158,14 +158,111 @@ fn chars() {
         char;
         ('\\', r#"'\\'"#, r#"'\\'"#)
         ('\'', r#"'\''"#, r#"'\''"#)
-        ('\"', r#"'\"'"#, r#"'\"'"#)
+        ('\"', r#"'"'"#, r#"'"'"#)
         ('\n', r#"'\n'"#, r#"'\n'"#)
         ('\r', r#"'\r'"#, r#"'\r'"#)
         ('\t', r#"'\t'"#, r#"'\t'"#)
         ('o', r#"'o'"#, r#"'o'"#)
         ('ñ', r#"'ñ'"#, r#"'ñ'"#)
         ('个', r#"'个'"#, r#"'个'"#)
-        // non-ascii characters are always written unescaped
-        ('\u{100000}', "'\u{100000}'", "'\u{100000}'")
+        ('\0', r#"'\0'"#, r#"'\0'"#)
+        ('\x7F', r#"'\u{7f}'"#, r#"'\u{7f}'"#)
+        // non-ascii characters are escaped the same way that std does it
+        ('\u{A0}', r#"'\u{a0}'"#, r#"'\u{a0}'"#)
+        ('\u{301}', r#"'\u{301}'"#, r#"'\u{301}'"#)
+        ('\u{100000}', r#"'\u{100000}'"#, r#"'\u{100000}'"#)
+    }
+}
+
+macro_rules! padded {
+    ($method:ident, $value:expr, $flags:expr) => {{
+        let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+        coerce_to_fmt!(&$value)
+            .$method(&mut writer.make_formatter($flags))
+            .unwrap();
+
+        let mut str_len = ComputeStrLength::new();
+        coerce_to_fmt!(&$value)
+            .$method(&mut str_len.make_formatter($flags))
+            .unwrap();
+        assert_eq!(writer.len(), str_len.len(), "{}", writer.as_str());
+
+        writer.as_str().to_owned()
+    }};
+}
+
+#[test]
+fn padding() {
+    let flags = FormattingFlags::NEW.set_width(6);
+    let left = flags.set_alignment(Alignment::Left);
+    let center = flags.set_fill('ñ').set_alignment(Alignment::Center);
+    let right = flags.set_fill('-').set_alignment(Alignment::Right);
+    let hex = FormattingFlags::NEW
+        .set_width(8)
+        .set_alternate(true)
+        .set_lower_hexadecimal();
+
+    for &n in &[0i32, 7, -13, 12345, -123456, -1234567] {
+        assert_eq!(padded!(const_display_fmt, n, flags), format!("{:6}", n));
+        assert_eq!(padded!(const_display_fmt, n, left), format!("{:<6}", n));
+        assert_eq!(padded!(const_display_fmt, n, center), format!("{:ñ^6}", n));
+        assert_eq!(padded!(const_debug_fmt, n, right), format!("{:->6?}", n));
+        assert_eq!(
+            padded!(const_debug_fmt, n as u32, hex),
+            format!("{:#8x?}", n as u32)
+        );
+    }
+
+    for &s in &["", "ab", "ñandú", "abcdefgh"] {
+        assert_eq!(padded!(const_display_fmt, s, flags), format!("{:6}", s));
+        assert_eq!(padded!(const_display_fmt, s, center), format!("{:ñ^6}", s));
+        assert_eq!(padded!(const_display_fmt, s, right), format!("{:->6}", s));
+        // Like std, Debug formatting of strings isn't padded
+        assert_eq!(padded!(const_debug_fmt, s, flags), format!("{:6?}", s));
+    }
+
+    for &b in &[false, true] {
+        assert_eq!(padded!(const_display_fmt, b, flags), format!("{:6}", b));
+        assert_eq!(padded!(const_debug_fmt, b, right), format!("{:->6?}", b));
+    }
+
+    for &c in &['a', 'ñ', '个'] {
+        assert_eq!(padded!(const_display_fmt, c, flags), format!("{:6}", c));
+        assert_eq!(padded!(const_display_fmt, c, center), format!("{:ñ^6}", c));
+        // Like std, Debug formatting of chars isn't padded
+        assert_eq!(padded!(const_debug_fmt, c, right), format!("{:->6?}", c));
+    }
+
+    // Padding is applied to the elements of data structures, like std does
+    let array = [3u8, 10];
+    assert_eq!(
+        padded!(const_debug_fmt, array, right),
+        format!("{:->6?}", array)
+    );
+}
+
+#[test]
+fn padding_not_enough_space() {
+    let flags = FormattingFlags::NEW.set_width(6);
+
+    for &(value, fits) in &[("", 6), ("ab", 6), ("abcdefgh", 8)] {
+        let writer: &mut StrWriter = &mut StrWriter::new([0; 8]);
+        writer
+            .as_mut()
+            .write_str(&"-".repeat(8 - fits + 1))
+            .unwrap();
+
+        let mut fmt = writer.make_formatter(flags);
+        assert_eq!(fmt.pad(value), Err(Error::NotEnoughSpace));
+        assert_eq!(fmt.not_enough_space().map(|e| e.needed), Some(9));
+        // Nothing is written when there isn't enough space for the padded value
+        assert_eq!(writer.as_str(), "-".repeat(8 - fits + 1));
+
+        writer.truncate(8 - fits).unwrap();
+        writer.make_formatter(flags).pad(value).unwrap();
+        assert_eq!(
+            writer.as_str(),
+            format!("{}{:6}", "-".repeat(8 - fits), value)
+        );
     }
 }
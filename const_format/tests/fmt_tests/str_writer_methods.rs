@@ -3,7 +3,7 @@ use crate::RngExt;
 use cfmt_a::{
     fmt::{Error, FormattingFlags, StrWriter, StrWriterMut},
     formatcp,
-    test_utils::{ALL_ASCII, ALL_ASCII_ESCAPED},
+    test_utils::{std_debug_changed, ALL_ASCII, ALL_ASCII_ESCAPED},
     utils::saturate_range,
     wrapper_types::{AsciiStr, PWrapper},
 };
@@ -132,7 +132,7 @@ fn test_write_ints() {
 fn basic() {
     assert_eq!(
         formatcp!("{:?}", r#" !Aq¡\"🧡🧠₀₁ "#),
-        r#"" !Aq¡\\\"🧡🧠₀₁ ""#
+        r#"" !Aq¡\\\"\u{7f}\u{80}🧡🧠₀₁ ""#
     );
 }
 
@@ -275,7 +275,14 @@ fn write_ascii() {
 }
 
 fn is_it_escaped(c: char) -> bool {
-    matches!(c, '\0'..='\u{1F}' | '\\' | '"' | '\'')
+    // Conservatively treating the chars that the std escapes differently
+    // in older Rust versions as escaped, so that they're never expected to be written as is.
+    if std_debug_changed(c) {
+        return true;
+    }
+
+    let mut buffer = [0u8; 4];
+    format!("{:?}", &*c.encode_utf8(&mut buffer)).len() != c.len_utf8() + 2
 }
 
 #[test]
@@ -316,7 +323,7 @@ fn write_str_debug() {
 
         writer.truncate(snapshot).unwrap();
         writer.write_ascii_debug(all_ascii).unwrap();
-        let end = ALL_ASCII_ESCAPED.find('\u{80}').unwrap();
+        let end = ALL_ASCII_ESCAPED.find("\\u{80}").unwrap();
 
         let bytes = writer.as_bytes();
         assert_eq!(bytes[0], b'"');
@@ -412,11 +419,13 @@ fn returns_error() {
 
     test_case!("foo\nb", 1);
     test_case!("foo\"ba", 1);
-    test_case!("foo\'bar", 1);
+    test_case!("foo\'bar", 0);
     test_case!("foo\rbarb", 1);
     test_case!("foo\\barba", 1);
-    test_case!("foo\u{5}bar", 3);
-    test_case!("foo\u{11}bar", 3);
+    test_case!("foo\u{5}bar", 4);
+    test_case!("foo\u{11}bar", 5);
+    test_case!("foo\u{301}bar", 5);
+    test_case!("foo\u{10FFFF}bar", 6);
 }
 
 #[test]
@@ -570,3 +579,119 @@ fn write_ascii_debug() {
         p.writer.write_ascii_debug(ascii)
     });
 }
+
+fn std_bytes_debug(bytes: &[u8]) -> String {
+    format!("b\"{}\"", bytes.escape_ascii())
+}
+
+fn std_hexdump(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    for (i, line) in bytes.chunks(16).enumerate() {
+        if i != 0 {
+            out.push('\n');
+        }
+        write!(out, "{:08x} ", i * 16).unwrap();
+        for j in 0..16 {
+            out.push(' ');
+            if j == 8 {
+                out.push(' ');
+            }
+            match line.get(j) {
+                Some(b) => write!(out, "{:02x}", b).unwrap(),
+                None => out.push_str("  "),
+            }
+        }
+        out.push_str("  |");
+        for &b in line {
+            out.push(if b.is_ascii_graphic() || b == b' ' {
+                b as char
+            } else {
+                '.'
+            });
+        }
+        out.push('|');
+    }
+    out
+}
+
+#[test]
+fn write_bytes_debug() {
+    let all_bytes = (0..=255u8).collect::<Vec<u8>>();
+    let rng = Rng::with_seed(1989152812982806979);
+
+    let mut inputs = vec![Vec::new(), all_bytes];
+    for len in 0..64 {
+        inputs.push((0..len).map(|_| rng.u8(..)).collect());
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 2048]);
+    for input in &inputs {
+        let expected = std_bytes_debug(input);
+
+        writer.clear();
+        writer.as_mut().write_bytes_debug(input).unwrap();
+        assert_eq!(writer.as_str(), expected);
+
+        let fmt_len = &mut cfmt_a::fmt::ComputeStrLength::new();
+        fmt_len
+            .make_formatter(FormattingFlags::NEW)
+            .write_bytes_debug(input)
+            .unwrap();
+        assert_eq!(fmt_len.len(), expected.len());
+    }
+}
+
+#[test]
+fn write_bytes_hexdump() {
+    let rng = Rng::with_seed(1989152812982806979);
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 4096]);
+    for len in (0..50).chain([255, 256, 257].iter().copied()) {
+        let input = (0..len).map(|_| rng.u8(..)).collect::<Vec<u8>>();
+        let expected = std_hexdump(&input);
+
+        writer.clear();
+        writer.as_mut().write_bytes_hexdump(&input).unwrap();
+        assert_eq!(writer.as_str(), expected, "len: {}", len);
+
+        let fmt_len = &mut cfmt_a::fmt::ComputeStrLength::new();
+        fmt_len
+            .make_formatter(FormattingFlags::NEW)
+            .write_bytes_hexdump(&input)
+            .unwrap();
+        assert_eq!(fmt_len.len(), expected.len());
+    }
+}
+
+#[test]
+fn bytes_methods_return_error() {
+    fn test_case(
+        input: &[u8],
+        expected: &str,
+        method: fn(&mut StrWriterMut<'_>, &[u8]) -> Result<(), Error>,
+    ) {
+        for cap in expected.len().saturating_sub(3)..=expected.len() + 1 {
+            let mut buffer = vec![0; cap + 1];
+            let mut len = 0;
+            let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+            writer.write_str("_").unwrap();
+
+            let res = method(&mut writer, input);
+            if cap >= expected.len() {
+                res.unwrap();
+                assert_eq!(&writer.as_str()[1..], expected);
+            } else {
+                assert_eq!(res, Err(Error::NotEnoughSpace));
+                assert_eq!(writer.as_str(), "_");
+            }
+        }
+    }
+
+    let inputs: &[&[u8]] = &[b"", b"foo", b"\xFF\0\n\"\'\\", &[0xAB; 40]];
+    for input in inputs {
+        test_case(input, &std_bytes_debug(input), |w, b| {
+            w.write_bytes_debug(b)
+        });
+        test_case(input, &std_hexdump(input), |w, b| w.write_bytes_hexdump(b));
+    }
+}
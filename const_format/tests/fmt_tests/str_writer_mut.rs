@@ -1,4 +1,4 @@
-use cfmt_a::fmt::{Error, StrWriterMut};
+use cfmt_a::fmt::{Error, FormattingFlags, NotEnoughSpaceError, StrWriter, StrWriterMut};
 
 #[test]
 fn from_custom() -> Result<(), Error> {
@@ -172,3 +172,377 @@ fn as_bytes() {
         assert_eq!(writer.remaining_capacity(), CAP - i - 1);
     }
 }
+
+#[test]
+fn truncating() {
+    fn truncated(cap: usize, marker: &'static str, f: impl Fn(&mut StrWriterMut<'_>)) -> String {
+        let mut buffer = [0; 64];
+        let mut len = 0;
+        let mut writer =
+            StrWriterMut::from_custom_cleared(&mut buffer[..cap], &mut len).truncating(marker);
+        f(&mut writer);
+        assert!(writer.is_truncated(), "{:?}", writer.as_str());
+        writer.as_str().to_string()
+    }
+
+    // Cutting strings at char boundaries
+    for (cap, expected) in [
+        (8, "ñañ..."),
+        (7, "ña..."),
+        (6, "ña..."),
+        (5, "ñ..."),
+        (4, "..."),
+    ] {
+        let out = truncated(cap, "...", |w| w.write_str("ñañaña").unwrap());
+        assert_eq!(out, expected, "cap: {}", cap);
+    }
+
+    // The marker is cut if it doesn't fit
+    assert_eq!(truncated(2, "…", |w| w.write_str("abc").unwrap()), "");
+    assert_eq!(truncated(3, "…", |w| w.write_str("abcd").unwrap()), "…");
+    assert_eq!(truncated(4, "…", |w| w.write_str("abcde").unwrap()), "a…");
+    assert_eq!(truncated(2, "<<>>", |w| w.write_str("abc").unwrap()), "<<");
+
+    assert_eq!(
+        truncated(8, "~", |w| w.write_ascii_repeated(b'-', 10).unwrap()),
+        "-------~"
+    );
+
+    // Escapes aren't cut in half
+    assert_eq!(
+        truncated(7, "~", |w| w.write_str_debug("ab\ncd").unwrap()),
+        r#""ab\nc~"#
+    );
+    assert_eq!(
+        truncated(6, "~", |w| w.write_str_debug("ab\ncd").unwrap()),
+        r#""ab\n~"#
+    );
+    assert_eq!(
+        truncated(5, "~", |w| w.write_str_debug("ab\ncd").unwrap()),
+        r#""ab~"#
+    );
+
+    // Numbers and byte strings are written whole or not at all
+    assert_eq!(
+        truncated(8, "~", |w| {
+            w.write_str("ab").unwrap();
+            w.write_u32_display(1234567).unwrap();
+        }),
+        "ab~"
+    );
+    assert_eq!(
+        truncated(8, "~", |w| {
+            w.write_str("ab").unwrap();
+            w.write_bytes_debug(b"abcd").unwrap();
+        }),
+        "ab~"
+    );
+
+    // Writes after truncation are ignored
+    assert_eq!(
+        truncated(6, "~", |w| {
+            w.write_str("abcdefgh").unwrap();
+            w.write_str("").unwrap();
+            w.write_u8_display(0).unwrap();
+        }),
+        "abcde~"
+    );
+}
+
+#[test]
+fn truncating_reset() {
+    let mut buffer = [0; 8];
+    let mut len = 0;
+    let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len).truncating("~");
+
+    writer.write_str("0123456789").unwrap();
+    assert!(writer.is_truncated());
+    assert_eq!(writer.as_str(), "0123456~");
+
+    writer.truncate(3).unwrap();
+    assert!(!writer.is_truncated());
+    writer.write_str("abcd").unwrap();
+    assert_eq!(writer.as_str(), "012abcd");
+
+    writer.write_str("ef").unwrap();
+    assert!(writer.is_truncated());
+    assert_eq!(writer.as_str(), "012abcd~");
+
+    writer.clear();
+    assert!(!writer.is_truncated());
+    writer.write_str("foo").unwrap();
+    assert_eq!(writer.as_str(), "foo");
+}
+
+#[test]
+fn truncating_reborrow() {
+    let mut buffer = [0; 8];
+    let mut len = 0;
+    let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len).truncating("~");
+
+    writer.reborrow().write_str("0123456789").unwrap();
+    assert!(writer.is_truncated());
+    assert_eq!(writer.as_str(), "0123456~");
+
+    writer.clear();
+    writer.reborrow().reborrow().write_str("abc").unwrap();
+    assert!(!writer.is_truncated());
+
+    let mut f = writer.make_formatter(FormattingFlags::NEW);
+    f.write_str("defghi").unwrap();
+    assert!(f.is_truncated());
+    assert!(writer.is_truncated());
+    assert_eq!(writer.as_str(), "abcdefg~");
+
+    // Not truncating
+    let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    assert_eq!(
+        writer.reborrow().write_str("0123456789"),
+        Err(Error::NotEnoughSpace)
+    );
+    assert!(!writer.is_truncated());
+    assert_eq!(writer.as_str(), "");
+}
+
+#[test]
+fn checkpoint_rollback() {
+    let mut buffer = [0; 16];
+    let mut len = 0;
+    let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+
+    writer.write_str("ñañ").unwrap();
+    let checkpoint = writer.checkpoint();
+    assert_eq!(checkpoint, writer.checkpoint());
+
+    writer.write_str("aaa").unwrap();
+    writer.rollback(checkpoint);
+    assert_eq!(writer.as_str(), "ñañ");
+
+    // Rolling back to the current length does nothing
+    writer.rollback(checkpoint);
+    assert_eq!(writer.as_str(), "ñañ");
+
+    // Rolling back to a checkpoint after the current length does nothing
+    writer.truncate(2).unwrap();
+    writer.rollback(checkpoint);
+    assert_eq!(writer.as_str(), "ñ");
+
+    // Rolling back to a checkpoint that isn't on a char boundary does nothing
+    writer.write_str("ññ").unwrap();
+    writer.rollback(checkpoint);
+    assert_eq!(writer.as_str(), "ñññ");
+}
+
+#[test]
+fn checkpoint_rollback_truncating() {
+    let mut buffer = [0; 8];
+    let mut len = 0;
+    let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len).truncating("~");
+
+    writer.write_str("abc").unwrap();
+    let checkpoint = writer.checkpoint();
+
+    writer.write_str("defghijk").unwrap();
+    assert!(writer.is_truncated());
+    assert_eq!(writer.as_str(), "abcdefg~");
+
+    writer.rollback(checkpoint);
+    assert!(!writer.is_truncated());
+    assert_eq!(writer.as_str(), "abc");
+
+    writer.write_str("de").unwrap();
+    assert_eq!(writer.as_str(), "abcde");
+}
+
+#[test]
+fn not_enough_space() {
+    fn error(
+        cap: usize,
+        f: impl Fn(&mut StrWriterMut<'_>) -> Result<(), Error>,
+    ) -> NotEnoughSpaceError {
+        let mut buffer = [0; 64];
+        let mut len = 0;
+        let mut writer = StrWriterMut::from_custom_cleared(&mut buffer[..cap], &mut len);
+        writer.write_str("ab").unwrap();
+        assert_eq!(f(&mut writer), Err(Error::NotEnoughSpace));
+        assert_eq!(writer.len(), 2);
+        writer.not_enough_space().unwrap()
+    }
+    fn space_error(position: usize, needed: usize, capacity: usize) -> NotEnoughSpaceError {
+        NotEnoughSpaceError {
+            position,
+            needed,
+            capacity,
+        }
+    }
+
+    assert_eq!(error(4, |w| w.write_str("cde")), space_error(2, 5, 4));
+    assert_eq!(
+        error(4, |w| w.write_ascii_repeated(b'a', 4)),
+        space_error(2, 6, 4)
+    );
+    assert_eq!(
+        error(4, |w| w.write_u32_display(1000)),
+        space_error(2, 6, 4)
+    );
+    assert_eq!(error(4, |w| w.write_i8_display(-10)), space_error(2, 5, 4));
+    let flags = FormattingFlags::NEW.set_alternate(true).set_hexadecimal();
+    assert_eq!(
+        error(7, |w| w.write_u16_debug(0xFFFF, flags)),
+        space_error(2, 8, 7)
+    );
+    // Escapes that don't fit
+    assert_eq!(
+        error(7, |w| w.write_str_debug("a\nb")),
+        space_error(2, 8, 7)
+    );
+    assert_eq!(
+        error(8, |w| w.write_str_debug("abcdef")),
+        space_error(2, 10, 8)
+    );
+    assert_eq!(
+        error(8, |w| w.write_bytes_debug(b"\xff")),
+        space_error(2, 9, 8)
+    );
+    let hexdump_len = {
+        let mut buffer = [0; 128];
+        let mut len = 0;
+        let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+        writer.write_bytes_hexdump(b"a").unwrap();
+        writer.len()
+    };
+    assert_eq!(
+        error(8, |w| w.write_bytes_hexdump(b"a")),
+        space_error(2, 2 + hexdump_len, 8)
+    );
+}
+
+#[test]
+fn not_enough_space_truncating_and_reborrowed() {
+    let mut buffer = [0; 8];
+    let mut len = 0;
+    let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+
+    writer.write_str("abc").unwrap();
+    writer.reborrow().write_str("defghi").unwrap_err();
+    let expected = NotEnoughSpaceError {
+        position: 3,
+        needed: 9,
+        capacity: 8,
+    };
+    assert_eq!(writer.not_enough_space(), Some(expected));
+
+    // Successful writes don't reset it
+    writer.write_str("de").unwrap();
+    assert_eq!(writer.not_enough_space(), Some(expected));
+
+    writer.clear();
+    assert_eq!(writer.not_enough_space(), None);
+
+    let mut writer = writer.truncating("~");
+    writer.write_str("0123456789").unwrap();
+    assert_eq!(
+        writer.not_enough_space(),
+        Some(NotEnoughSpaceError {
+            position: 0,
+            needed: 10,
+            capacity: 8,
+        })
+    );
+}
+
+#[test]
+fn not_enough_space_display() {
+    let error = NotEnoughSpaceError {
+        position: 250,
+        needed: 312,
+        capacity: 256,
+    };
+    assert_eq!(error.additional(), 56);
+
+    let expected = "needed 312 bytes, capacity 256, when writing at byte 250";
+    assert_eq!(error.to_string(), expected);
+
+    let mut buffer = [0; 64];
+    let mut len = 0;
+    let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    cfmt_a::writec!(writer, "{}", error).unwrap();
+    assert_eq!(writer.as_str(), expected);
+
+    #[cfg(feature = "rust_1_83")]
+    {
+        let _: &dyn std::error::Error = &error;
+        let _: &dyn std::error::Error = &Error::NotEnoughSpace;
+    }
+}
+
+#[test]
+#[should_panic(
+    expected = "There was not enough space to write the formatted output, \
+                needed 15 bytes, capacity 11, when writing at byte 4"
+)]
+fn unwrap_fmt_reports_not_enough_space() {
+    let mut buffer = StrWriter::new([0; 11]);
+    cfmt_a::unwrap_fmt!(buffer, |f| cfmt_a::writec!(f, "foo {}", "bar baz qux"));
+}
+
+#[test]
+fn unwrap_fmt_writes() {
+    let buffer: &mut StrWriter = &mut StrWriter::new([0; 16]);
+    cfmt_a::unwrap_fmt!(buffer, |f| cfmt_a::writec!(f, "foo {}", "bar baz qux"));
+    assert_eq!(buffer.as_str(), "foo bar baz qux");
+}
+
+#[test]
+fn fmt_write() {
+    use core::fmt::Write;
+
+    {
+        let mut len = 0;
+        let mut buffer = [0; 16];
+        let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+
+        writer.write_u8_display(3).unwrap();
+        write!(writer, " {:?}", "foo").unwrap();
+        writer.write_char('ñ').unwrap();
+        assert_eq!(writer.as_str(), r#"3 "foo"ñ"#);
+
+        // The arguments before the one that doesn't fit are written.
+        assert_eq!(
+            write!(writer, "{}{}", 'a', "bcdefghijk"),
+            Err(core::fmt::Error)
+        );
+        assert_eq!(writer.as_str(), r#"3 "foo"ña"#);
+        assert_eq!(
+            writer.not_enough_space(),
+            Some(NotEnoughSpaceError {
+                position: 10,
+                needed: 20,
+                capacity: 16,
+            }),
+        );
+    }
+    {
+        let mut len = 0;
+        let mut buffer = [0; 8];
+        let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len).truncating("~");
+
+        writeln!(writer, "{}", 123456789).unwrap();
+        assert_eq!(writer.as_str(), "1234567~");
+        assert!(writer.is_truncated());
+    }
+    {
+        let writer: &mut StrWriter = &mut StrWriter::new([0; 8]);
+        writeln!(writer, "{}", 1234).unwrap();
+        assert_eq!(writer.as_str(), "1234\n");
+        assert!(writer.write_str("abcd").is_err());
+        assert_eq!(writer.as_str(), "1234\n");
+    }
+    {
+        let mut writer = StrWriter::new([0; 8]);
+        write!(writer, "{:x}", 255).unwrap();
+        writer.write_str("-").unwrap();
+        assert_eq!(writer.r().as_str(), "ff-");
+        assert!(write!(writer, "{}", "foobar").is_err());
+    }
+}
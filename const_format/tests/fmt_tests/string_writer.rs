@@ -0,0 +1,92 @@
+use cfmt_a::{
+    fmt::{Error, FormattingFlags, StringWriter},
+    to_debug_string, to_string,
+    wrapper_types::PWrapper,
+    writec,
+};
+
+#[test]
+fn grows_to_fit() {
+    let mut writer = StringWriter::new();
+    assert_eq!(writer.capacity(), 0);
+    assert!(writer.is_empty());
+
+    let mut calls = 0;
+    writer
+        .write_with(FormattingFlags::NEW, |f| {
+            calls += 1;
+            writec!(f, "{:?}", [u64::MAX; 20])
+        })
+        .unwrap();
+
+    let expected = format!("{:?}", [u64::MAX; 20]);
+    assert_eq!(writer.as_str(), expected);
+    assert_eq!(writer.as_bytes(), expected.as_bytes());
+    assert_eq!(writer.len(), expected.len());
+    assert!(writer.capacity() >= expected.len());
+    assert!(calls > 1, "{}", calls);
+
+    // There's enough space now, so it's only called once.
+    writer.clear();
+    let mut calls = 0;
+    writer
+        .write_with(FormattingFlags::NEW, |f| {
+            calls += 1;
+            writec!(f, "{:?}", [u64::MAX; 20])
+        })
+        .unwrap();
+    assert_eq!(writer.as_str(), expected);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn appends_to_existing_text() {
+    let mut writer = StringWriter::with_capacity(4);
+    assert_eq!(writer.capacity(), 4);
+
+    writer
+        .write_with(FormattingFlags::NEW, |f| f.write_str("foo"))
+        .unwrap();
+
+    let flags = FormattingFlags::NEW.set_alternate(true).set_hexadecimal();
+    writer
+        .write_with(flags, |f| PWrapper(&[255u8, 16][..]).const_debug_fmt(f))
+        .unwrap();
+
+    let expected = "foo[\n    0xFF,\n    0x10,\n]";
+    assert_eq!(writer.as_str(), expected);
+    assert_eq!(writer.into_string(), expected);
+}
+
+#[test]
+fn returns_errors() {
+    let mut writer = StringWriter::new();
+
+    // An error that isn't caused by running out of space is returned,
+    // and the output before it is kept.
+    let res = writer.write_with(FormattingFlags::NEW, |f| {
+        f.write_str("hello")?;
+        Err(Error::NotAscii)
+    });
+    assert_eq!(res, Err(Error::NotAscii));
+    assert_eq!(writer.as_str(), "hello");
+
+    // `NotEnoughSpace` errors that the writer didn't cause are returned as well.
+    let res = writer.write_with(FormattingFlags::NEW, |_| Err(Error::NotEnoughSpace));
+    assert_eq!(res, Err(Error::NotEnoughSpace));
+    assert_eq!(writer.as_str(), "hello");
+}
+
+#[test]
+fn to_string_macros() {
+    assert_eq!(to_string!("foo\n"), "foo\n");
+    assert_eq!(to_debug_string!("foo\n"), r#""foo\n""#);
+    assert_eq!(to_string!(100u8), "100");
+    assert_eq!(to_debug_string!(Some(100u8)), "Some(100)");
+
+    let flags = FormattingFlags::NEW.set_alternate(true).set_hexadecimal();
+    assert_eq!(to_debug_string!([100u8], flags), "[\n    0x64,\n]");
+
+    let long = [true; 200];
+    assert_eq!(to_debug_string!(long), format!("{:?}", long));
+}
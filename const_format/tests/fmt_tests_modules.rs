@@ -1,5 +1,9 @@
 #![cfg(feature = "fmt")]
-#![cfg_attr(feature = "fmt", feature(const_mut_refs))]
+#![cfg_attr(
+    all(feature = "fmt", not(feature = "rust_1_83")),
+    feature(const_mut_refs)
+)]
+#![cfg_attr(feature = "nightly_const_traits", feature(const_trait_impl))]
 
 // Prevents importing from const_format, requiring importing from cfmt_b.
 extern crate const_format as cfmt_a;
@@ -12,6 +16,12 @@ pub const _ASSERT_NOT_CF: [(); 13] = [(); const_format::NOT_CF];
 cfmt_a::__declare_rng_ext! {}
 
 mod fmt_tests {
+    #[cfg(not(feature = "__only_new_tests"))]
+    mod const_string;
+
+    #[cfg(all(feature = "nightly_const_traits", not(feature = "__only_new_tests")))]
+    mod const_traits;
+
     #[cfg(not(feature = "__only_new_tests"))]
     mod display_formatting;
 
@@ -21,6 +31,9 @@ mod fmt_tests {
     #[cfg(not(feature = "__only_new_tests"))]
     mod formatter_methods;
 
+    #[cfg(not(feature = "__only_new_tests"))]
+    mod std_adapter;
+
     #[cfg(not(feature = "__only_new_tests"))]
     mod std_impl_tests;
 
@@ -29,4 +42,7 @@ mod fmt_tests {
 
     #[cfg(not(feature = "__only_new_tests"))]
     mod str_writer_mut;
+
+    #[cfg(all(feature = "alloc", not(feature = "__only_new_tests")))]
+    mod string_writer;
 }
@@ -5,6 +5,11 @@ use cfmt_b::{
 
 use core::marker::PhantomData;
 
+mod display_derive;
+
+#[cfg(feature = "assertc")]
+mod eq_derive;
+
 mod is_a_attributes;
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -153,3 +158,153 @@ fn enum_formatting() {
         ",
     );
 }
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(non_exhaustive)]
+struct NonExhaustiveBraced {
+    x: u32,
+    #[allow(dead_code)]
+    #[cdeb(ignore)]
+    secret: u32,
+}
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(non_exhaustive)]
+struct NonExhaustiveTupled(u32, #[cdeb(ignore)] u32);
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+enum PartiallyNonExhaustive {
+    #[cdeb(non_exhaustive)]
+    Braced {
+        x: u32,
+        #[allow(dead_code)]
+        #[cdeb(ignore)]
+        secret: u32,
+    },
+    #[cdeb(non_exhaustive)]
+    Tupled(#[cdeb(ignore)] u32),
+    Exhaustive(u32),
+}
+
+#[test]
+fn non_exhaustive_formatting() {
+    const fn inner(f: &mut Formatter<'_>) -> Result<(), Error> {
+        let braced = NonExhaustiveBraced { x: 3, secret: 5 };
+        let tupled = NonExhaustiveTupled(8, 13);
+        try_!(writec!(f, "{0:?}\n{0:#?}\n{1:?}\n{1:#?}\n", braced, tupled));
+
+        let braced = PartiallyNonExhaustive::Braced { x: 21, secret: 34 };
+        let tupled = PartiallyNonExhaustive::Tupled(55);
+        let exhaustive = PartiallyNonExhaustive::Exhaustive(89);
+        try_!(writec!(f, "{:?}\n{:?}\n{:?}", braced, tupled, exhaustive));
+
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 1024]);
+
+    inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
+
+    assert_eq!(
+        writer.as_str(),
+        "\
+            NonExhaustiveBraced { x: 3, .. }\n\
+            NonExhaustiveBraced {\n    x: 3,\n    ..\n}\n\
+            NonExhaustiveTupled(8, ..)\n\
+            NonExhaustiveTupled(\n    8,\n    ..\n)\n\
+            Braced { x: 21, .. }\n\
+            Tupled(..)\n\
+            Exhaustive(89)\
+        ",
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(std_debug)]
+struct StdDebugBraced {
+    x: u32,
+    #[allow(dead_code)]
+    #[cdeb(ignore)]
+    secret: u32,
+    unit: Unit,
+}
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(std_debug)]
+#[cdeb(impls("<U> StdDebugTupled<u8, U>", "<U> StdDebugTupled<bool, U>"))]
+struct StdDebugTupled<T, U>(T, PhantomData<U>);
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(std_debug(capacity = 8))]
+struct StdDebugSmall(&'static str);
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(std_debug)]
+struct StdDebugLong {
+    values: [u32; 300],
+    name: &'static str,
+}
+
+#[test]
+fn std_debug_formatting() {
+    let braced = StdDebugBraced {
+        x: 3,
+        secret: 5,
+        unit: Unit,
+    };
+    assert_eq!(
+        format!("{:?}", braced),
+        "StdDebugBraced { x: 3, unit: Unit }"
+    );
+    assert_eq!(
+        format!("{:#?}", braced),
+        "StdDebugBraced {\n    x: 3,\n    unit: Unit,\n}",
+    );
+    assert_eq!(
+        format!("{:?}", StdDebugTupled(255u8, PhantomData::<()>)),
+        "StdDebugTupled(255, PhantomData)",
+    );
+    assert_eq!(
+        format!("{:?}", StdDebugTupled(true, PhantomData::<()>)),
+        "StdDebugTupled(true, PhantomData)",
+    );
+    assert_eq!(
+        format!("{:?}", StdDebugSmall("hello")),
+        r#"StdDebugSmall("hello")"#,
+    );
+    assert_eq!(
+        format!("{:?}", StdDebugSmall("h\u{e9}llo w\u{f6}rld")),
+        "StdDebugSmall(\"h\u{e9}llo w\u{f6}rld\")",
+    );
+
+    let long = StdDebugLong {
+        values: [100_000; 300],
+        name: "long",
+    };
+    assert_eq!(
+        format!("{:?}", long),
+        format!(
+            "StdDebugLong {{ values: {:?}, name: {:?} }}",
+            long.values, long.name
+        ),
+    );
+    assert_eq!(
+        format!("{:#?}", long),
+        format!(
+            "StdDebugLong {{\n    values: {},\n    name: {:?},\n}}",
+            format!("{:#?}", long.values).replace('\n', "\n    "),
+            long.name
+        ),
+    );
+}
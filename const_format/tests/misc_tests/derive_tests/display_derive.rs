@@ -0,0 +1,110 @@
+use cfmt_b::{
+    fmt::{Error, Formatter, FormattingFlags, StrWriter},
+    formatc, try_, writec, ConstDebug, ConstDisplay,
+};
+
+use core::marker::PhantomData;
+
+const SEPARATOR: &str = "; ";
+
+#[derive(ConstDebug, ConstDisplay)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdisplay(crate = "::cfmt_b")]
+#[cdisplay("{host}:{port}")]
+struct Address {
+    host: &'static str,
+    port: u16,
+}
+
+#[derive(ConstDisplay)]
+#[cdisplay(crate = "::cfmt_b", "{1}{SEPARATOR}{0:?}|{:x}|{:>7}|{{{2}}}")]
+struct Tupled(u8, &'static str, u32);
+
+#[derive(ConstDebug, ConstDisplay)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdisplay(crate = "::cfmt_b")]
+enum Enum {
+    #[cdisplay("braced {y} {x:#b}")]
+    Braced {
+        x: u32,
+        y: bool,
+        #[allow(dead_code)]
+        z: char,
+    },
+    #[cdisplay(r#"tupled "{0}" {1}"#)]
+    Tupled(&'static str, u32),
+    #[cdisplay("unit")]
+    Unit,
+}
+
+#[derive(ConstDisplay)]
+#[cdisplay(crate = "::cfmt_b")]
+#[cdisplay(impls("Generic<u8>", "<T> Generic<PhantomData<T>>"))]
+#[cdisplay("Generic({len})")]
+struct Generic<T> {
+    len: usize,
+    #[allow(dead_code)]
+    value: T,
+}
+
+#[test]
+fn display_derive_formatting() {
+    const fn inner(f: &mut Formatter<'_>) -> Result<(), Error> {
+        try_!(Tupled(10, "hello", 3).const_display_fmt(f));
+        try_!(writec!(f, "\n"));
+
+        try_!(Enum::Braced {
+            x: 5,
+            y: true,
+            z: 'a'
+        }
+        .const_display_fmt(f));
+        try_!(writec!(f, "\n"));
+        try_!(Enum::Tupled("foo", 8).const_display_fmt(f));
+        try_!(writec!(f, "\n"));
+        try_!(Enum::Unit.const_display_fmt(f));
+        try_!(writec!(f, "\n"));
+
+        try_!(Generic { len: 3, value: 0u8 }.const_display_fmt(f));
+        try_!(Generic {
+            len: 5,
+            value: PhantomData::<()>
+        }
+        .const_display_fmt(f));
+
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 256]);
+
+    inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
+
+    assert_eq!(
+        writer.as_str(),
+        "\
+            hello; 10|a|  hello|{3}\n\
+            braced true 0b101\n\
+            tupled \"foo\" 8\n\
+            unit\n\
+            Generic(3)Generic(5)\
+        ",
+    );
+}
+
+#[test]
+fn display_derive_with_formatc() {
+    const ADDRESS: &str = formatc!(
+        "{0} {0:?}",
+        Address {
+            host: "localhost",
+            port: 8080
+        }
+    );
+    assert_eq!(
+        ADDRESS,
+        r#"localhost:8080 Address { host: "localhost", port: 8080 }"#
+    );
+
+    const ENUM: &str = formatc!("{}", Enum::Tupled("bar", 0));
+    assert_eq!(ENUM, r#"tupled "bar" 0"#);
+}
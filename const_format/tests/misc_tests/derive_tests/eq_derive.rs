@@ -0,0 +1,174 @@
+use cfmt_b::{assertc_eq, assertc_ne, ConstDebug, ConstEq};
+
+use core::marker::PhantomData;
+
+type Pair = [u16; 2];
+
+#[derive(ConstDebug, ConstEq)]
+#[cdeb(crate = "::cfmt_b")]
+#[ceq(crate = "::cfmt_b")]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[derive(ConstDebug, ConstEq)]
+#[cdeb(crate = "::cfmt_b")]
+#[ceq(crate = "::cfmt_b")]
+struct Config {
+    name: &'static str,
+    names: &'static [&'static str],
+    bytes: [u8; 3],
+    limit: Option<u32>,
+    origin: Option<Point>,
+    points: [Point; 2],
+    #[cdeb(is_a(array))]
+    #[ceq(is_a(array))]
+    pair: Pair,
+    #[ceq(with = "case_insensitive_eq")]
+    key: char,
+    #[cdeb(ignore)]
+    #[ceq(ignore)]
+    #[allow(dead_code)]
+    counter: u64,
+}
+
+const fn case_insensitive_eq(l: &char, r: &char) -> bool {
+    l.eq_ignore_ascii_case(r)
+}
+
+#[derive(ConstDebug, ConstEq)]
+#[cdeb(crate = "::cfmt_b")]
+#[ceq(crate = "::cfmt_b")]
+enum Enum {
+    Braced { x: u8, point: Point },
+    Tupled(&'static str, #[ceq(ignore)] bool),
+    Unit,
+}
+
+#[derive(ConstEq)]
+#[ceq(crate = "::cfmt_b")]
+struct Tupled(u8, i64);
+
+#[derive(ConstEq)]
+#[ceq(crate = "::cfmt_b")]
+struct Unit;
+
+#[derive(ConstEq)]
+#[ceq(crate = "::cfmt_b")]
+#[ceq(impls("Generic<u8>", "<T> Generic<PhantomData<T>>"))]
+struct Generic<T>(u32, T);
+
+const CONFIG: Config = Config {
+    name: "foo",
+    names: &["bar", "baz"],
+    bytes: [3, 5, 8],
+    limit: Some(13),
+    origin: Some(Point { x: 0, y: 1 }),
+    points: [Point { x: 2, y: 3 }, Point { x: 5, y: 8 }],
+    pair: [21, 34],
+    key: 'a',
+    counter: 0,
+};
+
+#[test]
+fn struct_equality() {
+    const SAME: [Config; 2] = [
+        CONFIG,
+        Config {
+            key: 'A',
+            counter: 100,
+            ..CONFIG
+        },
+    ];
+
+    const DIFFERENT: [Config; 10] = [
+        Config {
+            name: "fooo",
+            ..CONFIG
+        },
+        Config {
+            names: &["bar"],
+            ..CONFIG
+        },
+        Config {
+            names: &["bar", "qux"],
+            ..CONFIG
+        },
+        Config {
+            bytes: [3, 5, 9],
+            ..CONFIG
+        },
+        Config {
+            limit: None,
+            ..CONFIG
+        },
+        Config {
+            origin: None,
+            ..CONFIG
+        },
+        Config {
+            origin: Some(Point { x: 1, y: 1 }),
+            ..CONFIG
+        },
+        Config {
+            points: [Point { x: 2, y: 3 }, Point { x: 5, y: 9 }],
+            ..CONFIG
+        },
+        Config {
+            pair: [21, 35],
+            ..CONFIG
+        },
+        Config { key: 'b', ..CONFIG },
+    ];
+
+    for other in SAME.iter() {
+        assert!(CONFIG.const_eq(other));
+        assert!(other.const_eq(&CONFIG));
+    }
+    for other in DIFFERENT.iter() {
+        assert!(!CONFIG.const_eq(other), "{:?}", other.name);
+        assert!(!other.const_eq(&CONFIG));
+        assert!(other.const_eq(other));
+    }
+
+    assert!(Tupled(3, -5).const_eq(&Tupled(3, -5)));
+    assert!(!Tupled(3, -5).const_eq(&Tupled(3, 5)));
+    assert!(!Tupled(3, -5).const_eq(&Tupled(4, -5)));
+
+    assert!(Unit.const_eq(&Unit));
+
+    assert!(Generic(3, 5u8).const_eq(&Generic(3, 5)));
+    assert!(!Generic(3, 5u8).const_eq(&Generic(3, 6)));
+    assert!(Generic(3, PhantomData::<()>).const_eq(&Generic(3, PhantomData)));
+    assert!(!Generic(3, PhantomData::<()>).const_eq(&Generic(4, PhantomData)));
+}
+
+#[test]
+fn enum_equality() {
+    const VALUES: [Enum; 5] = [
+        Enum::Braced {
+            x: 3,
+            point: Point { x: 5, y: 8 },
+        },
+        Enum::Braced {
+            x: 3,
+            point: Point { x: 5, y: 9 },
+        },
+        Enum::Tupled("foo", false),
+        Enum::Tupled("bar", false),
+        Enum::Unit,
+    ];
+
+    for (i, left) in VALUES.iter().enumerate() {
+        for (j, right) in VALUES.iter().enumerate() {
+            assert_eq!(left.const_eq(right), i == j, "{} {}", i, j);
+        }
+    }
+
+    assert!(Enum::Tupled("foo", false).const_eq(&Enum::Tupled("foo", true)));
+}
+
+assertc_eq!(CONFIG, Config { key: 'A', ..CONFIG });
+
+assertc_ne!(Enum::Unit, Enum::Tupled("foo", true));
@@ -339,3 +339,154 @@ fn access_formatter() {
         "[(), ()] ; [(), ()]"
     );
 }
+
+#[test]
+#[cfg(feature = "fmt")]
+fn debug_limits() {
+    use cfmt_b::call_debug_fmt;
+
+    assert_eq!(formatc!("{:?(entries = 2)}", [3u8, 5, 8]), "[3, 5, ...]");
+    assert_eq!(formatc!("{:?(entries = 3)}", [3u8, 5, 8]), "[3, 5, 8]");
+    assert_eq!(formatc!("{:?(entries = 0)}", [3u8, 5, 8]), "[...]");
+    assert_eq!(formatc!("{:x?(entries = 1)}", [10u8, 11]), "[a, ...]");
+    assert_eq!(
+        formatc!("{:#?(entries = 1)}", [3u8, 5, 8]),
+        "[\n    3,\n    ...\n]"
+    );
+
+    assert_eq!(formatc!("{:?(depth = 0)}", Some(10u8)), "..");
+    assert_eq!(formatc!("{:?(depth = 1)}", Some(10u8)), "Some(10)");
+    assert_eq!(formatc!("{:#?(depth = 0)}", Some(10u8)), "..");
+
+    assert_eq!(
+        formatc!("{0:?(depth = 1)}", |fmt| call_debug_fmt!(
+            array,
+            [Some(3u8), None],
+            fmt
+        )),
+        "[.., None]"
+    );
+    assert_eq!(
+        formatc!("{0:#?(depth = 1, entries = 1)}", |fmt| call_debug_fmt!(
+            array,
+            [Some(3u8), None],
+            fmt
+        )),
+        "[\n    ..,\n    ...\n]"
+    );
+}
+
+#[test]
+#[cfg(feature = "fmt")]
+fn debug_indent() {
+    assert_eq!(formatc!("{:#?(indent = 2)}", [1u8, 2]), "[\n  1,\n  2,\n]");
+    assert_eq!(
+        formatc!("{:#?(indent = tab)}", ["foo", "bar"]),
+        "[\n\t\"foo\",\n\t\"bar\",\n]"
+    );
+    assert_eq!(formatc!("{:#?(indent = 0)}", [1u8, 2]), "[\n1,\n2,\n]");
+    assert_eq!(
+        formatc!("{:#?(indent = 2, line_width = 10)}", [10u8, 20, 30]),
+        "[\n  10,\n  20,\n  30,\n]"
+    );
+
+    // The indentation only affects the alternate debug formatting
+    assert_eq!(formatc!("{:?(indent = tab)}", [1u8, 2]), "[1, 2]");
+}
+
+#[test]
+#[cfg(feature = "fmt")]
+fn debug_line_width() {
+    use cfmt_b::call_debug_fmt;
+
+    assert_eq!(formatc!("{:#?(line_width = 6)}", [1u8, 2]), "[1, 2]");
+    assert_eq!(
+        formatc!("{:#?(line_width = 5)}", [1u8, 2]),
+        "[\n    1,\n    2,\n]"
+    );
+
+    // The text before the argument in the same line counts towards the line width
+    assert_eq!(
+        formatc!("x = {:#?(line_width = 10)}", [1u8, 2]),
+        "x = [1, 2]"
+    );
+    assert_eq!(
+        formatc!("x = {:#?(line_width = 9)}", [1u8, 2]),
+        "x = [\n    1,\n    2,\n]"
+    );
+
+    assert_eq!(
+        formatc!("{:#?(line_width = 80, entries = 2)}", [3u8, 5, 8]),
+        "[3, 5, ...]"
+    );
+
+    assert_eq!(
+        formatc!("{0:#?(line_width = 12)}", |fmt| call_debug_fmt!(
+            array,
+            [Some(3u8), None],
+            fmt
+        )),
+        "[\n    Some(3),\n    None,\n]"
+    );
+    assert_eq!(
+        formatc!("{0:#?(line_width = 11)}", |fmt| call_debug_fmt!(
+            array,
+            [Some(3u8), None],
+            fmt
+        )),
+        "[\n    Some(\n        3,\n    ),\n    None,\n]"
+    );
+}
+
+#[test]
+#[cfg(feature = "fmt")]
+fn width_fill_alignment() {
+    use cfmt_b::{
+        fmt::{Error, Formatter, StrWriter},
+        impl_fmt, writec,
+    };
+
+    struct Fruit {
+        name: &'static str,
+        price: &'static str,
+    }
+
+    impl_fmt! {
+        impl Fruit;
+
+        const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+            f.pad(self.name)
+        }
+
+        const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+            f.pad_integral(true, "$", self.price)
+        }
+    }
+
+    assert_eq!(
+        formatc!("[{:5}][{:>5}][{:^5}][{:*<5}]", 42u8, "ab", true, 'c'),
+        "[   42][   ab][true ][c****]"
+    );
+    assert_eq!(
+        formatc!("[{:>5?}][{:^#8x?}][{:<4}]", -3i8, 255u8, "toolong"),
+        "[   -3][  0xff  ][toolong]"
+    );
+    assert_eq!(formatc!("[{0:-^5}]", |fmt| fmt.pad("ñ")), "[--ñ--]");
+
+    const APPLE: Fruit = Fruit {
+        name: "apple",
+        price: "3",
+    };
+    const BANANA: Fruit = Fruit {
+        name: "banana",
+        price: "12",
+    };
+    assert_eq!(
+        formatc!("{:>8}|{:>4?}\n{:>8}|{:<#4?}", APPLE, APPLE, BANANA, BANANA),
+        "   apple|   3\n  banana|$12 "
+    );
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 16]);
+    writec!(writer, "{:>6}|{:6}", "foo", 1u8).unwrap();
+    assert_eq!(writer.as_str(), "   foo|     1");
+}
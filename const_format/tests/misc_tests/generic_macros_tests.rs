@@ -0,0 +1,68 @@
+use cfmt_b::{concatcp, concatcp_generic, formatcp, formatcp_generic};
+
+trait Named {
+    const NAME: &'static str;
+}
+
+impl Named for u8 {
+    const NAME: &'static str = "u8";
+}
+
+impl Named for () {
+    const NAME: &'static str = "";
+}
+
+impl<T: Named> Named for Option<T> {
+    const NAME: &'static str = concatcp_generic!(64; "Option<", T::NAME, ">");
+}
+
+impl<T: Named, const N: usize> Named for [T; N] {
+    const NAME: &'static str = formatcp_generic!(64; "[{}; {N}]", T::NAME);
+}
+
+impl<A: Named, B: Named> Named for (A, B) {
+    const NAME: &'static str = formatcp_generic!(64; "({a}, {b})", a = A::NAME, b = B::NAME);
+}
+
+struct Exact<T>(T);
+
+impl<T: Named> Exact<T> {
+    // exactly the capacity needed for `Exact::<u8>::NAME`
+    const NAME: &'static str = concatcp_generic!(8; "Exact<", T::NAME);
+}
+
+#[test]
+fn generic_type_names() {
+    assert_eq!(<Option<u8>>::NAME, "Option<u8>");
+    assert_eq!(<[Option<u8>; 3]>::NAME, "[Option<u8>; 3]");
+    assert_eq!(<(u8, [u8; 0])>::NAME, "(u8, [u8; 0])");
+    assert_eq!(<Option<(Option<u8>, ())>>::NAME, "Option<(Option<u8>, )>");
+    assert_eq!(Exact::<u8>::NAME, "Exact<u8");
+    assert_eq!(Exact::<()>::NAME, "Exact<");
+}
+
+#[test]
+fn same_as_non_generic_macros() {
+    const ZERO: u32 = 0;
+
+    macro_rules! assert_concat {
+        ($($arg:expr),* $(,)?) => {{
+            const A: &str = concatcp!($($arg),*);
+            const B: &str = concatcp_generic!(128; $($arg),*);
+            assert_eq!(A, B);
+        }};
+    }
+    macro_rules! assert_format {
+        ($($arg:tt)*) => {{
+            const A: &str = formatcp!($($arg)*);
+            const B: &str = formatcp_generic!(128; $($arg)*);
+            assert_eq!(A, B);
+        }};
+    }
+
+    assert_concat!();
+    assert_concat!("foo", 'ñ', 3u8, -5i64, u128::MAX, true, ZERO);
+    assert_format!("");
+    assert_format!("{:?}{}{:x}{:#X}{:b}", "he\"llo", 'a', 255u8, 255u8, 5u8);
+    assert_format!("{0}{0:?}{ZERO}{a}", "foo", a = i8::MIN);
+}
@@ -54,7 +54,7 @@ fn repeated_positional_args() {
     inner(&foo, writer).unwrap();
     assert_eq!(
         writer.as_str(),
-        "13,13,0xd,0xD,0b1101,foo\nbar\tbaz\x00,\"foo\\nbar\\tbaz\\x00\""
+        "13,13,0xd,0xD,0b1101,foo\nbar\tbaz\x00,\"foo\\nbar\\tbaz\\0\""
     );
 }
 
@@ -76,7 +76,7 @@ fn write_from_consts() {
     inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
     assert_eq!(
         writer.as_str(),
-        "13,13,0xd,0xD,0b1101,foo\nbar\tbaz\x00,\"foo\\nbar\\tbaz\\x00\""
+        "13,13,0xd,0xD,0b1101,foo\nbar\tbaz\x00,\"foo\\nbar\\tbaz\\0\""
     );
 }
 
@@ -1,4 +1,7 @@
-#![cfg_attr(feature = "fmt", feature(const_mut_refs))]
+#![cfg_attr(
+    all(feature = "fmt", not(feature = "rust_1_83")),
+    feature(const_mut_refs)
+)]
 
 extern crate const_format as cfmt_b;
 extern crate self as const_format;
@@ -36,6 +39,10 @@ mod misc_tests {
     #[cfg(not(feature = "__only_new_tests"))]
     mod formatc_macros;
 
+    #[cfg(feature = "fmt")]
+    #[cfg(not(feature = "__only_new_tests"))]
+    mod generic_macros_tests;
+
     #[cfg(feature = "fmt")]
     #[cfg(not(feature = "__only_new_tests"))]
     mod impl_fmt_macro_tests;
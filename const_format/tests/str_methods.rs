@@ -1,4 +1,7 @@
 mod str_methods_modules {
+    #[cfg(feature = "rust_1_51")]
+    mod binary_encoding;
+
     #[cfg(feature = "rust_1_51")]
     mod conv_ascii_case;
 
@@ -7,6 +10,12 @@ mod str_methods_modules {
 
     mod str_splice;
 
+    #[cfg(feature = "rust_1_51")]
+    mod str_from_utf8;
+
+    #[cfg(feature = "rust_1_51")]
+    mod str_unescape;
+
     #[cfg(feature = "rust_1_64")]
     mod str_split_tests;
 }
@@ -0,0 +1,109 @@
+use const_format::{base64_decode, base64_encode, hex_decode, hex_encode, Base64Config, Case};
+
+#[test]
+fn test_hex_encode() {
+    assert_eq!(hex_encode!(""), "");
+    assert_eq!(hex_encode!("foo"), "666f6f");
+    assert_eq!(hex_encode!("ñ"), "c3b1");
+    assert_eq!(hex_encode!("ñ", Case::Lower), "c3b1");
+    assert_eq!(hex_encode!("ñ", Case::Upper), "C3B1");
+    assert_eq!(hex_encode!(b"\x00\x7f\x80\xff"), "007f80ff");
+    {
+        const IN: &[u8] = &[1, 35, 69, 103, 137, 171, 205, 239];
+        assert_eq!(hex_encode!(IN), "0123456789abcdef");
+        assert_eq!(hex_encode!(IN, Case::Upper), "0123456789ABCDEF");
+    }
+}
+
+#[test]
+fn test_hex_decode() {
+    assert_eq!(hex_decode!(""), &[0u8; 0]);
+    assert_eq!(hex_decode!("666f6f"), b"foo");
+    assert_eq!(hex_decode!("C3b1"), "ñ".as_bytes());
+    assert_eq!(hex_decode!(b"007F80ff"), &[0x00, 0x7F, 0x80, 0xFF]);
+    {
+        const IN: &[u8] = b"0123456789abcdefABCDEF";
+        assert_eq!(
+            hex_decode!(IN),
+            &[0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xAB, 0xCD, 0xEF],
+        );
+    }
+}
+
+#[test]
+fn test_hex_roundtrip() {
+    const BYTES: &[u8] = &[0, 1, 2, 3, 127, 128, 200, 254, 255];
+    const ENCODED: &str = hex_encode!(BYTES);
+    assert_eq!(hex_decode!(ENCODED), BYTES);
+}
+
+// Test vectors from RFC 4648
+#[test]
+fn test_base64_encode() {
+    assert_eq!(base64_encode!(""), "");
+    assert_eq!(base64_encode!("f"), "Zg==");
+    assert_eq!(base64_encode!("fo"), "Zm8=");
+    assert_eq!(base64_encode!("foo"), "Zm9v");
+    assert_eq!(base64_encode!("foob"), "Zm9vYg==");
+    assert_eq!(base64_encode!("fooba"), "Zm9vYmE=");
+    assert_eq!(base64_encode!("foobar"), "Zm9vYmFy");
+
+    const NO_PAD: Base64Config = Base64Config::STANDARD_NO_PAD;
+    assert_eq!(base64_encode!("", NO_PAD), "");
+    assert_eq!(base64_encode!("f", NO_PAD), "Zg");
+    assert_eq!(base64_encode!("fo", NO_PAD), "Zm8");
+    assert_eq!(base64_encode!("foo", NO_PAD), "Zm9v");
+    assert_eq!(base64_encode!("foob", NO_PAD), "Zm9vYg");
+}
+
+#[test]
+fn test_base64_alphabets() {
+    const IN: &[u8; 6] = &[0xFB, 0xEF, 0xBE, 0xFF, 0xFF, 0xFF];
+    assert_eq!(base64_encode!(IN), "++++////");
+    assert_eq!(base64_encode!(IN, Base64Config::URL_SAFE), "----____");
+    assert_eq!(base64_decode!("++++////"), IN);
+    assert_eq!(
+        base64_decode!("----____", Base64Config::URL_SAFE_NO_PAD),
+        IN
+    );
+
+    assert_eq!(base64_encode!(b"\xFF", Base64Config::URL_SAFE_NO_PAD), "_w");
+    assert_eq!(
+        base64_encode!(b"\xFF", Base64Config::STANDARD.set_url_safe(true)),
+        "_w=="
+    );
+}
+
+#[test]
+fn test_base64_decode() {
+    assert_eq!(base64_decode!(""), &[0u8; 0]);
+    assert_eq!(base64_decode!("Zg=="), b"f");
+    assert_eq!(base64_decode!("Zm8="), b"fo");
+    assert_eq!(base64_decode!("Zm9v"), b"foo");
+    assert_eq!(base64_decode!(b"Zm9vYg=="), b"foob");
+    assert_eq!(base64_decode!("Zm9vYmE="), b"fooba");
+    assert_eq!(base64_decode!("Zm9vYmFy"), b"foobar");
+
+    const NO_PAD: Base64Config = Base64Config::STANDARD_NO_PAD;
+    assert_eq!(base64_decode!("Zg", NO_PAD), b"f");
+    assert_eq!(base64_decode!("Zm8", NO_PAD), b"fo");
+    assert_eq!(base64_decode!("Zm9vYmE", NO_PAD), b"fooba");
+}
+
+#[test]
+fn test_base64_roundtrip() {
+    const BYTES: &[u8] = &[0, 1, 2, 3, 62, 63, 64, 127, 128, 200, 251, 254, 255];
+
+    macro_rules! roundtrip {
+        ($config:expr) => {{
+            const CFG: Base64Config = $config;
+            const ENCODED: &str = base64_encode!(BYTES, CFG);
+            assert_eq!(base64_decode!(ENCODED, CFG), BYTES);
+        }};
+    }
+
+    roundtrip!(Base64Config::STANDARD);
+    roundtrip!(Base64Config::STANDARD_NO_PAD);
+    roundtrip!(Base64Config::URL_SAFE);
+    roundtrip!(Base64Config::URL_SAFE_NO_PAD);
+}
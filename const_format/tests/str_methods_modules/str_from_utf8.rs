@@ -0,0 +1,90 @@
+use const_format::{__str_methods::FromUtf8Input, str_from_utf8, str_from_utf8_lossy};
+
+macro_rules! test_lossy {
+    ($input:expr) => {{
+        const IN: &[u8] = $input;
+        assert_eq!(
+            str_from_utf8_lossy!(IN),
+            String::from_utf8_lossy(IN),
+            "{:?}",
+            IN
+        );
+    }};
+}
+
+#[test]
+fn test_str_from_utf8() {
+    assert_eq!(str_from_utf8!(b""), "");
+    assert_eq!(str_from_utf8!(b"foo"), "foo");
+    assert_eq!(str_from_utf8!("!Aq¡🧡🧠₀₁oñ个"), "!Aq¡🧡🧠₀₁oñ个");
+    assert_eq!(
+        str_from_utf8!(&[0x7F, 0xC2, 0x80, 0xEF, 0xBF, 0xBF, 0xF4, 0x8F, 0xBF, 0xBF]),
+        "\x7F\u{80}\u{FFFF}\u{10FFFF}",
+    );
+    {
+        const BYTES: &[u8] = "hello ñ world".as_bytes();
+        assert_eq!(str_from_utf8!(BYTES), "hello ñ world");
+    }
+    {
+        const BYTES: &[u8; 4] = &[0xF0, 0x9F, 0x98, 0x80];
+        assert_eq!(str_from_utf8!(BYTES), "😀");
+    }
+}
+
+#[test]
+fn test_str_from_utf8_lossy() {
+    test_lossy!(b"");
+    test_lossy!(b"foo");
+    test_lossy!("ñ个🧡".as_bytes());
+    test_lossy!(b"\x80");
+    test_lossy!(b"foo\xFFbar");
+    test_lossy!(b"\xC0\x80");
+    test_lossy!(b"\xE0\x80\x80");
+    test_lossy!(b"\xED\xA0\x80");
+    test_lossy!(b"\xF4\x90\x80\x80");
+    test_lossy!(b"\xF5\xF6\xF7");
+    test_lossy!(b"\xE2\x82x\xE2\x82");
+    test_lossy!(b"\xF0\x9F\x98\xF0\x9F\x98\x80");
+    test_lossy!(b"a\xF0\x9F\x98");
+}
+
+// Compares against std with every sequence of up to 3 interesting bytes
+#[test]
+fn test_from_utf8_input_against_std() {
+    let interesting: &[u8] = &[
+        0x00, 0x41, 0x7F, 0x80, 0x8F, 0x90, 0x9F, 0xA0, 0xBF, 0xC0, 0xC1, 0xC2, 0xDF, 0xE0, 0xE1,
+        0xEC, 0xED, 0xEE, 0xEF, 0xF0, 0xF1, 0xF3, 0xF4, 0xF5, 0xFF,
+    ];
+
+    let mut inputs = vec![Vec::new()];
+    let mut prev = inputs.clone();
+    for _ in 0..3 {
+        prev = prev
+            .iter()
+            .flat_map(|p| {
+                interesting.iter().map(move |&b| {
+                    let mut p = p.clone();
+                    p.push(b);
+                    p
+                })
+            })
+            .collect();
+        inputs.extend(prev.iter().cloned());
+    }
+    inputs.push("\u{10FFFF}".as_bytes().to_vec());
+    inputs.push(b"\xF4\x8F\xBF\xBF\xF4\x8F\xBF".to_vec());
+
+    for input in inputs {
+        let input: &'static [u8] = Box::leak(input.into_boxed_slice());
+        let args = FromUtf8Input::new(input);
+
+        let valid_up_to = match std::str::from_utf8(input) {
+            Ok(_) => input.len(),
+            Err(e) => e.valid_up_to(),
+        };
+        assert_eq!(args.valid_up_to(), valid_up_to, "{:?}", input);
+
+        let lossy = String::from_utf8_lossy(input);
+        assert_eq!(args.lossy_length(), lossy.len(), "{:?}", input);
+    }
+}
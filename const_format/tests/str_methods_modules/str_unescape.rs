@@ -0,0 +1,43 @@
+use const_format::str_unescape;
+
+#[test]
+fn test_str_unescape_no_escapes() {
+    assert_eq!(str_unescape!(""), "");
+    assert_eq!(str_unescape!("foo"), "foo");
+    assert_eq!(str_unescape!("!Aq¡🧡🧠₀₁oñ个"), "!Aq¡🧡🧠₀₁oñ个");
+}
+
+#[test]
+fn test_str_unescape_simple_escapes() {
+    assert_eq!(str_unescape!(r"\n"), "\n");
+    assert_eq!(str_unescape!(r"\r\t\0"), "\r\t\0");
+    assert_eq!(str_unescape!(r#"\\\'\""#), "\\'\"");
+    assert_eq!(str_unescape!(r"foo\nbar\tbaz"), "foo\nbar\tbaz");
+    assert_eq!(str_unescape!(r"\\n"), "\\n");
+}
+
+#[test]
+fn test_str_unescape_hex_escapes() {
+    assert_eq!(str_unescape!(r"\x00"), "\x00");
+    assert_eq!(str_unescape!(r"\x41\x62"), "Ab");
+    assert_eq!(str_unescape!(r"\x7f\x7F"), "\x7F\x7F");
+    assert_eq!(str_unescape!(r"ñ\x2Dñ"), "ñ-ñ");
+}
+
+#[test]
+fn test_str_unescape_unicode_escapes() {
+    assert_eq!(str_unescape!(r"\u{0}"), "\0");
+    assert_eq!(str_unescape!(r"\u{41}"), "A");
+    assert_eq!(str_unescape!(r"\u{f1}\u{00F1}"), "ññ");
+    assert_eq!(str_unescape!(r"\u{4E2A}"), "个");
+    assert_eq!(str_unescape!(r"\u{1F9E1}\u{1_F9E0}"), "🧡🧠");
+    assert_eq!(str_unescape!(r"\u{10FFFF}"), "\u{10FFFF}");
+    assert_eq!(str_unescape!(r"a\u{D7FF}b\u{E000}c"), "a\u{D7FF}b\u{E000}c");
+}
+
+#[test]
+fn test_str_unescape_const_arg() {
+    const IN: &str = r"name:\t\u{2018}foo\u{2019}\n";
+    const OUT: &str = str_unescape!(IN);
+    assert_eq!(OUT, "name:\t‘foo’\n");
+}
@@ -1,16 +1,19 @@
 use crate::datastructure::{DataStructure, DataVariant, Field, StructKind};
 
-use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
 
 use quote::{quote, quote_spanned, quote_spanned as quote_s, ToTokens, TokenStreamExt};
 
 use syn::{DeriveInput, Ident};
 
-mod attribute_parsing;
-mod syntax;
-mod type_detection;
+pub(crate) mod attribute_parsing;
+pub(crate) mod syntax;
+pub(crate) mod type_detection;
 
-use self::attribute_parsing::HowToFmt;
+use self::{
+    attribute_parsing::{HowToFmt, StdDebug},
+    syntax::ImplHeader,
+};
 
 pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2, crate::Error> {
     let ds = &DataStructure::new(&input);
@@ -50,15 +53,48 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2,
         }
     };
 
+    let type_params = ds
+        .generics
+        .type_params()
+        .map(|x| &x.ident)
+        .collect::<Vec<_>>();
+
+    // Whether the field is formatted with the `ConstDebug` trait,
+    // requiring its type to implement the trait.
+    let uses_const_trait = |f: &Field<'_>| -> bool {
+        config.const_trait_bounds
+            && matches!(
+                config.field_map[f].how_to_fmt,
+                HowToFmt::Regular | HowToFmt::Slice | HowToFmt::Option_
+            )
+            && mentions_type_params(f.ty.to_token_stream(), &type_params)
+    };
+
+    let mut bounded_types = Vec::<&syn::Type>::new();
+    let mut bounded_type_strs = Vec::<String>::new();
+    for f in ds.variants.iter().flat_map(|v| &v.fields) {
+        let ty_str = f.ty.to_token_stream().to_string();
+        if uses_const_trait(f) && !bounded_type_strs.contains(&ty_str) {
+            bounded_types.push(f.ty);
+            bounded_type_strs.push(ty_str);
+        }
+    }
+
     let enum_prefix = match ds.data_variant {
         DataVariant::Enum => quote!(#name::),
         DataVariant::Struct => TokenStream2::new(),
         DataVariant::Union => panic!("Cannot derive ConstDebug on unions"),
     };
 
-    let variant_branches = ds.variants.iter().map(|variant| {
+    let variant_branches = ds.variants.iter().enumerate().map(|(i, variant)| {
         let vname = variant.name;
 
+        let finish_method = if config.non_exhaustive[i] {
+            Ident::new("finish_non_exhaustive", Span::call_site())
+        } else {
+            Ident::new("finish", Span::call_site())
+        };
+
         let debug_method = match variant.kind {
             StructKind::Braced => Ident::new("debug_struct", Span::call_site()),
             StructKind::Tupled => Ident::new("debug_tuple", Span::call_site()),
@@ -100,6 +136,7 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2,
                 );
 
                 field_ts.append_all(match &how_to_fmt {
+                    _ if uses_const_trait(f) => fmt_with_const_trait(&cratep, f),
                     HowToFmt::Regular => coerce_and_fmt(&cratep, f),
                     HowToFmt::Ignore => unreachable!(),
                     HowToFmt::Slice => fmt_slice(&cratep, f),
@@ -117,19 +154,64 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2,
             #enum_prefix #vname { #(#patt)* .. } => {
                 let mut formatter = formatter.#debug_method(stringify!(#vname));
                 #(#fmt_call)*
-                formatter.finish()
+                formatter.#finish_method()
             }
         )
     });
 
+    let std_debug_impls = match &config.std_debug {
+        Some(std_debug) => std_debug_impls(&cratep, ds, &config.impls, &bounded_types, std_debug),
+        None => TokenStream2::new(),
+    };
+
+    let const_trait_bounds = if bounded_types.is_empty() {
+        TokenStream2::new()
+    } else {
+        quote!(where #( #bounded_types: [const] #cratep::fmt::ConstDebug, )*)
+    };
+
+    let const_trait_impls = if config.const_trait_bounds {
+        let impl_params = ds.generics.params.iter();
+        let (_, tygen, _) = ds.generics.split_for_impl();
+        let preds = ds.generics.where_clause.iter().flat_map(|x| &x.predicates);
+
+        quote!(
+            #cratep::__assert_const_trait_bounds_supported!{}
+
+            #cratep::__impl_const_fmt_trait!{
+                ConstDebug::const_debug_fmt;
+
+                impl[#( #impl_params ,)*] #name #tygen
+                where[
+                    #( #preds, )*
+                    #( #bounded_types: [const] #cratep::fmt::ConstDebug, )*
+                ];
+            }
+        )
+    } else {
+        quote!(
+            #cratep::__impl_const_fmt_trait!{
+                ConstDebug::const_debug_fmt;
+
+                #impl_headers
+            }
+        )
+    };
+
     let ret = quote!(
+        #std_debug_impls
+
+        #const_trait_impls
+
         #cratep::impl_fmt!{
             #impl_headers
 
             #vis const fn const_debug_fmt(
                 &self,
                 formatter: &mut #cratep::pmr::Formatter<'_>,
-            ) -> #cratep::pmr::Result<(), #cratep::pmr::Error> {
+            ) -> #cratep::pmr::Result<(), #cratep::pmr::Error>
+            #const_trait_bounds
+            {
                 match self {
                     #(
                         #variant_branches
@@ -145,6 +227,63 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2,
     Ok(ret)
 }
 
+// Implements `core::fmt::Debug` for every impl of `const_debug_fmt`
+fn std_debug_impls(
+    cratep: &TokenStream2,
+    ds: &DataStructure<'_>,
+    impls: &[ImplHeader],
+    bounded_types: &[&syn::Type],
+    std_debug: &StdDebug,
+) -> TokenStream2 {
+    let with_capacity = std_debug
+        .capacity
+        .as_ref()
+        .map(|cap| quote!(.with_capacity::<{ #cap }>()));
+
+    let body = quote!(
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            let adapter = #cratep::pmr::ConstFmtAdapter::<Self>::from_debug(
+                self,
+                |this, f| this.const_debug_fmt(f),
+            );
+            ::core::fmt::Debug::fmt(&adapter #with_capacity, f)
+        }
+    );
+
+    if impls.is_empty() {
+        let name = ds.name;
+        let (impl_generics, ty_generics, _) = ds.generics.split_for_impl();
+        let preds = ds.generics.where_clause.iter().flat_map(|x| &x.predicates);
+
+        quote!(
+            impl #impl_generics ::core::fmt::Debug for #name #ty_generics
+            where
+                #( #preds, )*
+                #( #bounded_types: #cratep::fmt::ConstDebug, )*
+            {
+                #body
+            }
+        )
+    } else {
+        let mut ret = TokenStream2::new();
+
+        for imp in impls {
+            let (impl_generics, _, where_clause) = imp.generics.split_for_impl();
+            let self_ty = &imp.self_ty;
+
+            ret.append_all(quote!(
+                impl #impl_generics ::core::fmt::Debug for #self_ty
+                #where_clause
+                {
+                    #body
+                }
+            ));
+        }
+
+        ret
+    }
+}
+
 // Copying the definitino of the `const_format::coerce_to_fn` macro here
 // because the compiler points inside the coerce_to_fn macro otherwise
 fn coerce_and_fmt(cratep: &TokenStream2, field: &Field<'_>) -> TokenStream2 {
@@ -257,6 +396,15 @@ fn call_with_wrapper(
     )
 }
 
+fn fmt_with_const_trait(cratep: &TokenStream2, field: &Field<'_>) -> TokenStream2 {
+    let var = field.pattern_ident();
+    let fspan = var.span();
+
+    quote_spanned!(fspan=>
+        #cratep::try_!(#cratep::fmt::ConstDebug::const_debug_fmt(#var, field_formatter));
+    )
+}
+
 // Helper of the other `call_` functions
 fn call_debug_fmt(
     cratep: &TokenStream2,
@@ -287,3 +435,12 @@ fn get_where_clause_tokens(where_clause: &Option<syn::WhereClause>) -> TokenStre
         None => TokenStream2::new(),
     }
 }
+
+// Whether any of the `type_params` is used in `tokens`
+fn mentions_type_params(tokens: TokenStream2, type_params: &[&Ident]) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => type_params.iter().any(|param| ident == **param),
+        TokenTree::Group(group) => mentions_type_params(group.stream(), type_params),
+        _ => false,
+    })
+}
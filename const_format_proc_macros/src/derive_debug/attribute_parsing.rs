@@ -1,5 +1,5 @@
 use crate::{
-    datastructure::{DataStructure, Field, FieldMap},
+    datastructure::{DataStructure, DataVariant, Field, FieldMap},
     utils::LinearResult,
 };
 
@@ -15,6 +15,12 @@ pub(crate) struct ConstDebugConfig<'a> {
     pub(crate) debug_print: bool,
     pub(crate) crate_path: Option<syn::Path>,
     pub(crate) impls: Vec<ImplHeader>,
+    /// Whether each variant is formatted with `..` after its fields.
+    pub(crate) non_exhaustive: Vec<bool>,
+    /// Whether to implement `core::fmt::Debug`, delegating to `const_debug_fmt`.
+    pub(crate) std_debug: Option<StdDebug>,
+    /// Whether to format the fields that use type parameters with the `ConstDebug` trait.
+    pub(crate) const_trait_bounds: bool,
     pub(crate) field_map: FieldMap<FieldConfig<'a>>,
     _marker: PhantomData<&'a ()>,
 }
@@ -25,6 +31,9 @@ impl<'a> ConstDebugConfig<'a> {
             debug_print,
             crate_path,
             impls,
+            non_exhaustive,
+            std_debug,
+            const_trait_bounds,
             field_map,
             errors: _,
             _marker: PhantomData,
@@ -34,6 +43,9 @@ impl<'a> ConstDebugConfig<'a> {
             debug_print,
             crate_path,
             impls,
+            non_exhaustive,
+            std_debug,
+            const_trait_bounds: const_trait_bounds.is_some(),
             field_map,
             _marker: PhantomData,
         })
@@ -44,6 +56,9 @@ struct ConstDebugAttrs<'a> {
     debug_print: bool,
     crate_path: Option<syn::Path>,
     impls: Vec<ImplHeader>,
+    non_exhaustive: Vec<bool>,
+    std_debug: Option<StdDebug>,
+    const_trait_bounds: Option<syn::Path>,
     field_map: FieldMap<FieldConfig<'a>>,
     errors: LinearResult,
     _marker: PhantomData<&'a ()>,
@@ -51,6 +66,14 @@ struct ConstDebugAttrs<'a> {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+pub(crate) struct StdDebug {
+    /// The length of the buffer that the value is formatted into,
+    /// `None` if it's the default length.
+    pub(crate) capacity: Option<syn::Expr>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 pub(crate) struct FieldConfig<'a> {
     pub(crate) how_to_fmt: HowToFmt<'a>,
 }
@@ -82,6 +105,7 @@ pub(crate) enum HowToFmt<'a> {
 #[derive(Copy, Clone)]
 enum ParseContext<'a> {
     TypeAttr,
+    Variant { variant: usize },
     Field { field: &'a Field<'a> },
 }
 
@@ -92,6 +116,9 @@ pub(crate) fn parse_attrs_for_derive<'a>(
         debug_print: false,
         crate_path: None,
         impls: Vec::new(),
+        non_exhaustive: vec![false; ds.variants.len()],
+        std_debug: None,
+        const_trait_bounds: None,
         field_map: FieldMap::with(ds, |f| FieldConfig {
             how_to_fmt: type_detection::detect_type_formatting(f.ty),
         }),
@@ -102,7 +129,15 @@ pub(crate) fn parse_attrs_for_derive<'a>(
     let ty_ctx = ParseContext::TypeAttr;
     parse_inner(&mut this, ds.attrs, ty_ctx)?;
 
-    for variant in &ds.variants {
+    for (i, variant) in ds.variants.iter().enumerate() {
+        // The attributes of structs are the same as those of their only variant.
+        if let DataVariant::Enum = ds.data_variant {
+            parse_inner(
+                &mut this,
+                variant.attrs,
+                ParseContext::Variant { variant: i },
+            )?;
+        }
         for field in variant.fields.iter() {
             parse_inner(&mut this, field.attrs, ParseContext::Field { field })?;
         }
@@ -110,6 +145,14 @@ pub(crate) fn parse_attrs_for_derive<'a>(
 
     this.errors.take()?;
 
+    if let (Some(path), false) = (&this.const_trait_bounds, this.impls.is_empty()) {
+        return_spanned_err!(
+            path,
+            "The `#[cdeb(const_trait_bounds)]` attribute can't be used with \
+             the `#[cdeb(impls(...))]` attribute."
+        );
+    }
+
     ConstDebugConfig::new(this)
 }
 
@@ -207,9 +250,22 @@ fn parse_sabi_attr<'a>(
                 return Err(make_err(&list));
             }
         }
+        (ParseContext::Variant { variant }, Meta::Path(path)) => {
+            if path.is_ident("non_exhaustive") {
+                this.non_exhaustive[variant] = true;
+            } else {
+                return Err(make_err(&path));
+            }
+        }
         (ParseContext::TypeAttr { .. }, Meta::Path(path)) => {
             if path.is_ident("debug_print") {
                 this.debug_print = true;
+            } else if path.is_ident("non_exhaustive") {
+                this.non_exhaustive.iter_mut().for_each(|x| *x = true);
+            } else if path.is_ident("std_debug") {
+                this.std_debug = Some(StdDebug { capacity: None });
+            } else if path.is_ident("const_trait_bounds") {
+                this.const_trait_bounds = Some(path);
             } else {
                 return Err(make_err(&path));
             }
@@ -230,6 +286,16 @@ fn parse_sabi_attr<'a>(
                     };
                     this.impls.push(parse_lit::<ImplHeader>(&lit)?);
                 }
+            } else if list.path.is_ident("std_debug") {
+                let mut std_debug = StdDebug { capacity: None };
+                with_nested_meta("std_debug", list.nested, |attr| match attr {
+                    Meta::NameValue(nv) if nv.path.is_ident("capacity") => {
+                        std_debug.capacity = Some(parse_expr(nv.lit)?);
+                        Ok(())
+                    }
+                    _ => Err(make_err(&attr)),
+                })?;
+                this.std_debug = Some(std_debug);
             } else {
                 return Err(make_err(&list));
             }
@@ -267,7 +333,7 @@ fn parse_the_is_a_attribute<'a>(
 
 ///////////////////////////////////////////////////////////////////////////////
 
-fn parse_lit<T>(lit: &syn::Lit) -> Result<T, crate::Error>
+pub(crate) fn parse_lit<T>(lit: &syn::Lit) -> Result<T, crate::Error>
 where
     T: syn::parse::Parse,
 {
@@ -280,7 +346,6 @@ where
     }
 }
 
-#[allow(dead_code)]
 fn parse_expr(lit: syn::Lit) -> Result<syn::Expr, crate::Error> {
     match lit {
         syn::Lit::Str(x) => x.parse(),
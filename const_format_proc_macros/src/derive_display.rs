@@ -0,0 +1,216 @@
+use crate::{
+    datastructure::{DataStructure, DataVariant, FieldIdent, Struct, StructKind},
+    format_args::{ExpandFormatted, ExpandInto},
+    format_str::{FmtArg, FmtStrComponent, FormatStr, WhichArg},
+    utils::LinearResult,
+};
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+
+use quote::{quote, ToTokens, TokenStreamExt};
+
+mod attribute_parsing;
+
+pub(crate) fn derive_constdisplay_impl(
+    input: syn::DeriveInput,
+) -> Result<TokenStream2, crate::Error> {
+    let ds = &DataStructure::new(&input);
+    let config = attribute_parsing::parse_attrs_for_derive(ds)?;
+    let cratep = match &config.crate_path {
+        Some(p) => p.to_token_stream(),
+        None => quote!(::const_format),
+    };
+
+    let vis = ds.vis;
+
+    let name = ds.name;
+
+    let enum_prefix = match ds.data_variant {
+        DataVariant::Enum => quote!(#name::),
+        DataVariant::Struct => TokenStream2::new(),
+        DataVariant::Union => panic!("Cannot derive ConstDisplay on unions"),
+    };
+
+    let formatter = Ident::new("formatter", proc_macro2::Span::mixed_site());
+
+    let mut res = LinearResult::ok();
+
+    let variant_branches = ds
+        .variants
+        .iter()
+        .zip(config.format_strs)
+        .map(|(variant, format_str)| {
+            let vname = variant.name;
+
+            let (used_fields, expanded_into) = match expand_format_str(variant, format_str) {
+                Ok(x) => x,
+                Err(e) => {
+                    res.push_err(e);
+                    return TokenStream2::new();
+                }
+            };
+
+            let patt = used_fields.iter().map(|&pos| {
+                let field = &variant.fields[pos];
+                let pat = &field.ident;
+                let variable = field.pattern_ident();
+                quote!(#pat : #variable,)
+            });
+
+            let fmt_call = expanded_into.iter().map(|ei| ei.fmt_call(&formatter));
+
+            quote!(
+                #enum_prefix #vname { #(#patt)* .. } => {
+                    #(
+                        __cf_osRcTFl4A::try_!(#fmt_call);
+                    )*
+                }
+            )
+        })
+        .collect::<Vec<TokenStream2>>();
+
+    res.take()?;
+
+    let method = quote!(
+        #vis const fn const_display_fmt(
+            &self,
+            #formatter: &mut #cratep::pmr::Formatter<'_>,
+        ) -> #cratep::pmr::Result<(), #cratep::pmr::Error> {
+            use #cratep::__cf_osRcTFl4A;
+
+            match self {
+                #( #variant_branches )*
+            }
+            __cf_osRcTFl4A::pmr::Ok(())
+        }
+    );
+
+    let mut impls = TokenStream2::new();
+    let mut trait_impl_headers = TokenStream2::new();
+
+    if config.impls.is_empty() {
+        let (impl_generics, ty_generics, where_clause) = ds.generics.split_for_impl();
+        let impl_params = ds.generics.params.iter();
+        let preds = ds.generics.where_clause.iter().flat_map(|x| &x.predicates);
+
+        impls.append_all(quote!(
+            impl #impl_generics #name #ty_generics #where_clause {
+                #method
+            }
+        ));
+        trait_impl_headers.append_all(quote!(
+            impl[#( #impl_params ,)*] #name #ty_generics
+            where[ #( #preds, )* ];
+        ));
+    } else {
+        for imp in config.impls.iter() {
+            let (impl_generics, _, where_clause) = imp.generics.split_for_impl();
+            let params = imp.generics.params.iter();
+            let self_ty = &imp.self_ty;
+            let preds = imp.generics.where_clause.iter().flat_map(|x| &x.predicates);
+
+            impls.append_all(quote!(
+                impl #impl_generics #self_ty #where_clause {
+                    #method
+                }
+            ));
+            trait_impl_headers.append_all(quote!(
+                impl[#( #params ,)*] #self_ty
+                where[ #( #preds, )* ];
+            ));
+        }
+    }
+
+    let ret = quote!(
+        #cratep::__impl_const_fmt_trait!{
+            ConstDisplay::const_display_fmt;
+            #trait_impl_headers
+        }
+
+        #impls
+    );
+
+    if config.debug_print {
+        panic!("\n\n\n{}\n\n\n", ret);
+    }
+    Ok(ret)
+}
+
+// Converts the format string of a variant into the code that formats it,
+// returning the positions of the fields used in the format string.
+fn expand_format_str(
+    variant: &Struct<'_>,
+    format_str: FormatStr,
+) -> Result<(Vec<usize>, Vec<ExpandInto>), crate::Error> {
+    let mut used_fields = Vec::<usize>::new();
+    let mut current_pos_arg = 0;
+
+    let mut expanded_into = Vec::with_capacity(format_str.list.len());
+
+    for component in format_str.list {
+        let FmtArg {
+            which_arg,
+            formatting,
+            rawness,
+        } = match component {
+            FmtStrComponent::Str(str, rawness) => {
+                expanded_into.push(ExpandInto::Str(str, rawness));
+                continue;
+            }
+            FmtStrComponent::Arg(arg) => arg,
+        };
+
+        let field = match (&which_arg, variant.kind) {
+            (WhichArg::Ident(ident), _) => variant.fields.iter().find(|f| match f.ident {
+                FieldIdent::Named(name) => name == ident,
+                FieldIdent::Index(_) => false,
+            }),
+            (WhichArg::Positional(opt_pos), StructKind::Tupled) => {
+                let pos = opt_pos.unwrap_or_else(|| {
+                    let pos = current_pos_arg;
+                    current_pos_arg += 1;
+                    pos
+                });
+
+                match variant.fields.get(pos) {
+                    Some(field) => Some(field),
+                    None => {
+                        return Err(crate::Error::new(
+                            rawness.span(),
+                            format!("`{}` has no field at position {}", variant.name, pos),
+                        ))
+                    }
+                }
+            }
+            (WhichArg::Positional(_), StructKind::Braced) => {
+                return Err(crate::Error::new(
+                    rawness.span(),
+                    format!(
+                        "the fields of `{}` must be referred to by name",
+                        variant.name
+                    ),
+                ))
+            }
+        };
+
+        let local_variable = match (field, which_arg) {
+            (Some(field), _) => {
+                if !used_fields.contains(&field.index.pos) {
+                    used_fields.push(field.index.pos);
+                }
+                Ident::new(&field.pattern_ident().to_string(), rawness.span())
+            }
+            // Like `formatcp!("{FOO}")`, names that aren't fields are assumed to be
+            // constants in scope.
+            (None, WhichArg::Ident(ident)) => Ident::new(&ident, rawness.span()),
+            (None, WhichArg::Positional(_)) => unreachable!(),
+        };
+
+        expanded_into.push(ExpandInto::Formatted(ExpandFormatted {
+            format: formatting,
+            local_variable,
+        }));
+    }
+
+    Ok((used_fields, expanded_into))
+}
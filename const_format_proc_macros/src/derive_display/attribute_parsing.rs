@@ -0,0 +1,208 @@
+use crate::{
+    datastructure::{DataStructure, DataVariant},
+    derive_debug::{attribute_parsing::parse_lit, syntax::ImplHeader},
+    format_str::FormatStr,
+    parse_utils::LitStr,
+    utils::LinearResult,
+};
+
+use quote::ToTokens;
+
+use syn::{Attribute, Meta, MetaList, NestedMeta};
+
+pub(crate) struct ConstDisplayConfig {
+    pub(crate) debug_print: bool,
+    pub(crate) crate_path: Option<syn::Path>,
+    pub(crate) impls: Vec<ImplHeader>,
+    /// The format string of each variant.
+    pub(crate) format_strs: Vec<FormatStr>,
+}
+
+struct ConstDisplayAttrs {
+    debug_print: bool,
+    crate_path: Option<syn::Path>,
+    impls: Vec<ImplHeader>,
+    format_strs: Vec<Option<FormatStr>>,
+    errors: LinearResult,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Copy, Clone)]
+enum ParseContext {
+    TypeAttr,
+    Variant { variant: usize },
+    Field,
+}
+
+pub(crate) fn parse_attrs_for_derive(
+    ds: &DataStructure<'_>,
+) -> Result<ConstDisplayConfig, crate::Error> {
+    let mut this = ConstDisplayAttrs {
+        debug_print: false,
+        crate_path: None,
+        impls: Vec::new(),
+        format_strs: ds.variants.iter().map(|_| None).collect(),
+        errors: LinearResult::ok(),
+    };
+
+    parse_inner(&mut this, ds.attrs, ParseContext::TypeAttr, ds);
+
+    for (i, variant) in ds.variants.iter().enumerate() {
+        // The attributes of structs are the same as those of their only variant.
+        if let DataVariant::Enum = ds.data_variant {
+            parse_inner(
+                &mut this,
+                variant.attrs,
+                ParseContext::Variant { variant: i },
+                ds,
+            );
+        }
+        for field in variant.fields.iter() {
+            parse_inner(&mut this, field.attrs, ParseContext::Field, ds);
+        }
+    }
+
+    this.errors.take()?;
+
+    let mut format_strs = Vec::with_capacity(ds.variants.len());
+    for (variant, format_str) in ds.variants.iter().zip(this.format_strs) {
+        match format_str {
+            Some(x) => format_strs.push(x),
+            None => this.errors.push_err(spanned_err!(
+                variant.name,
+                "expected a `#[cdisplay(\"...\")]` attribute with the format string of `{}`",
+                variant.name,
+            )),
+        }
+    }
+
+    this.errors.take()?;
+
+    Ok(ConstDisplayConfig {
+        debug_print: this.debug_print,
+        crate_path: this.crate_path,
+        impls: this.impls,
+        format_strs,
+    })
+}
+
+/// Parses an individual attribute
+fn parse_inner(
+    this: &mut ConstDisplayAttrs,
+    attrs: &[Attribute],
+    pctx: ParseContext,
+    ds: &DataStructure<'_>,
+) {
+    for attr in attrs {
+        match attr.parse_meta() {
+            Ok(Meta::List(list)) => {
+                let x = parse_attr_list(this, pctx, ds, list);
+                this.errors.combine_err(x);
+            }
+            Err(e) => {
+                this.errors.push_err(e);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Parses an individual attribute list (A `#[attribute( .. )] attribute`).
+fn parse_attr_list(
+    this: &mut ConstDisplayAttrs,
+    pctx: ParseContext,
+    ds: &DataStructure<'_>,
+    list: MetaList,
+) -> Result<(), crate::Error> {
+    if !list.path.is_ident("cdisplay") {
+        return Ok(());
+    }
+
+    for nested in list.nested {
+        let x = match nested {
+            NestedMeta::Lit(lit) => parse_format_str(this, pctx, ds, lit),
+            NestedMeta::Meta(attr) => parse_cdisplay_attr(this, pctx, attr),
+        };
+        this.errors.combine_err(x);
+    }
+
+    Ok(())
+}
+
+fn make_err(tokens: &dyn ToTokens) -> crate::Error {
+    spanned_err!(tokens, "unrecognized attribute")
+}
+
+/// Parses the format string in a `#[cdisplay("...")]` attribute.
+fn parse_format_str(
+    this: &mut ConstDisplayAttrs,
+    pctx: ParseContext,
+    ds: &DataStructure<'_>,
+    lit: syn::Lit,
+) -> Result<(), crate::Error> {
+    let variant = match (pctx, ds.data_variant) {
+        (ParseContext::TypeAttr, DataVariant::Struct) => 0,
+        (ParseContext::Variant { variant }, _) => variant,
+        (ParseContext::TypeAttr, _) => return_spanned_err!(
+            lit,
+            "the format string of enums must be passed in each variant's attributes"
+        ),
+        (ParseContext::Field, _) => return Err(make_err(&lit)),
+    };
+
+    let lit_str = match &lit {
+        syn::Lit::Str(x) => LitStr::parse_from_literal(&x.token())?,
+        _ => return_spanned_err!(lit, "expected a string literal with the format string"),
+    };
+
+    if this.format_strs[variant].is_some() {
+        return_spanned_err!(lit, "the format string can only be passed once");
+    }
+
+    let format_str = FormatStr::parse(lit_str.value(), lit_str.rawness)
+        .map_err(|e| e.into_crate_err(lit_str.span, lit_str.value()))?;
+
+    this.format_strs[variant] = Some(format_str);
+
+    Ok(())
+}
+
+/// Parses the contents of a `#[cdisplay( .. )]` attribute.
+fn parse_cdisplay_attr(
+    this: &mut ConstDisplayAttrs,
+    pctx: ParseContext,
+    attr: Meta,
+) -> Result<(), crate::Error> {
+    match (pctx, attr) {
+        (ParseContext::TypeAttr, Meta::Path(path)) => {
+            if path.is_ident("debug_print") {
+                this.debug_print = true;
+            } else {
+                return Err(make_err(&path));
+            }
+        }
+        (ParseContext::TypeAttr, Meta::NameValue(nv)) => {
+            if nv.path.is_ident("crate") {
+                this.crate_path = Some(parse_lit(&nv.lit)?);
+            } else {
+                return Err(make_err(&nv));
+            }
+        }
+        (ParseContext::TypeAttr, Meta::List(list)) => {
+            if list.path.is_ident("impls") {
+                for x in list.nested {
+                    let lit = match x {
+                        NestedMeta::Meta(attr) => return Err(make_err(&attr)),
+                        NestedMeta::Lit(lit) => lit,
+                    };
+                    this.impls.push(parse_lit::<ImplHeader>(&lit)?);
+                }
+            } else {
+                return Err(make_err(&list));
+            }
+        }
+        (_, x) => return Err(make_err(&x)),
+    }
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use crate::datastructure::{DataStructure, DataVariant, Field};
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+
+use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
+
+mod attribute_parsing;
+
+use self::attribute_parsing::HowToCmp;
+
+pub(crate) fn derive_consteq_impl(input: syn::DeriveInput) -> Result<TokenStream2, crate::Error> {
+    let ds = &DataStructure::new(&input);
+    let config = attribute_parsing::parse_attrs_for_derive(ds)?;
+    let cratep = match &config.crate_path {
+        Some(p) => p.to_token_stream(),
+        None => quote!(::const_format),
+    };
+
+    let vis = ds.vis;
+
+    let name = ds.name;
+
+    let enum_prefix = match ds.data_variant {
+        DataVariant::Enum => quote!(#name::),
+        DataVariant::Struct => TokenStream2::new(),
+        DataVariant::Union => panic!("Cannot derive ConstEq on unions"),
+    };
+
+    let variant_branches = ds.variants.iter().map(|variant| {
+        let vname = variant.name;
+
+        let compared_fields = variant
+            .fields
+            .iter()
+            .filter(|f| !matches!(config.field_map[*f], HowToCmp::Ignore))
+            .collect::<Vec<&Field<'_>>>();
+
+        let field_names = compared_fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+        let left_vars = compared_fields.iter().map(|f| left_ident(f));
+        let right_vars = compared_fields.iter().map(|f| right_ident(f));
+
+        let comparisons = compared_fields.iter().map(|f| match &config.field_map[*f] {
+            HowToCmp::Regular => compare_coerced(&cratep, f),
+            HowToCmp::Ignore => unreachable!(),
+            HowToCmp::Slice => compare_slice(&cratep, f),
+            HowToCmp::Option_ => compare_option(&cratep, f),
+            HowToCmp::With(func) => call_with_function(f, func),
+        });
+
+        quote!(
+            (
+                #enum_prefix #vname { #( #field_names: #left_vars, )* .. },
+                #enum_prefix #vname { #( #field_names: #right_vars, )* .. },
+            ) => {
+                #( if !#comparisons { return false; } )*
+                true
+            }
+        )
+    });
+
+    // Avoids an unreachable pattern warning in structs and single-variant enums
+    let mismatched_variants = if ds.variants.len() > 1 {
+        quote!(_ => false,)
+    } else {
+        TokenStream2::new()
+    };
+
+    let method = quote!(
+        #vis const fn const_eq(&self, other: &Self) -> #cratep::pmr::bool {
+            match (self, other) {
+                #( #variant_branches )*
+                #mismatched_variants
+            }
+        }
+    );
+
+    let mut ret = TokenStream2::new();
+
+    if config.impls.is_empty() {
+        let (impl_generics, ty_generics, where_clause) = ds.generics.split_for_impl();
+
+        ret.append_all(quote!(
+            impl #impl_generics #name #ty_generics #where_clause {
+                #method
+            }
+        ));
+    } else {
+        for imp in config.impls.iter() {
+            let (impl_generics, _, where_clause) = imp.generics.split_for_impl();
+            let self_ty = &imp.self_ty;
+
+            ret.append_all(quote!(
+                impl #impl_generics #self_ty #where_clause {
+                    #method
+                }
+            ));
+        }
+    }
+
+    if config.debug_print {
+        panic!("\n\n\n{}\n\n\n", ret);
+    }
+    Ok(ret)
+}
+
+fn left_ident(field: &Field<'_>) -> Ident {
+    field.pattern_ident().clone()
+}
+
+fn right_ident(field: &Field<'_>) -> Ident {
+    let var = field.pattern_ident();
+    Ident::new(&format!("{}_r", var), var.span())
+}
+
+fn compare_coerced(cratep: &TokenStream2, field: &Field<'_>) -> TokenStream2 {
+    let left = left_ident(field);
+    let right = right_ident(field);
+    let fspan = left.span();
+
+    call_const_eq(cratep, left, right, fspan)
+}
+
+fn compare_slice(cratep: &TokenStream2, field: &Field<'_>) -> TokenStream2 {
+    let left = left_ident(field);
+    let right = right_ident(field);
+    let fspan = left.span();
+
+    let call = call_const_eq(
+        cratep,
+        quote_spanned!(fspan=> &#left[n]),
+        quote_spanned!(fspan=> &#right[n]),
+        fspan,
+    );
+
+    quote_spanned!(fspan=>{
+        let len = #left.len();
+        let mut n = 0;
+        let mut equal = len == #right.len();
+        while equal && n != len {
+            equal = #call;
+            n += 1;
+        }
+        equal
+    })
+}
+
+fn compare_option(cratep: &TokenStream2, field: &Field<'_>) -> TokenStream2 {
+    let left = left_ident(field);
+    let right = right_ident(field);
+    let fspan = left.span();
+
+    let call = call_const_eq(
+        cratep,
+        quote_spanned!(fspan=> l),
+        quote_spanned!(fspan=> r),
+        fspan,
+    );
+
+    quote_spanned!(fspan=>
+        match (#left, #right) {
+            (#cratep::pmr::Some(l), #cratep::pmr::Some(r)) => #call,
+            (#cratep::pmr::None, #cratep::pmr::None) => true,
+            _ => false,
+        }
+    )
+}
+
+fn call_with_function(field: &Field<'_>, func: &syn::Path) -> TokenStream2 {
+    let left = left_ident(field);
+    let right = right_ident(field);
+    let fspan = left.span();
+
+    quote_spanned!(fspan=> #func(#left, #right))
+}
+
+// Helper of the other `compare_` functions
+fn call_const_eq(
+    cratep: &TokenStream2,
+    left: impl ToTokens,
+    right: impl ToTokens,
+    span: proc_macro2::Span,
+) -> TokenStream2 {
+    quote_spanned!(span=>{
+        // Importing it like this because the error span is wrong otherwise
+        use #cratep::pmr::IsAFormatMarker as __IsAFormatMarker;
+
+        let mut marker = __IsAFormatMarker::NEW;
+        if false {
+            marker = marker.infer_type(#left);
+        }
+        marker.coerce(marker.unreference(#left)).const_eq(#right)
+    })
+}
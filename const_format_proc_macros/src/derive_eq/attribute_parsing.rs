@@ -0,0 +1,214 @@
+use crate::{
+    datastructure::{DataStructure, DataVariant, Field, FieldMap},
+    derive_debug::{
+        attribute_parsing::{parse_lit, with_nested_meta, HowToFmt},
+        syntax::ImplHeader,
+        type_detection,
+    },
+    utils::LinearResult,
+};
+
+use quote::ToTokens;
+
+use syn::{Attribute, Meta, MetaList, NestedMeta};
+
+pub(crate) struct ConstEqConfig {
+    pub(crate) debug_print: bool,
+    pub(crate) crate_path: Option<syn::Path>,
+    pub(crate) impls: Vec<ImplHeader>,
+    pub(crate) field_map: FieldMap<HowToCmp>,
+}
+
+struct ConstEqAttrs {
+    debug_print: bool,
+    crate_path: Option<syn::Path>,
+    impls: Vec<ImplHeader>,
+    field_map: FieldMap<HowToCmp>,
+    errors: LinearResult,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) enum HowToCmp {
+    /// `coerce_to_fmt!(left).const_eq(right)`
+    Regular,
+    /// Doesn't compare the field.
+    Ignore,
+    /// A slice or an array
+    Slice,
+    /// An `Option`
+    Option_,
+    /// The function used to compare the field,
+    /// it's expected to be callable as `thefunction(left, right)`.
+    With(syn::Path),
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Copy, Clone)]
+enum ParseContext<'a> {
+    TypeAttr,
+    Variant,
+    Field { field: &'a Field<'a> },
+}
+
+pub(crate) fn parse_attrs_for_derive<'a>(
+    ds: &'a DataStructure<'a>,
+) -> Result<ConstEqConfig, crate::Error> {
+    let mut this = ConstEqAttrs {
+        debug_print: false,
+        crate_path: None,
+        impls: Vec::new(),
+        field_map: FieldMap::with(ds, |f| match type_detection::detect_type_formatting(f.ty) {
+            HowToFmt::Slice => HowToCmp::Slice,
+            HowToFmt::Option_ => HowToCmp::Option_,
+            _ => HowToCmp::Regular,
+        }),
+        errors: LinearResult::ok(),
+    };
+
+    parse_inner(&mut this, ds.attrs, ParseContext::TypeAttr);
+
+    for variant in ds.variants.iter() {
+        if let DataVariant::Enum = ds.data_variant {
+            parse_inner(&mut this, variant.attrs, ParseContext::Variant);
+        }
+        for field in variant.fields.iter() {
+            parse_inner(&mut this, field.attrs, ParseContext::Field { field });
+        }
+    }
+
+    this.errors.take()?;
+
+    Ok(ConstEqConfig {
+        debug_print: this.debug_print,
+        crate_path: this.crate_path,
+        impls: this.impls,
+        field_map: this.field_map,
+    })
+}
+
+/// Parses an individual attribute
+fn parse_inner<'a>(this: &mut ConstEqAttrs, attrs: &'a [Attribute], pctx: ParseContext<'a>) {
+    for attr in attrs {
+        match attr.parse_meta() {
+            Ok(Meta::List(list)) => {
+                let x = parse_attr_list(this, pctx, list);
+                this.errors.combine_err(x);
+            }
+            Err(e) => {
+                this.errors.push_err(e);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Parses an individual attribute list (A `#[attribute( .. )] attribute`).
+fn parse_attr_list<'a>(
+    this: &mut ConstEqAttrs,
+    pctx: ParseContext<'a>,
+    list: MetaList,
+) -> Result<(), crate::Error> {
+    if list.path.is_ident("ceq") {
+        with_nested_meta("ceq", list.nested, |attr| {
+            let x = parse_ceq_attr(this, pctx, attr);
+            this.errors.combine_err(x);
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
+
+fn make_err(tokens: &dyn ToTokens) -> crate::Error {
+    spanned_err!(tokens, "unrecognized attribute")
+}
+
+/// Parses the contents of a `#[ceq( .. )]` attribute.
+fn parse_ceq_attr<'a>(
+    this: &mut ConstEqAttrs,
+    pctx: ParseContext<'a>,
+    attr: Meta,
+) -> Result<(), crate::Error> {
+    match (pctx, attr) {
+        (ParseContext::Field { field }, Meta::Path(path)) => {
+            if path.is_ident("ignore") {
+                this.field_map[field.index] = HowToCmp::Ignore;
+            } else {
+                return Err(make_err(&path));
+            }
+        }
+        (ParseContext::Field { field }, Meta::NameValue(nv)) => {
+            if nv.path.is_ident("with") {
+                this.field_map[field.index] = HowToCmp::With(parse_lit(&nv.lit)?);
+            } else {
+                return Err(make_err(&nv));
+            }
+        }
+        (ParseContext::Field { field }, Meta::List(list)) => {
+            if list.path.is_ident("is_a") {
+                match list.nested.len() {
+                    0 => return Err(make_err(&list)),
+                    1 => (),
+                    _ => return_spanned_err!(
+                        list,
+                        "The `#[ceq(is_a())` attribute must only specify one kind of type."
+                    ),
+                }
+                with_nested_meta("is_a", list.nested, |attr| {
+                    this.field_map[field.index] = parse_the_is_a_attribute(attr)?;
+                    Ok(())
+                })?;
+            } else {
+                return Err(make_err(&list));
+            }
+        }
+        (ParseContext::TypeAttr, Meta::Path(path)) => {
+            if path.is_ident("debug_print") {
+                this.debug_print = true;
+            } else {
+                return Err(make_err(&path));
+            }
+        }
+        (ParseContext::TypeAttr, Meta::NameValue(nv)) => {
+            if nv.path.is_ident("crate") {
+                this.crate_path = Some(parse_lit(&nv.lit)?);
+            } else {
+                return Err(make_err(&nv));
+            }
+        }
+        (ParseContext::TypeAttr, Meta::List(list)) => {
+            if list.path.is_ident("impls") {
+                for x in list.nested {
+                    let lit = match x {
+                        NestedMeta::Meta(attr) => return Err(make_err(&attr)),
+                        NestedMeta::Lit(lit) => lit,
+                    };
+                    this.impls.push(parse_lit::<ImplHeader>(&lit)?);
+                }
+            } else {
+                return Err(make_err(&list));
+            }
+        }
+        (_, x) => return Err(make_err(&x)),
+    }
+    Ok(())
+}
+
+fn parse_the_is_a_attribute(attr: syn::Meta) -> Result<HowToCmp, crate::Error> {
+    match attr {
+        Meta::Path(path) => {
+            if path.is_ident("array") || path.is_ident("slice") {
+                Ok(HowToCmp::Slice)
+            } else if path.is_ident("Option") || path.is_ident("option") {
+                Ok(HowToCmp::Option_)
+            } else if path.is_ident("non_std") || path.is_ident("not_std") {
+                Ok(HowToCmp::Regular)
+            } else {
+                Err(make_err(&path))
+            }
+        }
+        _ => Err(make_err(&attr)),
+    }
+}
@@ -95,6 +95,22 @@ impl Error {
             .collect()
     }
 
+    /// Like `to_compile_error`, but outputs each error as a separate statement,
+    /// so that multiple errors can be emitted inside a block.
+    #[cfg(feature = "derive")]
+    pub fn to_compile_error_stmts(&self) -> TokenStream2 {
+        self.messages
+            .iter()
+            .map(|em| {
+                let e = Error {
+                    messages: vec![em.clone()],
+                }
+                .to_compile_error();
+                quote::quote!(#e;)
+            })
+            .collect()
+    }
+
     pub fn combine(&mut self, another: Error) {
         self.messages.extend(another.messages)
     }
@@ -39,6 +39,12 @@ pub(crate) struct FormatIfArgs {
     pub(crate) inner: FormatArgs,
 }
 
+/// The arguments of `formatcp_generic`
+pub(crate) struct FormatGenericArgs {
+    pub(crate) capacity: ExprArg,
+    pub(crate) inner: FormatArgs,
+}
+
 /// The arguments of `writec`
 pub(crate) struct WriteArgs {
     pub(crate) writer_expr: TokenStream2,
@@ -1,6 +1,6 @@
 use super::{
-    ExpandFormatted, ExpandInto, ExpandWithFormatter, FormatArg, FormatArgs, FormatIfArgs,
-    LocalVariable, UncheckedFormatArg, UncheckedFormatArgs, WriteArgs,
+    ExpandFormatted, ExpandInto, ExpandWithFormatter, FormatArg, FormatArgs, FormatGenericArgs,
+    FormatIfArgs, LocalVariable, UncheckedFormatArg, UncheckedFormatArgs, WriteArgs,
 };
 
 use crate::{
@@ -321,6 +321,18 @@ impl MyParse for FormatIfArgs {
 
 ////////////////////////////////////////////////
 
+impl MyParse for FormatGenericArgs {
+    fn parse(input: ParseStream) -> Result<Self, crate::Error> {
+        let capacity = ExprArg::parse(input)?;
+
+        let inner = FormatArgs::parse(input)?;
+
+        Ok(Self { capacity, inner })
+    }
+}
+
+////////////////////////////////////////////////
+
 impl MyParse for WriteArgs {
     fn parse(input: ParseStream) -> Result<Self, crate::Error> {
         let prefix = Ident::new("const_fmt_local_", Span::call_site());
@@ -1,5 +1,7 @@
 use crate::{
-    format_args::{ExpandInto, FormatArgs, FormatIfArgs, LocalVariable, WriteArgs},
+    format_args::{
+        ExpandInto, FormatArgs, FormatGenericArgs, FormatIfArgs, LocalVariable, WriteArgs,
+    },
     parse_utils::TokenStream2Ext,
     shared_arg_parsing::{ExprArg, ExprArgs},
     Error,
@@ -54,37 +56,10 @@ pub(crate) fn formatcp_impl(fmt_args: FormatArgs) -> Result<TokenStream2, crate:
             quote_spanned!(span=> let #ident = #expr;)
         });
 
-    for ei in fmt_args.expanded_into.iter() {
-        if let ExpandInto::WithFormatter(wf) = ei {
-            return Err(crate::Error::new(
-                wf.fmt_ident.span(),
-                "Can't do custom formatting in the `formatcp` macro",
-            ));
-        }
-    }
+    check_formatcp_args(&fmt_args, "formatcp")?;
 
-    let parg_constructor = fmt_args.expanded_into.iter().map(|ei| match ei {
-        ExpandInto::Str(str, rawness) => {
-            let str_tokens = rawness.tokenize_sub(str);
-            quote!(
-                __cf_osRcTFl4A::pmr::PConvWrapper(#str_tokens)
-                    .to_pargument_display(__cf_osRcTFl4A::pmr::FormattingFlags::NEW)
-            )
-        }
-        ExpandInto::Formatted(fmted) => {
-            let to_pargument_m = fmted.format.to_pargument_method_name();
-            let formatting = fmted.format;
-            let local_variable = &fmted.local_variable;
-            let span = local_variable.span();
-            // I had to use `set_span_recursive` to set the span to that of the argument,
-            // quote_span doesn't work for that somehow.
-            quote!(
-                __cf_osRcTFl4A::pmr::PConvWrapper(#local_variable).#to_pargument_m(#formatting)
-            )
-            .set_span_recursive(span)
-        }
-        ExpandInto::WithFormatter { .. } => unreachable!(),
-    });
+    let parg_constructor =
+        pargument_constructors(&fmt_args, |local_variable| quote!(#local_variable));
 
     let fmt_if_true = quote!({
         let mut len = 0usize;
@@ -122,6 +97,91 @@ pub(crate) fn formatcp_impl(fmt_args: FormatArgs) -> Result<TokenStream2, crate:
     }
 }
 
+pub(crate) fn formatcp_generic_impl(
+    value: FormatGenericArgs,
+) -> Result<TokenStream2, crate::Error> {
+    let FormatGenericArgs { capacity, inner } = value;
+
+    check_formatcp_args(&inner, "formatcp_generic")?;
+
+    // The arguments are inlined where they're used,
+    // because local variables would prevent the array from being promoted to a `'static`.
+    let parg_constructor = pargument_constructors(&inner, |local_variable| {
+        match inner
+            .local_variables
+            .iter()
+            .find(|x| x.ident == *local_variable)
+        {
+            Some(LocalVariable { expr, .. }) => quote!((#expr)),
+            None => quote!(#local_variable),
+        }
+    });
+
+    Ok(quote!(
+        match &__cf_osRcTFl4A::pmr::__concatcp_generic::<{ #capacity }>(&[
+            #( #parg_constructor ),*
+        ]) {
+            string => string.as_str(),
+        }
+    ))
+}
+
+fn check_formatcp_args(fmt_args: &FormatArgs, macro_name: &str) -> Result<(), crate::Error> {
+    for ei in fmt_args.expanded_into.iter() {
+        if let ExpandInto::WithFormatter(wf) = ei {
+            return Err(crate::Error::new(
+                wf.fmt_ident.span(),
+                format!("Can't do custom formatting in the `{}` macro", macro_name),
+            ));
+        }
+        if let ExpandInto::Formatted(fmted) = ei {
+            if fmted.format.is_padded() {
+                return Err(crate::Error::new(
+                    fmted.local_variable.span(),
+                    format!(
+                        "Can't use width, fill, or alignment in the `{}` macro",
+                        macro_name
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Constructs the `PArgument`s for each part of the format string,
+// `arg_tokens` converts the local variable of an argument into the tokens used to access it.
+fn pargument_constructors<'a, F>(
+    fmt_args: &'a FormatArgs,
+    arg_tokens: F,
+) -> impl Iterator<Item = TokenStream2> + 'a
+where
+    F: Fn(&Ident) -> TokenStream2 + 'a,
+{
+    fmt_args.expanded_into.iter().map(move |ei| match ei {
+        ExpandInto::Str(str, rawness) => {
+            let str_tokens = rawness.tokenize_sub(str);
+            quote!(
+                __cf_osRcTFl4A::pmr::PConvWrapper(#str_tokens)
+                    .to_pargument_display(__cf_osRcTFl4A::pmr::FormattingFlags::NEW)
+            )
+        }
+        ExpandInto::Formatted(fmted) => {
+            let to_pargument_m = fmted.format.to_pargument_method_name();
+            let formatting = fmted.format;
+            let local_variable = arg_tokens(&fmted.local_variable);
+            let span = fmted.local_variable.span();
+            // I had to use `set_span_recursive` to set the span to that of the argument,
+            // quote_span doesn't work for that somehow.
+            quote!(
+                __cf_osRcTFl4A::pmr::PConvWrapper(#local_variable).#to_pargument_m(#formatting)
+            )
+            .set_span_recursive(span)
+        }
+        ExpandInto::WithFormatter { .. } => unreachable!(),
+    })
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 pub(crate) fn formatc_if_macro_impl(value: FormatIfArgs) -> Result<TokenStream2, crate::Error> {
@@ -41,6 +41,24 @@ fn access_formatter_error() {
     }
 }
 
+#[test]
+fn padding_error() {
+    let cases = [
+        r#"("{:8}"), (100u8) "#,
+        r#"("{:>?}"), (100u8) "#,
+        r#"("{foo:*<3}"), (foo = 100u8) "#,
+    ];
+
+    for case in cases.iter().copied() {
+        assert_ret!(process_str(case), |s| {
+            s.unwrap_err()
+                .consecutive_in_self(&["width, fill, or alignment", "formatcp"])
+        });
+    }
+
+    process_str(r#"("{:?}{:#x}"), (100u8), (100u8) "#).unwrap();
+}
+
 #[test]
 fn nonexistent_argument() {
     assert_ret!(process_str(r#"("{1}"), () "#), |s| {
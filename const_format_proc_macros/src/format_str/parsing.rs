@@ -1,7 +1,7 @@
 use super::{FmtArg, FmtStrComponent, FormatStr, ParseError, ParseErrorKind, WhichArg};
 
 use crate::{
-    formatting::{FormattingFlags, IsAlternate, NumberFormatting},
+    formatting::{Alignment, FormattingFlags, Indent, IsAlternate, NumberFormatting},
     parse_utils::StrRawness,
 };
 
@@ -157,14 +157,6 @@ fn parse_which_arg(input: &str, starts_at: usize) -> Result<WhichArg, ParseError
 ///
 /// `starts_at` is the offset of `input` in the formatting string.
 fn parse_formatting(input: &str, starts_at: usize) -> Result<FormattingFlags, ParseError> {
-    match input {
-        "#" => return Ok(FormattingFlags::display(IsAlternate::Yes)),
-        "" => return Ok(FormattingFlags::display(IsAlternate::No)),
-        _ => {}
-    }
-
-    let mut bytes = input.as_bytes();
-
     let make_error = || ParseError {
         pos: starts_at,
         kind: ParseErrorKind::UnknownFormatting {
@@ -172,23 +164,166 @@ fn parse_formatting(input: &str, starts_at: usize) -> Result<FormattingFlags, Pa
         },
     };
 
-    if let [before @ .., b'?'] = bytes {
-        bytes = before;
-    }
+    // The offset of `part` in the formatting string
+    let offset_of = |part: &str| starts_at + (part.as_ptr() as usize - input.as_ptr() as usize);
+
+    let (fill, alignment, after_alignment) = parse_alignment(input);
+
+    let (mut spec, limits) = match after_alignment.find('(') {
+        Some(open_pos) => (&after_alignment[..open_pos], Some(open_pos)),
+        None => (after_alignment, None),
+    };
 
-    let mut num_fmt = NumberFormatting::Decimal;
     let mut is_alternate = IsAlternate::No;
+    if let Some(rem) = spec.strip_prefix('#') {
+        is_alternate = IsAlternate::Yes;
+        spec = rem;
+    }
+
+    let width_len = spec
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(spec.len());
+    let width = match &spec[..width_len] {
+        "" => None,
+        // The `0` flag (sign-aware zero padding) is not supported
+        x if x.starts_with('0') => return Err(make_error()),
+        x => match x.parse::<usize>() {
+            Ok(width) => Some(width),
+            Err(_) => {
+                return Err(ParseError {
+                    pos: offset_of(x),
+                    kind: ParseErrorKind::NotANumber {
+                        what: x.to_string(),
+                    },
+                })
+            }
+        },
+    };
+    spec = &spec[width_len..];
+
+    // The `#` flag must come before the width
+    if width.is_some() && spec.contains('#') {
+        return Err(make_error());
+    }
+
+    let mut flags = if spec.is_empty() {
+        if limits.is_some() {
+            return Err(make_error());
+        }
+        FormattingFlags::display(is_alternate)
+    } else {
+        let mut bytes = spec.as_bytes();
+
+        if let [before @ .., b'?'] = bytes {
+            bytes = before;
+        }
+
+        let mut num_fmt = NumberFormatting::Decimal;
 
-    for byte in bytes {
-        match byte {
-            b'b' if num_fmt.is_regular() => num_fmt = NumberFormatting::Binary,
-            b'x' if num_fmt.is_regular() => num_fmt = NumberFormatting::LowerHexadecimal,
-            b'X' if num_fmt.is_regular() => num_fmt = NumberFormatting::Hexadecimal,
-            b'#' => is_alternate = IsAlternate::Yes,
-            _ => return Err(make_error()),
+        for byte in bytes {
+            match byte {
+                b'b' if num_fmt.is_regular() => num_fmt = NumberFormatting::Binary,
+                b'x' if num_fmt.is_regular() => num_fmt = NumberFormatting::LowerHexadecimal,
+                b'X' if num_fmt.is_regular() => num_fmt = NumberFormatting::Hexadecimal,
+                b'#' => is_alternate = IsAlternate::Yes,
+                _ => return Err(make_error()),
+            }
         }
+
+        FormattingFlags::debug(num_fmt, is_alternate)
+    };
+
+    flags.width = width;
+    flags.fill = fill;
+    flags.alignment = alignment;
+
+    if let Some(open_pos) = limits {
+        let limits = &after_alignment[open_pos..];
+        parse_debug_limits(limits, offset_of(limits), &mut flags)?;
     }
-    Ok(FormattingFlags::debug(num_fmt, is_alternate))
+
+    Ok(flags)
+}
+
+/// Parses the optional fill character and alignment at the start of the formatters,
+/// eg: `*>` in `{:*>8}`, `<` in `{:<8?}`.
+///
+/// Returns the fill character, the alignment, and the rest of `input`.
+fn parse_alignment(input: &str) -> (Option<char>, Option<Alignment>, &str) {
+    let mut chars = input.char_indices();
+
+    if let (Some((_, fill)), Some((align_pos, align))) = (chars.next(), chars.next()) {
+        if let Some(alignment) = Alignment::from_char(align) {
+            return (Some(fill), Some(alignment), &input[align_pos + 1..]);
+        }
+    }
+
+    match input.chars().next().and_then(Alignment::from_char) {
+        Some(alignment) => (None, Some(alignment), &input[1..]),
+        None => (None, None, input),
+    }
+}
+
+/// Parses the limits of debug formatting, eg: `(depth = 2, entries = 8)` in `{:?(depth = 2)}`,
+/// and the line width and indentation of the alternate debug formatting,
+/// eg: `{:#?(line_width = 80, indent = 2)}`.
+///
+/// `indent` takes either the amount of spaces, or `tab`.
+///
+/// `starts_at` is the offset of `input` in the formatting string.
+fn parse_debug_limits(
+    input: &str,
+    starts_at: usize,
+    flags: &mut FormattingFlags,
+) -> Result<(), ParseError> {
+    let make_error = |what: &str, pos: usize| ParseError {
+        pos,
+        kind: ParseErrorKind::UnknownFormatting {
+            what: what.to_string(),
+        },
+    };
+
+    let inner = match input.strip_prefix('(').and_then(|x| x.strip_suffix(')')) {
+        Some(inner) => inner,
+        None => return Err(make_error(input, starts_at)),
+    };
+
+    // The offset of `part` in the formatting string
+    let offset_of = |part: &str| starts_at + (part.as_ptr() as usize - input.as_ptr() as usize);
+
+    for limit in inner.split(',') {
+        let (key, value) = match limit.find('=') {
+            Some(eq_pos) => (limit[..eq_pos].trim(), limit[eq_pos + 1..].trim()),
+            None => return Err(make_error(limit, offset_of(limit))),
+        };
+
+        if key == "indent" && value == "tab" {
+            flags.indent = Some(Indent::Tab);
+            continue;
+        }
+
+        let value = match value.parse::<usize>() {
+            Ok(value) => value,
+            Err(_) => {
+                return Err(ParseError {
+                    pos: offset_of(value),
+                    kind: ParseErrorKind::NotANumber {
+                        what: value.to_string(),
+                    },
+                })
+            }
+        };
+
+        match key {
+            "depth" => flags.max_depth = Some(value),
+            "entries" => flags.max_entries = Some(value),
+            "line_width" => flags.line_width = Some(value),
+            "indent" => flags.indent = Some(Indent::Spaces(value)),
+            _ => return Err(make_error(key, offset_of(key))),
+        }
+    }
+
+    Ok(())
 }
 
 /// Parses an identifier in a formatting argument.
@@ -2,7 +2,7 @@ use super::*;
 
 use super::{ParseError as PE, ParseErrorKind as PEK};
 
-use crate::formatting::{FormattingFlags as FF, IsAlternate, NumberFormatting};
+use crate::formatting::{Alignment, FormattingFlags as FF, Indent, IsAlternate, NumberFormatting};
 
 use fastrand::Rng;
 
@@ -193,6 +193,224 @@ fn ok_cases() {
     );
 }
 
+#[test]
+fn debug_limits() {
+    const ALT: IsAlternate = IsAlternate::Yes;
+
+    assert_eq!(
+        ok("{:?(depth = 2)}{0:#x?(entries=3)}{FOO:#?( entries = 0 , depth = 1 )}").list,
+        vec![
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF {
+                    max_depth: Some(2),
+                    ..FF::debug(NFDEC, NOALT)
+                },
+            ),
+            FmtStrComponent::arg(
+                WhichArg::Positional(Some(0)),
+                FF {
+                    max_entries: Some(3),
+                    ..FF::debug(NumberFormatting::LowerHexadecimal, ALT)
+                },
+            ),
+            FmtStrComponent::arg(
+                WhichArg::ident("FOO"),
+                FF {
+                    max_depth: Some(1),
+                    max_entries: Some(0),
+                    ..FF::debug(NFDEC, ALT)
+                },
+            ),
+        ]
+    );
+
+    assert_eq!(
+        ok("{:#?(line_width = 80)}").list,
+        vec![FmtStrComponent::arg(
+            WhichArg::Positional(None),
+            FF {
+                line_width: Some(80),
+                ..FF::debug(NFDEC, ALT)
+            },
+        )]
+    );
+
+    assert_eq!(
+        ok("{:#?(indent = 2)}{:#?(indent=tab, line_width = 40)}").list,
+        vec![
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF {
+                    indent: Some(Indent::Spaces(2)),
+                    ..FF::debug(NFDEC, ALT)
+                },
+            ),
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF {
+                    indent: Some(Indent::Tab),
+                    line_width: Some(40),
+                    ..FF::debug(NFDEC, ALT)
+                },
+            ),
+        ]
+    );
+
+    assert_eq!(
+        err("{:(depth = 2)}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting("(depth = 2)")
+        }
+    );
+    assert_eq!(
+        err("{:?(depth = 2}"),
+        PE {
+            pos: 3,
+            kind: PEK::unknown_formatting("(depth = 2")
+        }
+    );
+    assert_eq!(
+        err("{:?(depth = 2, width = 3)}"),
+        PE {
+            pos: 15,
+            kind: PEK::unknown_formatting("width")
+        }
+    );
+    assert_eq!(
+        err("{:?(depth = 2, entries)}"),
+        PE {
+            pos: 14,
+            kind: PEK::unknown_formatting(" entries")
+        }
+    );
+    assert_eq!(
+        err("{:#?(indent = tabs)}"),
+        PE {
+            pos: 14,
+            kind: PEK::not_a_number("tabs")
+        }
+    );
+    assert_eq!(
+        err("{:?(depth = -2)}"),
+        PE {
+            pos: 12,
+            kind: PEK::not_a_number("-2")
+        }
+    );
+}
+
+#[test]
+fn width_fill_alignment() {
+    const ALT: IsAlternate = IsAlternate::Yes;
+
+    assert_eq!(
+        ok("{:8}{:>8}{0:*^12?}{FOO:<#5x?}{:#>3}{:é<1}{:>#4}").list,
+        vec![
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF {
+                    width: Some(8),
+                    ..FF::display(NOALT)
+                },
+            ),
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF {
+                    width: Some(8),
+                    alignment: Some(Alignment::Right),
+                    ..FF::display(NOALT)
+                },
+            ),
+            FmtStrComponent::arg(
+                WhichArg::Positional(Some(0)),
+                FF {
+                    width: Some(12),
+                    fill: Some('*'),
+                    alignment: Some(Alignment::Center),
+                    ..FF::debug(NFDEC, NOALT)
+                },
+            ),
+            FmtStrComponent::arg(
+                WhichArg::ident("FOO"),
+                FF {
+                    width: Some(5),
+                    alignment: Some(Alignment::Left),
+                    ..FF::debug(NumberFormatting::LowerHexadecimal, ALT)
+                },
+            ),
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF {
+                    width: Some(3),
+                    fill: Some('#'),
+                    alignment: Some(Alignment::Right),
+                    ..FF::display(NOALT)
+                },
+            ),
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF {
+                    width: Some(1),
+                    fill: Some('é'),
+                    alignment: Some(Alignment::Left),
+                    ..FF::display(NOALT)
+                },
+            ),
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF {
+                    width: Some(4),
+                    alignment: Some(Alignment::Right),
+                    ..FF::display(ALT)
+                },
+            ),
+        ]
+    );
+
+    assert_eq!(
+        ok("{:>#?(depth = 1)}").list,
+        vec![FmtStrComponent::arg(
+            WhichArg::Positional(None),
+            FF {
+                max_depth: Some(1),
+                alignment: Some(Alignment::Right),
+                ..FF::debug(NFDEC, ALT)
+            },
+        )]
+    );
+
+    assert_eq!(
+        err("{:08}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting("08")
+        }
+    );
+    assert_eq!(
+        err("{:>8(depth = 1)}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting(">8(depth = 1)")
+        }
+    );
+    assert_eq!(
+        err("{:>#99999999999999999999999}"),
+        PE {
+            pos: 4,
+            kind: PEK::not_a_number("99999999999999999999999")
+        }
+    );
+    assert_eq!(
+        err("{:8#}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting("8#")
+        }
+    );
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 trait RngExt {
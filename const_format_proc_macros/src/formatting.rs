@@ -43,10 +43,66 @@ pub(crate) enum IsAlternate {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// The indentation of the alternate debug formatting.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Indent {
+    Spaces(usize),
+    Tab,
+}
+
+impl ToTokens for Indent {
+    fn to_tokens(&self, ts: &mut TokenStream2) {
+        let indent = match *self {
+            Self::Spaces(count) => " ".repeat(count),
+            Self::Tab => "\t".to_string(),
+        };
+        ts.append_all(quote!(#indent));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+impl Alignment {
+    pub(crate) fn from_char(c: char) -> Option<Self> {
+        match c {
+            '<' => Some(Self::Left),
+            '^' => Some(Self::Center),
+            '>' => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+impl ToTokens for Alignment {
+    fn to_tokens(&self, ts: &mut TokenStream2) {
+        ts.append_all(match self {
+            Self::Left => quote!(__cf_osRcTFl4A::pmr::Alignment::Left),
+            Self::Center => quote!(__cf_osRcTFl4A::pmr::Alignment::Center),
+            Self::Right => quote!(__cf_osRcTFl4A::pmr::Alignment::Right),
+        });
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub(crate) struct FormattingFlags {
     pub(crate) formatting: Formatting,
     pub(crate) is_alternate: IsAlternate,
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) max_entries: Option<usize>,
+    pub(crate) line_width: Option<usize>,
+    pub(crate) indent: Option<Indent>,
+    pub(crate) width: Option<usize>,
+    pub(crate) fill: Option<char>,
+    pub(crate) alignment: Option<Alignment>,
 }
 
 impl FormattingFlags {
@@ -55,6 +111,13 @@ impl FormattingFlags {
         Self {
             formatting: Formatting::Display,
             is_alternate,
+            max_depth: None,
+            max_entries: None,
+            line_width: None,
+            indent: None,
+            width: None,
+            fill: None,
+            alignment: None,
         }
     }
 
@@ -63,11 +126,23 @@ impl FormattingFlags {
         Self {
             formatting: Formatting::Debug(num_fmt),
             is_alternate,
+            max_depth: None,
+            max_entries: None,
+            line_width: None,
+            indent: None,
+            width: None,
+            fill: None,
+            alignment: None,
         }
     }
 }
 
 impl FormattingFlags {
+    /// Whether the value is padded, eg: `{:>8}`.
+    pub(crate) fn is_padded(self) -> bool {
+        self.width.is_some() || self.fill.is_some() || self.alignment.is_some()
+    }
+
     pub(crate) fn to_pargument_method_name(self) -> Ident {
         let name = match self.formatting {
             Formatting::Display => "to_pargument_display",
@@ -121,5 +196,27 @@ impl ToTokens for FormattingFlags {
             }
             (IA::Yes, FM::Binary) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_BIN),
         });
+
+        if let Some(max_depth) = self.max_depth {
+            ts.append_all(quote!(.set_max_depth(#max_depth)));
+        }
+        if let Some(max_entries) = self.max_entries {
+            ts.append_all(quote!(.set_max_entries(#max_entries)));
+        }
+        if let Some(line_width) = self.line_width {
+            ts.append_all(quote!(.set_line_width(#line_width)));
+        }
+        if let Some(indent) = self.indent {
+            ts.append_all(quote!(.set_indent(#indent)));
+        }
+        if let Some(width) = self.width {
+            ts.append_all(quote!(.set_width(#width)));
+        }
+        if let Some(fill) = self.fill {
+            ts.append_all(quote!(.set_fill(#fill)));
+        }
+        if let Some(alignment) = self.alignment {
+            ts.append_all(quote!(.set_alignment(#alignment)));
+        }
     }
 }
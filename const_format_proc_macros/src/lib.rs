@@ -17,6 +17,12 @@ mod error;
 #[cfg(feature = "derive")]
 mod derive_debug;
 
+#[cfg(feature = "derive")]
+mod derive_display;
+
+#[cfg(feature = "derive")]
+mod derive_eq;
+
 mod format_args;
 
 mod format_str;
@@ -74,6 +80,15 @@ pub fn __formatcp_impl(input: TokenStream1) -> TokenStream1 {
         .into()
 }
 
+#[doc(hidden)]
+#[proc_macro]
+pub fn __formatcp_generic_impl(input: TokenStream1) -> TokenStream1 {
+    MyParse::parse_token_stream_1(input)
+        .and_then(format_macro::formatcp_generic_impl)
+        .unwrap_or_else(compile_err_empty_str)
+        .into()
+}
+
 #[doc(hidden)]
 #[proc_macro]
 pub fn __formatc_impl(input: TokenStream1) -> TokenStream1 {
@@ -122,7 +137,38 @@ pub fn derive_const_debug(input: TokenStream1) -> TokenStream1 {
     syn::parse(input)
         .map_err(crate::Error::from)
         .and_then(derive_debug::derive_constdebug_impl)
-        .unwrap_or_else(|e| e.to_compile_error())
+        .unwrap_or_else(|e| {
+            // The errors are wrapped in a constant because
+            // they're expressions, which can't be used as items.
+            let e = e.to_compile_error_stmts();
+            quote::quote!(const _: () = { #e };)
+        })
+        .into()
+}
+
+#[cfg(feature = "derive")]
+#[proc_macro_derive(ConstDisplay, attributes(cdisplay))]
+pub fn derive_const_display(input: TokenStream1) -> TokenStream1 {
+    syn::parse(input)
+        .map_err(crate::Error::from)
+        .and_then(derive_display::derive_constdisplay_impl)
+        .unwrap_or_else(|e| {
+            let e = e.to_compile_error_stmts();
+            quote::quote!(const _: () = { #e };)
+        })
+        .into()
+}
+
+#[cfg(feature = "derive")]
+#[proc_macro_derive(ConstEq, attributes(ceq))]
+pub fn derive_const_eq(input: TokenStream1) -> TokenStream1 {
+    syn::parse(input)
+        .map_err(crate::Error::from)
+        .and_then(derive_eq::derive_consteq_impl)
+        .unwrap_or_else(|e| {
+            let e = e.to_compile_error_stmts();
+            quote::quote!(const _: () = { #e };)
+        })
         .into()
 }
 